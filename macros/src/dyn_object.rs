@@ -1,35 +1,50 @@
+use std::collections::HashSet;
+
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{ToTokens, format_ident, quote};
 use syn::{
-    FnArg, GenericParam, ImplItemFn, ItemTrait, Path, PathSegment, Token, TraitItem, TraitItemFn,
-    TraitItemType, meta::ParseNestedMeta, parse_quote, punctuated::Punctuated, visit_mut,
-    visit_mut::VisitMut,
+    FnArg, GenericParam, ImplItemFn, ItemTrait, Meta, Path, PathSegment, Token, TraitItem,
+    TraitItemFn, TraitItemType, TypeParamBound, meta::ParseNestedMeta, parse_quote,
+    punctuated::Punctuated, visit_mut, visit_mut::VisitMut,
 };
 
 use crate::{
     MacroArgs, crate_name,
     macros::{bail, bail_method, fields, try_match},
-    utils::{IteratorExt, fn_args, impl_method, is_dispatchable, last_segment, pinned_ref, respan},
+    utils::{
+        IteratorExt, fn_args, impl_method, is_consuming, is_dispatchable, last_segment, pinned_ref,
+        respan,
+    },
 };
 
 #[derive(Default)]
 pub(super) struct DynObjectOps {
     bounds: Punctuated<Path, Token![+]>,
+    clone: bool,
     crate_: Option<Path>,
+    downcast: bool,
     remote: Option<Path>,
+    supertraits: Punctuated<Path, Token![+]>,
 }
 
 impl MacroArgs for DynObjectOps {
     fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
         if meta.path.is_ident("bounds") {
             meta.input.parse::<Token![=]>()?;
-            self.bounds = Punctuated::parse_terminated(meta.input)?;
+            self.bounds = Punctuated::parse_separated_nonempty(meta.input)?;
+        } else if meta.path.is_ident("clone") {
+            self.clone = true;
         } else if meta.path.is_ident("crate") {
             meta.input.parse::<Token![=]>()?;
             self.crate_ = Some(meta.input.parse()?);
+        } else if meta.path.is_ident("downcast") {
+            self.downcast = true;
         } else if meta.path.is_ident("remote") {
             meta.input.parse::<Token![=]>()?;
             self.remote = Some(meta.input.parse()?);
+        } else if meta.path.is_ident("supertraits") {
+            meta.input.parse::<Token![=]>()?;
+            self.supertraits = Punctuated::parse_separated_nonempty(meta.input)?;
         } else {
             bail!(meta.path, "unknown attribute");
         }
@@ -42,7 +57,7 @@ pub(super) fn dyn_object_impl(r#trait: ItemTrait, opts: DynObjectOps) -> syn::Re
     for item in r#trait.items.iter() {
         match item {
             TraitItem::Fn(method) => {
-                if !is_dispatchable(method) {
+                if !is_dispatchable(&method.sig) && !is_consuming(method) {
                     bail_method!(method, "method is not dispatchable");
                 }
                 dyn_object.methods.push(method);
@@ -57,25 +72,69 @@ pub(super) fn dyn_object_impl(r#trait: ItemTrait, opts: DynObjectOps) -> syn::Re
             _ => bail!(item, "unsupported item"),
         }
     }
+    for supertrait in dyn_object.supertraits.iter() {
+        let is_supertrait = |bound: &TypeParamBound| {
+            let ident = &supertrait.segments.last().unwrap().ident;
+            try_match!(bound, TypeParamBound::Trait)
+                .is_some_and(|b| last_segment(&b.path, &ident.to_string()).is_some())
+        };
+        if !r#trait.supertraits.iter().any(is_supertrait) {
+            bail!(supertrait, "not a supertrait");
+        }
+    }
     fields!(dyn_object => crate_, remote);
     let dyn_trait = dyn_object.dyn_trait();
     let generics = dyn_object.generics();
     let vtable_fields = (dyn_object.methods.iter()).map(|m| dyn_object.vtable_field(m));
     let vtable_methods = (dyn_object.methods.iter()).map(|m| dyn_object.vtable_method(m));
-    let impl_methods = (dyn_object.methods.iter()).map(|m| dyn_object.impl_method(m));
-    let impl_types = (dyn_object.types.iter()).map(|t| dyn_object.impl_type(t));
+    let impl_methods = (dyn_object.methods.iter())
+        .map(|m| dyn_object.impl_method(m))
+        .collect_vec();
+    let impl_types = (dyn_object.types.iter())
+        .map(|t| dyn_object.impl_type(t))
+        .collect_vec();
+    let upcasts = dyn_object.upcasts();
+    let upcast_fields = (0..upcasts.len()).map(|i| format_ident!("__upcast{i}"));
+    let upcast_vtables = (upcasts.iter().enumerate()).map(|(i, u)| dyn_object.upcast_vtable(i, u));
+    let upcast_impls = (upcasts.iter().enumerate()).map(|(i, u)| dyn_object.upcast_impl(i, u));
     let (_, ty_gen, where_clause) = r#trait.generics.split_for_impl();
-    let remote_with_args = quote!(#remote #ty_gen);
+    let impls = (dyn_object.impl_headers(quote!(#remote #ty_gen)).into_iter()).map(|header| {
+        quote! {
+            #header {
+                #(#impl_types)*
+                #(#impl_methods)*
+            }
+        }
+    });
     let opt_trait = dyn_object.include_trait.then_some(&r#trait);
+    let clone = dyn_object.clone.then(|| quote!(+ ::core::clone::Clone));
+    let clone_field = dyn_object.clone.then(|| quote!(__clone: unsafe fn(),));
+    let clone_vtable = dyn_object.clone.then(|| dyn_object.clone_vtable());
+    let clone_impl = dyn_object.clone.then(|| dyn_object.clone_impl());
+    let downcast = dyn_object.downcast.then(|| quote!(+ 'static));
+    let downcast_field = dyn_object
+        .downcast
+        .then(|| quote!(__type_id: ::core::any::TypeId,));
+    let downcast_vtable = dyn_object
+        .downcast
+        .then(|| quote!(__type_id: ::core::any::TypeId::of::<__Dyn>(),));
+    let downcast_impl = dyn_object.downcast.then(|| dyn_object.downcast_impl());
+    let downgrade_impls = dyn_object.downgrade_impls();
+    let unsize_impl = dyn_object.unsize_impl();
     Ok(quote! {
         #opt_trait
 
         const _: () = {
             #[derive(Debug)]
+            #[repr(C)]
             pub struct __Vtable {
                 __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
                 __layout: ::core::alloc::Layout,
+                __unsize: unsafe fn(),
+                #clone_field
+                #downcast_field
                 #(#vtable_fields,)*
+                #(#upcast_fields: unsafe fn(),)*
             }
 
             impl<#(#generics,)*> #crate_::object::DynTrait for dyn #dyn_trait #where_clause {
@@ -89,26 +148,37 @@ pub(super) fn dyn_object_impl(r#trait: ItemTrait, opts: DynObjectOps) -> syn::Re
             }
 
             // SAFETY: vtable fields respect trait contract
-            unsafe impl<#(#generics,)* __Dyn: #dyn_trait> #crate_::object::Vtable<__Dyn>
+            unsafe impl<#(#generics,)* __Dyn: #dyn_trait #clone #downcast> #crate_::object::Vtable<__Dyn>
                 for dyn #dyn_trait #where_clause
             {
-                fn vtable<__Storage: #crate_::storage::Storage>() -> &'static Self::Vtable {
+                fn vtable<__Storage: #crate_::storage::SharedStorage>() -> &'static Self::Vtable {
                     &const {
                         __Vtable {
                             __drop_in_place: <Self as #crate_::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
                             __layout: core::alloc::Layout::new::<__Dyn>(),
+                            // SAFETY: transmutation is only used to erase the function type
+                            __unsize: unsafe {
+                                ::core::mem::transmute::<
+                                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                                    unsafe fn(),
+                                >(|__ptr| __ptr.cast::<__Dyn>())
+                            },
+                            #clone_vtable
+                            #downcast_vtable
                             #(#vtable_methods,)*
+                            #(#upcast_vtables)*
                         }
                     }
                 }
             }
 
-            impl<#(#generics,)* __Storage: #crate_::storage::Storage> #remote_with_args
-                for #crate_::DynObject<dyn #dyn_trait, __Storage> #where_clause
-            {
-                #(#impl_types)*
-                #(#impl_methods)*
-            }
+            #unsize_impl
+            #clone_impl
+            #downcast_impl
+            #(#upcast_impls)*
+            #(#downgrade_impls)*
+
+            #(#impls)*
         };
     })
 }
@@ -116,9 +186,14 @@ pub(super) fn dyn_object_impl(r#trait: ItemTrait, opts: DynObjectOps) -> syn::Re
 struct DynObject<'a> {
     r#trait: &'a ItemTrait,
     include_trait: bool,
+    is_remote: bool,
     crate_: Path,
     remote: Path,
     bounds: Punctuated<Path, Token![+]>,
+    clone: bool,
+    downcast: bool,
+    supertraits: Punctuated<Path, Token![+]>,
+    following: Vec<DynObjectOps>,
     types: Vec<(Ident, &'a TraitItemType)>,
     methods: Vec<&'a TraitItemFn>,
 }
@@ -130,15 +205,33 @@ impl<'a> DynObject<'a> {
         Self {
             r#trait,
             include_trait: opts.remote.is_none() || has_dyn_object_attr(),
+            is_remote: opts.remote.is_some(),
             crate_: opts.crate_.unwrap_or_else(crate_name),
             remote: opts.remote.unwrap_or_else(|| r#trait.ident.clone().into()),
             bounds: opts.bounds,
+            clone: opts.clone,
+            downcast: opts.downcast,
+            supertraits: opts.supertraits,
+            following: (r#trait.attrs.iter())
+                .filter(|attr| last_segment(attr.path(), "dyn_object").is_some())
+                .filter_map(|attr| {
+                    let mut opts = DynObjectOps::default();
+                    if let Meta::List(_) = &attr.meta {
+                        attr.parse_nested_meta(|m| opts.parse_meta(m)).ok()?;
+                    }
+                    Some(opts)
+                })
+                .collect(),
             types: Vec::new(),
             methods: Vec::new(),
         }
     }
 
     fn dyn_trait(&self) -> TokenStream {
+        self.dyn_trait_with(&self.bounds, quote!('__lt))
+    }
+
+    fn dyn_trait_with(&self, bounds: &Punctuated<Path, Token![+]>, lt: TokenStream) -> TokenStream {
         let dyn_trait_args = (self.r#trait.generics.params.iter())
             .map(|param| match param {
                 GenericParam::Lifetime(p) => p.lifetime.to_token_stream(),
@@ -149,8 +242,8 @@ impl<'a> DynObject<'a> {
                 let ty_name = &ty.ident;
                 quote!(#ty_name = #ty_arg)
             }));
-        fields!(self => bounds, remote);
-        let mut dyn_trait = quote!(#remote<#(#dyn_trait_args,)*> + '__lt);
+        let remote = &self.remote;
+        let mut dyn_trait = quote!(#remote<#(#dyn_trait_args,)*> + #lt);
         if !bounds.is_empty() {
             dyn_trait.extend(quote!(+ #bounds));
         }
@@ -177,6 +270,273 @@ impl<'a> DynObject<'a> {
         generics
     }
 
+    /// Whether the trait is implemented for every `DynObject` which can be upcast to it,
+    /// allowing subtraits objects to implement it.
+    fn is_blanket(&self) -> bool {
+        !self.is_remote
+            && self.bounds.is_empty()
+            && self.types.is_empty()
+            && self.r#trait.generics.params.is_empty()
+    }
+
+    /// Returns the implementation headers of the trait for `DynObject` and `ThinDynObject`.
+    fn impl_headers(&self, remote_with_args: TokenStream) -> [TokenStream; 2] {
+        fields!(self => crate_, r#trait);
+        let dyn_trait = self.dyn_trait();
+        let generics = self.generics();
+        let mut where_clause = r#trait.generics.where_clause.clone();
+        let supertraits = &r#trait.supertraits;
+        if !supertraits.is_empty() {
+            (where_clause
+                .get_or_insert_with(|| parse_quote!(where))
+                .predicates)
+                .push(parse_quote!(Self: #supertraits));
+        }
+        // methods borrowing the object mutably are not available with shared storages
+        let storage = if (self.methods.iter())
+            .all(|m| matches!(VtableReceiver::new(m), VtableReceiver::Ref))
+        {
+            quote!(SharedStorage)
+        } else {
+            quote!(Storage)
+        };
+        let header = |object: TokenStream, storage: TokenStream| {
+            if self.is_blanket() {
+                quote! {
+                    impl<
+                        #(#generics,)*
+                        __Dyn: #crate_::object::Upcast<dyn #dyn_trait> + ?Sized,
+                        __Storage: #crate_::storage::#storage
+                    > #remote_with_args for #crate_::#object<__Dyn, __Storage> #where_clause
+                }
+            } else {
+                quote! {
+                    impl<#(#generics,)* __Storage: #crate_::storage::#storage> #remote_with_args
+                        for #crate_::#object<dyn #dyn_trait, __Storage> #where_clause
+                }
+            }
+        };
+        [
+            header(quote!(DynObject), storage),
+            header(quote!(ThinDynObject), quote!(ThinStorage)),
+        ]
+    }
+
+    fn vtable_expr(&self, this: TokenStream) -> TokenStream {
+        let crate_ = &self.crate_;
+        let dyn_trait = self.dyn_trait();
+        if self.is_blanket() {
+            quote!(<__Dyn as #crate_::object::Upcast<dyn #dyn_trait>>::upcast_vtable(#this.vtable()))
+        } else {
+            quote!(#this.vtable())
+        }
+    }
+
+    /// Returns the supertraits objects the trait object can be upcast to.
+    fn upcasts(&self) -> Vec<TokenStream> {
+        // upcasts with bounds are put at the end, so the vtable without bounds is a prefix
+        let bounds = &self.bounds;
+        let with_bounds = (self.supertraits.iter())
+            .filter(|_| !bounds.is_empty())
+            .map(|supertrait| quote!(#supertrait + '__lt + #bounds));
+        (self.supertraits.iter())
+            .map(|supertrait| quote!(#supertrait + '__lt))
+            .chain(with_bounds)
+            .collect()
+    }
+
+    fn upcast_vtable(&self, index: usize, upcast: &TokenStream) -> TokenStream {
+        let crate_ = &self.crate_;
+        let field = format_ident!("__upcast{index}");
+        quote! {
+            // SAFETY: transmutation is only used to erase the function type
+            #field: unsafe {
+                ::core::mem::transmute::<
+                    fn() -> &'static <dyn #upcast as #crate_::object::DynTrait>::Vtable,
+                    unsafe fn(),
+                >(<dyn #upcast as #crate_::object::Vtable<__Dyn>>::vtable::<__Storage>)
+            },
+        }
+    }
+
+    fn upcast_impl(&self, index: usize, upcast: &TokenStream) -> TokenStream {
+        fields!(self => crate_, r#trait);
+        let field = format_ident!("__upcast{index}");
+        let dyn_trait = self.dyn_trait();
+        let generics = self.generics();
+        let where_clause = &r#trait.generics.where_clause;
+        quote! {
+            // SAFETY: `#field` is initialized with the same concrete type and storage
+            unsafe impl<#(#generics,)*> #crate_::object::Upcast<dyn #upcast>
+                for dyn #dyn_trait #where_clause
+            {
+                fn upcast_vtable(
+                    vtable: &'static Self::Vtable,
+                ) -> &'static <dyn #upcast as #crate_::object::DynTrait>::Vtable {
+                    // SAFETY: the vtable field has been initialized with the given type
+                    unsafe {
+                        ::core::mem::transmute::<
+                            unsafe fn(),
+                            fn() -> &'static <dyn #upcast as #crate_::object::DynTrait>::Vtable,
+                        >(vtable.#field)()
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the `Downgrade` implementations of this trait object, and between this trait
+    /// object and the ones generated by the following `dyn_object` attributes.
+    fn downgrade_impls(&self) -> Vec<TokenStream> {
+        let mut impls = vec![self.downgrade_impl(&self.bounds, &self.bounds)];
+        let self_bounds = paths_set(&self.bounds);
+        for other in self
+            .following
+            .iter()
+            .filter(|other| self.has_same_fields(other))
+        {
+            let other_bounds = paths_set(&other.bounds);
+            if other_bounds == self_bounds {
+                continue;
+            }
+            let (bounds, target_bounds) = if other_bounds.is_subset(&self_bounds) {
+                (&self.bounds, &other.bounds)
+            } else if self_bounds.is_subset(&other_bounds) {
+                (&other.bounds, &self.bounds)
+            } else {
+                continue;
+            };
+            // upcasts with bounds are not the same, so the target must have no bounds
+            if self.supertraits.is_empty() || target_bounds.is_empty() {
+                impls.push(self.downgrade_impl(bounds, target_bounds));
+            }
+        }
+        impls
+    }
+
+    fn has_same_fields(&self, other: &DynObjectOps) -> bool {
+        let remote = self.is_remote.then_some(&self.remote);
+        other.clone == self.clone
+            && other.downcast == self.downcast
+            && paths_set(&other.supertraits) == paths_set(&self.supertraits)
+            && other.remote.as_ref().map(path_string) == remote.map(path_string)
+    }
+
+    fn downgrade_impl(
+        &self,
+        bounds: &Punctuated<Path, Token![+]>,
+        target_bounds: &Punctuated<Path, Token![+]>,
+    ) -> TokenStream {
+        fields!(self => crate_, r#trait);
+        let dyn_trait = self.dyn_trait_with(bounds, quote!('__lt));
+        let target = self.dyn_trait_with(target_bounds, quote!('__target));
+        let mut generics = self.generics();
+        generics[0] = parse_quote!('__lt: '__target);
+        generics.insert(0, parse_quote!('__target));
+        let where_clause = &r#trait.generics.where_clause;
+        quote! {
+            // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+            // and the target has less bounds and a shorter lifetime
+            unsafe impl<#(#generics,)*> #crate_::object::Downgrade<dyn #target>
+                for dyn #dyn_trait #where_clause {}
+        }
+    }
+
+    fn unsize_impl(&self) -> TokenStream {
+        fields!(self => crate_, r#trait);
+        let dyn_trait = self.dyn_trait();
+        let generics = self.generics();
+        let where_clause = &r#trait.generics.where_clause;
+        quote! {
+            // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+            unsafe impl<#(#generics,)*> #crate_::object::DynUnsize for dyn #dyn_trait #where_clause {
+                fn unsize_fn(
+                    vtable: &Self::Vtable,
+                ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+                    // SAFETY: transmutation is only used to erase the function type
+                    unsafe {
+                        ::core::mem::transmute::<
+                            unsafe fn(),
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                        >(vtable.__unsize)
+                    }
+                }
+            }
+
+            #crate_::__cfg_alloc! {
+                impl<#(#generics,)* __Storage: #crate_::storage::Storage>
+                    ::core::convert::From<#crate_::DynObject<dyn #dyn_trait, __Storage>>
+                    for #crate_::__private::Box<dyn #dyn_trait> #where_clause
+                {
+                    fn from(object: #crate_::DynObject<dyn #dyn_trait, __Storage>) -> Self {
+                        object.into_box()
+                    }
+                }
+            }
+        }
+    }
+
+    fn clone_vtable(&self) -> TokenStream {
+        fields!(self => crate_);
+        // `Storage::new` would fail at build time for storages which cannot construct
+        // the object, e.g. `Slot`, so `try_new` is used instead, panicking on clone
+        quote! {
+            // SAFETY: transmutation is only used to erase the storage type,
+            // and `new_unchecked` is only called if the storage capacity can store the clone
+            __clone: unsafe {
+                ::core::mem::transmute::<fn(&__Storage) -> __Storage, unsafe fn()>(|__self| {
+                    let __clone = __Dyn::clone(__self.as_ref());
+                    let __can_store = const {
+                        <__Storage as #crate_::storage::SharedStorage>::CAPACITY.can_store::<__Dyn>()
+                    };
+                    if __can_store {
+                        __Storage::new_unchecked(__clone)
+                    } else {
+                        __Storage::try_new(__clone)
+                            .unwrap_or_else(|_| panic!("object cannot be cloned into its storage"))
+                    }
+                })
+            },
+        }
+    }
+
+    fn clone_impl(&self) -> TokenStream {
+        fields!(self => crate_, r#trait);
+        let dyn_trait = self.dyn_trait();
+        let generics = self.generics();
+        let where_clause = &r#trait.generics.where_clause;
+        quote! {
+            // SAFETY: `__clone` is initialized with the storage used to build the vtable
+            unsafe impl<#(#generics,)*> #crate_::object::DynClone for dyn #dyn_trait #where_clause {
+                fn clone_fn<__Storage: #crate_::storage::SharedStorage>(
+                    vtable: &Self::Vtable,
+                ) -> unsafe fn(&__Storage) -> __Storage {
+                    // SAFETY: transmutation is only used to erase the storage type
+                    unsafe {
+                        ::core::mem::transmute::<unsafe fn(), unsafe fn(&__Storage) -> __Storage>(
+                            vtable.__clone,
+                        )
+                    }
+                }
+            }
+        }
+    }
+
+    fn downcast_impl(&self) -> TokenStream {
+        fields!(self => crate_, r#trait);
+        let dyn_trait = self.dyn_trait();
+        let generics = self.generics();
+        let where_clause = &r#trait.generics.where_clause;
+        quote! {
+            // SAFETY: `__type_id` is initialized with the concrete type of the vtable
+            unsafe impl<#(#generics,)*> #crate_::object::DynDowncast for dyn #dyn_trait #where_clause {
+                fn type_id(vtable: &Self::Vtable) -> ::core::any::TypeId {
+                    vtable.__type_id
+                }
+            }
+        }
+    }
+
     fn vtable_field(&self, method: &TraitItemFn) -> TokenStream {
         let method_name = &method.sig.ident;
         quote!(#method_name: unsafe fn())
@@ -186,12 +546,19 @@ impl<'a> DynObject<'a> {
         let method_name = &method.sig.ident;
         let args = fn_args(&method.sig).skip(1).collect_vec();
         let erased_args = args.iter().map(|arg| quote!(::core::mem::transmute(#arg)));
+        let fn_ptr = vtable_fn_pointer(method, true);
+        // the vtable is built for any `SharedStorage`, but mutable receivers are only called
+        // by methods requiring a `Storage`, which doesn't share its data
         let self_as = match VtableReceiver::new(method) {
-            VtableReceiver::Ref => quote!(as_ref),
-            VtableReceiver::Mut => quote!(as_mut),
-            VtableReceiver::Pinned => quote!(as_pinned_mut),
+            VtableReceiver::Ref => quote!(__self.as_ref()),
+            VtableReceiver::Mut => quote!(__self.ptr_mut().cast::<__Dyn>().as_mut()),
+            VtableReceiver::Pinned => quote! {
+                ::core::pin::Pin::new_unchecked(
+                    __self.get_unchecked_mut().ptr_mut().cast::<__Dyn>().as_mut(),
+                )
+            },
+            VtableReceiver::Value => return self.vtable_consuming_method(method, fn_ptr),
         };
-        let fn_ptr = vtable_fn_pointer(method, true);
         quote! {
             #[allow(
                 clippy::missing_transmute_annotations,
@@ -202,47 +569,108 @@ impl<'a> DynObject<'a> {
             #method_name: unsafe {
                 ::core::mem::transmute::<#fn_ptr ,unsafe fn()>(
                     |__self, #(#args,)*| ::core::mem::transmute(
-                        __Dyn::#method_name(__self.#self_as(), #(#erased_args,)*)
+                        __Dyn::#method_name(#self_as, #(#erased_args,)*)
                     )
                 )
             }
         }
     }
 
+    fn vtable_consuming_method(&self, method: &TraitItemFn, fn_ptr: TokenStream) -> TokenStream {
+        let method_name = &method.sig.ident;
+        let args = fn_args(&method.sig).skip(1).collect_vec();
+        let erased_args = args.iter().map(|arg| quote!(::core::mem::transmute(#arg)));
+        quote! {
+            #[allow(
+                clippy::missing_transmute_annotations,
+                clippy::useless_transmute
+            )]
+            // SAFETY: transmutation are only used to erase lifetime,
+            // the real lifetime being enforced in the trait implementation;
+            // the method is only called with a `Storage`, so the object is moved out of it,
+            // which is then dropped in place, so the `DynObject` must not be dropped after the call
+            #method_name: unsafe {
+                ::core::mem::transmute::<#fn_ptr ,unsafe fn()>(
+                    |__self, #(#args,)*| {
+                        let __object = __self.ptr_mut().cast::<__Dyn>().read();
+                        __self.drop_object(None, ::core::alloc::Layout::new::<__Dyn>());
+                        ::core::mem::transmute(__Dyn::#method_name(__object, #(#erased_args,)*))
+                    }
+                )
+            }
+        }
+    }
+
     fn impl_method(&self, method: &TraitItemFn) -> ImplItemFn {
         let method_name = &method.sig.ident;
         let self_as = match VtableReceiver::new(method) {
             VtableReceiver::Ref => quote!(storage),
             VtableReceiver::Mut => quote!(storage_mut),
             VtableReceiver::Pinned => quote!(storage_pinned_mut),
+            VtableReceiver::Value => return self.impl_consuming_method(method),
         };
         let args = fn_args(&method.sig).skip(1);
         let fn_ptr = vtable_fn_pointer(method, false);
+        let vtable = self.vtable_expr(quote!(self));
         // SAFETY: the vtable method has been initialized with the given type
         let block = parse_quote!({ unsafe {
-            ::core::mem::transmute::<unsafe fn(), #fn_ptr>(self.vtable().#method_name)(
+            ::core::mem::transmute::<unsafe fn(), #fn_ptr>(#vtable.#method_name)(
                 self.#self_as(), #(#args,)*
             )
         } });
         impl_method(method.sig.clone(), block)
     }
 
+    fn impl_consuming_method(&self, method: &TraitItemFn) -> ImplItemFn {
+        let method_name = &method.sig.ident;
+        let args = fn_args(&method.sig).skip(1);
+        let fn_ptr = vtable_fn_pointer(method, false);
+        let vtable = self.vtable_expr(quote!(__this));
+        // SAFETY: the vtable method has been initialized with the given type,
+        // and the storage is dropped in place by the vtable method, so `self`
+        // must not be dropped
+        let block = parse_quote!({
+            let mut __this = ::core::mem::ManuallyDrop::new(self);
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), #fn_ptr>(#vtable.#method_name)(
+                    __this.storage_mut(), #(#args,)*
+                )
+            }
+        });
+        let mut sig = method.sig.clone();
+        if let Some(FnArg::Receiver(recv)) = sig.inputs.first_mut() {
+            recv.mutability = None;
+        }
+        impl_method(sig, block)
+    }
+
     fn impl_type(&self, (ty_param, ty): &(Ident, &TraitItemType)) -> TokenStream {
         let ty_name = &ty.ident;
         quote!(type #ty_name = #ty_param;)
     }
 }
 
+fn path_string(path: &Path) -> String {
+    path.to_token_stream().to_string()
+}
+
+fn paths_set(paths: &Punctuated<Path, Token![+]>) -> HashSet<String> {
+    paths.iter().map(path_string).collect()
+}
+
 enum VtableReceiver {
     Ref,
     Mut,
     Pinned,
+    Value,
 }
 
 impl VtableReceiver {
     fn new(method: &TraitItemFn) -> Self {
         let recv = method.sig.receiver().unwrap();
-        if recv.reference.is_none() {
+        if recv.reference.is_none() && pinned_ref(&recv.ty).is_none() {
+            Self::Value
+        } else if recv.reference.is_none() {
             Self::Pinned
         } else if recv.mutability.is_some() {
             Self::Mut
@@ -267,12 +695,12 @@ fn vtable_fn_pointer(method: &TraitItemFn, new_vtable: bool) -> TokenStream {
     let unsafety = &method.sig.unsafety;
     let recv = method.sig.receiver().unwrap();
     let recv_lt = match &recv.reference {
-        Some((_, lt)) => lt,
-        None => &pinned_ref(&recv.ty).unwrap().lifetime,
+        Some((_, lt)) => lt.as_ref(),
+        None => pinned_ref(&recv.ty).and_then(|r| r.lifetime.as_ref()),
     };
     let storage = match VtableReceiver::new(method) {
         VtableReceiver::Ref => quote!(&#recv_lt __Storage),
-        VtableReceiver::Mut => quote!(&#recv_lt mut __Storage),
+        VtableReceiver::Mut | VtableReceiver::Value => quote!(&#recv_lt mut __Storage),
         VtableReceiver::Pinned => quote!(::core::pin::Pin<&#recv_lt mut __Storage>),
     };
     let params = method
@@ -1,41 +1,70 @@
-use std::collections::HashSet;
+use std::{
+    collections::{HashMap, HashSet},
+    mem, slice,
+};
 
 use heck::ToPascalCase;
 use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote};
+use quote::{ToTokens, format_ident, quote};
 use syn::{
-    Attribute, CapturedParam, GenericParam, Generics, ImplItem, ImplItemFn, ItemTrait, Lifetime,
-    LifetimeParam, Path, Receiver, Signature, Token, TraitItem, TraitItemConst, TraitItemFn,
-    TraitItemType, Type, TypeImplTrait, TypeParamBound, TypeReference, TypeTraitObject,
-    WherePredicate, meta::ParseNestedMeta, parse_quote, parse_quote_spanned, spanned::Spanned,
-    visit_mut::VisitMut,
+    Attribute, CapturedParam, FnArg, GenericArgument, GenericParam, Generics, ImplItem, ImplItemFn,
+    ItemTrait, Lifetime, LifetimeParam, Path, PathArguments, Receiver, ReturnType, Signature,
+    Token, TraitBoundModifier, TraitItem, TraitItemConst, TraitItemFn, TraitItemType, Type,
+    TypeImplTrait, TypeParamBound, TypePath, TypeReference, TypeTraitObject, WherePredicate,
+    meta::ParseNestedMeta, parse_quote, parse_quote_spanned, punctuated::Punctuated,
+    spanned::Spanned, visit::Visit, visit_mut::VisitMut,
 };
 
 use crate::{
     MacroArgs, crate_name,
+    inlined::{inline_fn, is_inline_const},
     macros::{bail, bail_method, fields, try_match},
     sync::{is_sync_const, sync_fn},
     utils::{
-        IteratorExt, PatternAsArg, fn_args, future_output, impl_method, is_dispatchable,
-        is_not_generic, last_segment, return_type,
+        IteratorExt, PatternAsArg, desugar_async, fn_args, future_output, impl_method,
+        is_dispatchable, is_not_generic, iterator_item, last_segment, return_type,
     },
 };
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub(super) struct DynTraitOpts {
+    bridge: bool,
     crate_: Option<Path>,
+    factory: bool,
+    layouts: bool,
     remote: Option<Path>,
     name_template: Option<String>,
+    variants: Option<Variants>,
+}
+
+/// Variants of the trait to make dyn-compatible.
+#[derive(Clone, Copy, Default)]
+struct Variants {
+    local: bool,
+    send: bool,
 }
 
 impl MacroArgs for DynTraitOpts {
     fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
-        if meta.path.is_ident("crate") {
+        if meta.path.is_ident("bridge") {
+            self.bridge = true;
+        } else if meta.path.is_ident("crate") {
             meta.input.parse::<Token![=]>()?;
             self.crate_ = Some(meta.input.parse()?);
+        } else if meta.path.is_ident("factory") {
+            self.factory = true;
+        } else if meta.path.is_ident("layouts") {
+            self.layouts = true;
         } else if meta.path.is_ident("remote") {
             meta.input.parse::<Token![=]>()?;
             self.remote = Some(meta.input.parse()?);
+        } else if meta.path.is_ident("send") {
+            self.variants = Some(Variants {
+                local: true,
+                send: true,
+            });
+        } else if meta.path.is_ident("storage") {
+            bail!(meta.path, "`storage` is only supported at method level");
         } else if meta.path.is_ident("trait") {
             meta.input.parse::<Token![=]>()?;
             self.name_template = Some(if meta.input.peek(syn::Ident) {
@@ -45,6 +74,19 @@ impl MacroArgs for DynTraitOpts {
             } else {
                 bail!(meta.input.span(), "invalid trait name");
             });
+        } else if meta.path.is_ident("variants") {
+            let mut variants = Variants::default();
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("local") {
+                    variants.local = true;
+                } else if meta.path.is_ident("send") {
+                    variants.send = true;
+                } else {
+                    bail!(meta.path, "unknown variant");
+                }
+                Ok(())
+            })?;
+            self.variants = Some(variants);
         } else {
             bail!(meta.path, "unknown attribute");
         }
@@ -54,9 +96,46 @@ impl MacroArgs for DynTraitOpts {
 
 pub(super) fn dyn_trait_impl(
     mut r#trait: ItemTrait,
-    opts: DynTraitOpts,
+    mut opts: DynTraitOpts,
 ) -> syn::Result<TokenStream> {
-    let dyn_trait_attrs = extract_dyn_trait_attrs(&mut r#trait)?;
+    let Some(variants) = opts.variants.take().filter(|v| v.send) else {
+        return dyn_trait_variant_impl(&mut r#trait, opts);
+    };
+    if let Some(remote) = &opts.remote {
+        bail!(
+            remote,
+            "`send` variant is not supported in combination to `remote`"
+        );
+    }
+    let mut send_trait = send_variant(&r#trait);
+    let local_trait = if variants.local {
+        let local_trait = dyn_trait_variant_impl(&mut r#trait, opts.clone())?;
+        // layouts of the returned objects are the same for both variants
+        opts.layouts = false;
+        local_trait
+    } else {
+        extract_dyn_trait_attrs(&mut r#trait)?;
+        for method in r#trait
+            .items
+            .iter_mut()
+            .filter_map(try_match!(TraitItem::Fn))
+        {
+            (method.attrs).retain(|attr| !attr.path().is_ident("dyn_trait"));
+        }
+        r#trait.to_token_stream()
+    };
+    // built after the local variant, to forward its hidden `maybe_sync`/`maybe_inline` items
+    let blanket_impl = send_blanket_impl(&r#trait, &send_trait);
+    let send_trait = dyn_trait_variant_impl(&mut send_trait, opts)?;
+    Ok(quote! {
+        #local_trait
+        #send_trait
+        #blanket_impl
+    })
+}
+
+fn dyn_trait_variant_impl(r#trait: &mut ItemTrait, opts: DynTraitOpts) -> syn::Result<TokenStream> {
+    let dyn_trait_attrs = extract_dyn_trait_attrs(r#trait)?;
     if !r#trait.generics.params.is_empty()
         && (dyn_trait_attrs.iter())
             .any(|attr| last_segment(attr.meta.path(), "dyn_object").is_some())
@@ -66,32 +145,61 @@ pub(super) fn dyn_trait_impl(
             "generic parameters are not supported in combination to `dyn_object`"
         );
     }
-    let mut dyn_trait = DynTrait::new(&r#trait, opts);
+    let mut dyn_trait = DynTrait::new(r#trait, opts);
     for item in r#trait.items.iter_mut() {
         match item {
             TraitItem::Type(ty) if is_not_generic(ty) => {
                 dyn_trait.parse_type(ty);
             }
-            TraitItem::Fn(method) if is_dispatchable(method) => {
-                dyn_trait.parse_method(method)?;
+            TraitItem::Fn(method) => {
+                let erased = erase_generic_args(&dyn_trait.crate_, &method.sig);
+                if let Some(sig) = erased.filter(is_dispatchable) {
+                    dyn_trait.parse_method(method, sig)?;
+                } else if dyn_trait.bridge && method.default.is_none() {
+                    dyn_trait.parse_non_dispatchable_method(method)?;
+                }
+            }
+            TraitItem::Type(ty) if dyn_trait.bridge => {
+                bail!(ty.ident, "generic associated type cannot be bridged");
+            }
+            TraitItem::Const(cst) if dyn_trait.bridge && cst.default.is_none() => {
+                bail!(
+                    cst.ident,
+                    "associated constant without default cannot be bridged"
+                );
             }
             _ => {}
         }
     }
-    r#trait.items.extend(dyn_trait.additional_trait_items);
+    if dyn_trait.factory {
+        let methods = (r#trait.items.iter_mut())
+            .filter_map(try_match!(TraitItem::Fn))
+            .filter(|method| method.sig.receiver().is_none());
+        for method in methods {
+            dyn_trait.parse_factory_method(&r#trait.generics, method)?;
+        }
+    }
+    r#trait
+        .items
+        .extend(mem::take(&mut dyn_trait.additional_trait_items));
 
-    let opt_trait = dyn_trait.include_trait.then_some(&r#trait);
+    let opt_trait = dyn_trait.include_trait.then_some(&*r#trait);
     fields!(dyn_trait => remote, dyn_trait_name, dyn_items, impl_items);
-    fields!(r#trait => ident, unsafety, vis, supertraits);
+    fields!(r#trait => ident, unsafety, vis, colon_token, supertraits);
     let (_, trait_ty_gen, where_clause) = r#trait.generics.split_for_impl();
     let dyn_param = parse_quote!(__Dyn: #remote #trait_ty_gen);
 
     let mut dyn_generics = r#trait.generics.clone();
-    dyn_generics.params.extend(dyn_trait.generic_storages);
+    dyn_generics
+        .params
+        .extend(mem::take(&mut dyn_trait.generic_storages));
     let mut impl_generics = dyn_generics.clone();
     impl_generics.params.push(dyn_param);
     let (_, dyn_ty_gen, _) = dyn_generics.split_for_impl();
     let (impl_impl_gen, _, _) = impl_generics.split_for_impl();
+    let bridge_impls = (dyn_trait.bridge).then(|| dyn_trait.bridge_impls(r#trait, &dyn_generics));
+    let factory = (dyn_trait.factory).then(|| dyn_trait.factory(r#trait));
+    let layouts = (dyn_trait.layouts).then(|| dyn_trait.layouts(r#trait));
 
     Ok(quote! {
         #opt_trait
@@ -99,14 +207,21 @@ pub(super) fn dyn_trait_impl(
         #[doc = "Dyn-compatible implementation of"]
         #[doc = ::core::concat!("[`", stringify!(#ident), "`](", stringify!(#remote), ").")]
         #(#dyn_trait_attrs)*
-        #vis #unsafety trait #dyn_trait_name #dyn_generics #supertraits #where_clause { #(#dyn_items)* }
+        #vis #unsafety trait #dyn_trait_name #dyn_generics #colon_token #supertraits #where_clause { #(#dyn_items)* }
 
         #unsafety impl #impl_impl_gen #dyn_trait_name #dyn_ty_gen for __Dyn #where_clause { #(#impl_items)* }
+
+        #bridge_impls
+
+        #factory
+
+        #layouts
     })
 }
 
 struct DynTrait {
     include_trait: bool,
+    bridge: bool,
     dyn_trait_name: Ident,
     crate_: Path,
     remote: Path,
@@ -115,6 +230,46 @@ struct DynTrait {
     dyn_items: Vec<TraitItem>,
     impl_items: Vec<ImplItem>,
     generic_storages: Vec<GenericParam>,
+    types: Vec<TraitItemType>,
+    bridge_methods: Vec<ImplItemFn>,
+    bridge_forwards: Vec<ImplItemFn>,
+    bridge_auto_traits: BridgeAutoTraits,
+    factory: bool,
+    factory_items: Vec<TraitItem>,
+    factory_impl_items: Vec<ImplItem>,
+    factory_storages: Vec<GenericParam>,
+    layouts: bool,
+    layout_consts: Vec<(String, Ident, TokenStream)>,
+}
+
+/// Constraints on the trait objects and pointers the original trait can be bridged to.
+#[derive(Default)]
+struct BridgeAutoTraits {
+    /// Some returned futures must be `Send` while borrowing the trait object immutably.
+    sync: bool,
+    /// Some returned futures must be `Send` while borrowing the trait object mutably.
+    send: bool,
+    /// Some methods borrow the trait object mutably.
+    mutable: bool,
+    /// Some methods are not forwarded, because pinned or only callable on sized types.
+    no_pointer: bool,
+}
+
+impl BridgeAutoTraits {
+    fn update(&mut self, dyn_method: &DynMethod) {
+        let recv = dyn_method.orig_sig.receiver().unwrap();
+        if dyn_method.is_send_future_bridge() {
+            match recv.mutability {
+                None if recv.reference.is_some() => self.sync = true,
+                _ => self.send = true,
+            }
+        }
+        if recv.reference.is_none() {
+            self.no_pointer = true;
+        } else if recv.mutability.is_some() {
+            self.mutable = true;
+        }
+    }
 }
 
 impl DynTrait {
@@ -122,6 +277,7 @@ impl DynTrait {
         let template = opts.name_template.as_deref().unwrap_or("Dyn{}");
         Self {
             include_trait: opts.remote.is_none(),
+            bridge: opts.bridge,
             dyn_trait_name: format_ident!("{}", template.replace("{}", &r#trait.ident.to_string())),
             crate_: opts.crate_.unwrap_or_else(crate_name),
             remote: opts.remote.unwrap_or_else(|| r#trait.ident.clone().into()),
@@ -132,10 +288,21 @@ impl DynTrait {
             dyn_items: Vec::new(),
             impl_items: Vec::new(),
             generic_storages: Vec::new(),
+            types: Vec::new(),
+            bridge_methods: Vec::new(),
+            bridge_forwards: Vec::new(),
+            bridge_auto_traits: BridgeAutoTraits::default(),
+            factory: opts.factory,
+            factory_items: Vec::new(),
+            factory_impl_items: Vec::new(),
+            factory_storages: Vec::new(),
+            layouts: opts.layouts,
+            layout_consts: Vec::new(),
         }
     }
 
     fn parse_type(&mut self, ty: &TraitItemType) {
+        self.types.push(ty.clone());
         self.dyn_items.push(ty.clone().into());
         let remote = &self.remote;
         let ty_name = &ty.ident;
@@ -143,12 +310,30 @@ impl DynTrait {
             .push(parse_quote!(type #ty_name = <__Dyn as #remote>::#ty_name;));
     }
 
-    fn parse_method(&mut self, method: &mut TraitItemFn) -> syn::Result<()> {
+    fn parse_method(&mut self, method: &mut TraitItemFn, sig: Signature) -> syn::Result<()> {
         let attrs = MethodAttrs::parse(method)?;
-        let dyn_method = DynMethod::new(&self.crate_, &self.trait_generics, method);
+        let dyn_method = DynMethod::new(&self.crate_, &self.trait_generics, method, sig)?;
         self.generic_storages
-            .extend(dyn_method.generic_storage(attrs.storage));
-        if attrs.maybe_sync {
+            .extend(dyn_method.generic_storages(attrs.storage));
+        if self.layouts {
+            self.layout_consts.extend(dyn_method.layout_consts());
+        }
+        if self.bridge {
+            (self.bridge_methods).push(dyn_method.bridge_method(&self.dyn_trait_name)?);
+            (self.bridge_forwards).push(dyn_method.bridge_forward(&self.remote));
+            self.bridge_auto_traits.update(&dyn_method);
+        }
+        if let Some(inline_ty) = attrs.maybe_inline {
+            let inline_ty = dyn_method.inline_type(inline_ty)?;
+            self.additional_trait_items
+                .push(dyn_method.inline_method(&inline_ty).into());
+            self.additional_trait_items
+                .push(dyn_method.is_inline_const().into());
+            self.impl_items
+                .push(dyn_method.maybe_inline_impl(&inline_ty).into());
+            self.dyn_items
+                .push(dyn_method.maybe_inline_method(&inline_ty).into());
+        } else if attrs.maybe_sync {
             self.additional_trait_items
                 .push(dyn_method.sync_method()?.into());
             self.additional_trait_items
@@ -163,18 +348,333 @@ impl DynTrait {
     }
 }
 
+impl DynTrait {
+    fn parse_non_dispatchable_method(&mut self, method: &TraitItemFn) -> syn::Result<()> {
+        let is_sized = |pred: &WherePredicate| {
+            try_match!(pred, WherePredicate::Type(p) => p).is_some_and(|p| {
+                p.bounded_ty == parse_quote!(Self)
+                    && (p.bounds.iter()).any(|b| *b == parse_quote!(Sized))
+            })
+        };
+        let where_clause = &method.sig.generics.where_clause;
+        if !where_clause
+            .iter()
+            .any(|w| w.predicates.iter().any(is_sized))
+        {
+            bail_method!(method, "method is not dyn-compatible, it cannot be bridged");
+        }
+        // methods only callable on sized types are not required for trait objects
+        self.bridge_auto_traits.no_pointer = true;
+        Ok(())
+    }
+
+    /// Parses a method without receiver into a method of the factory trait; methods returning
+    /// `Self` construct trait objects of the generated trait.
+    fn parse_factory_method(
+        &mut self,
+        trait_generics: &Generics,
+        method: &mut TraitItemFn,
+    ) -> syn::Result<()> {
+        let attrs = MethodAttrs::parse(method)?;
+        if attrs.maybe_sync {
+            bail_method!(method, "`maybe_sync` must be used on async methods");
+        }
+        // `Self` bounds, except `Sized`, cannot be satisfied by the factory
+        let bounds_self = |pred: &WherePredicate| {
+            try_match!(pred, WherePredicate::Type(p) => p)
+                .is_some_and(|p| Nested::SelfType.is_contained(&p.bounded_ty))
+        };
+        let is_sized = |pred: &WherePredicate| {
+            try_match!(pred, WherePredicate::Type(p) => p).is_some_and(|p| {
+                Nested::SelfType.is(&p.bounded_ty)
+                    && (p.bounds.iter()).all(|b| *b == parse_quote!(Sized))
+            })
+        };
+        let crate_ = &self.crate_;
+        let is_supported = |sig: &Signature| {
+            sig.asyncness.is_none()
+                && (sig.generics.params.iter()).all(|p| matches!(p, GenericParam::Lifetime(_)))
+                && (sig.generics.where_clause.iter())
+                    .flat_map(|w| w.predicates.iter())
+                    .all(|pred| is_sized(pred) || !bounds_self(pred))
+                && !(sig.inputs.iter())
+                    .filter_map(try_match!(FnArg::Typed))
+                    .any(|arg| Nested::SelfType.is_contained(&arg.ty))
+                && !return_type(sig).is_some_and(contains_impl_trait)
+                && return_type(sig).is_none_or(|ret| {
+                    let conversion =
+                        Nested::SelfType.conversion(crate_, ret, quote!(), &mut vec![]);
+                    conversion.is_ok()
+                })
+        };
+        let Some(mut sig) = erase_generic_args(&self.crate_, &method.sig).filter(is_supported)
+        else {
+            return Ok(());
+        };
+        fields!(self => crate_, dyn_trait_name);
+        PatternAsArg.visit_signature_mut(&mut sig);
+        sig.inputs.insert(0, parse_quote!(&self));
+        if let Some(where_clause) = &mut sig.generics.where_clause {
+            (where_clause.predicates) = mem::take(&mut where_clause.predicates)
+                .into_iter()
+                .filter(|pred| !is_sized(pred))
+                .collect();
+            if where_clause.predicates.is_empty() {
+                sig.generics.where_clause = None;
+            }
+        }
+        let method_name = &method.sig.ident;
+        let args = fn_args(&method.sig);
+        let call = quote!(__Dyn::#method_name(#(#args,)*));
+        let mut block = parse_quote!({ #call });
+        if let ReturnType::Type(_, ret) = &mut sig.output {
+            let mut self_types = Vec::new();
+            let conversion =
+                Nested::SelfType.conversion(crate_, ret, quote!(__ret), &mut self_types)?;
+            if let Some(conversion) = conversion {
+                let storage_name = method_name.to_string().to_pascal_case();
+                let storages = if Nested::SelfType.is(ret) {
+                    vec![format_ident!("__Storage{storage_name}")]
+                } else {
+                    (0..self_types.len())
+                        .map(|i| format_ident!("__Storage{storage_name}{i}"))
+                        .collect()
+                };
+                let default_storage = (attrs.storage.clone())
+                    .unwrap_or_else(|| parse_quote!(#crate_::storage::DefaultStorage));
+                self.factory_storages
+                    .extend(storages.iter().map(|storage| -> GenericParam {
+                        parse_quote_spanned! { default_storage.span() =>
+                            #storage: #crate_::storage::Storage = #default_storage
+                        }
+                    }));
+                let dyn_args = (trait_generics.params.iter())
+                    .map(|param| match param {
+                        GenericParam::Lifetime(p) => p.lifetime.to_token_stream(),
+                        GenericParam::Type(p) => p.ident.to_token_stream(),
+                        GenericParam::Const(p) => p.ident.to_token_stream(),
+                    })
+                    .chain((self.types.iter()).map(|ty| {
+                        let name = &ty.ident;
+                        quote!(#name = Self::#name)
+                    }));
+                let dyn_ty = quote!(dyn #dyn_trait_name<#(#dyn_args),*>);
+                let objects = storages
+                    .iter()
+                    .map(|storage| parse_quote!(#crate_::DynObject<#dyn_ty, #storage>));
+                Nested::SelfType.replace(ret, objects);
+                block = parse_quote!({
+                    let __ret = #call;
+                    #conversion
+                });
+            }
+        }
+        self.factory_items.push(
+            TraitItemFn {
+                attrs: method.attrs.clone(),
+                sig: sig.clone(),
+                default: None,
+                semi_token: Some(Default::default()),
+            }
+            .into(),
+        );
+        self.factory_impl_items.push(impl_method(sig, block).into());
+        Ok(())
+    }
+
+    /// Returns the factory trait, implemented for `Factory<T>` of every implementation `T` of
+    /// the original trait.
+    fn factory(&self, r#trait: &ItemTrait) -> TokenStream {
+        fields!(self => crate_, remote, dyn_trait_name, factory_items, factory_impl_items);
+        fields!(r#trait => ident, vis);
+        let factory_name = format_ident!("{dyn_trait_name}Factory");
+        let (_, trait_ty_gen, where_clause) = r#trait.generics.split_for_impl();
+        let mut factory_generics = r#trait.generics.clone();
+        (factory_generics.params).extend(self.factory_storages.iter().cloned());
+        let mut impl_generics = factory_generics.clone();
+        (impl_generics.params.iter_mut()).for_each(|param| match param {
+            GenericParam::Lifetime(_) => {}
+            GenericParam::Type(p) => p.default = None,
+            GenericParam::Const(p) => p.default = None,
+        });
+        (impl_generics.params).push(parse_quote!(__Dyn: #remote #trait_ty_gen + 'static));
+        let (_, factory_ty_gen, _) = factory_generics.split_for_impl();
+        let (impl_gen, _, _) = impl_generics.split_for_impl();
+        let types = (self.types.iter()).map(|ty| {
+            let mut ty = ty.clone();
+            ty.default = None;
+            ty
+        });
+        let impl_types = (self.types.iter()).map(|ty| {
+            let ty_name = &ty.ident;
+            quote!(type #ty_name = <__Dyn as #remote>::#ty_name;)
+        });
+        quote! {
+            #[doc = "Dyn-compatible factory of"]
+            #[doc = ::core::concat!("[`", stringify!(#ident), "`](", stringify!(#remote), ")")]
+            #[doc = "implementations."]
+            #vis trait #factory_name #factory_generics #where_clause {
+                #(#types)*
+                #(#factory_items)*
+            }
+
+            impl #impl_gen #factory_name #factory_ty_gen for #crate_::Factory<__Dyn> #where_clause {
+                #(#impl_types)*
+                #(#factory_impl_items)*
+            }
+        }
+    }
+
+    /// Returns the trait exposing the layouts of the objects returned by the implementations,
+    /// and its blanket implementation.
+    fn layouts(&self, r#trait: &ItemTrait) -> TokenStream {
+        fields!(self => remote, dyn_trait_name, layout_consts);
+        fields!(r#trait => ident, vis);
+        let layouts_name = format_ident!("{dyn_trait_name}Layouts");
+        let (_, trait_ty_gen, where_clause) = r#trait.generics.split_for_impl();
+        let mut impl_generics = r#trait.generics.clone();
+        (impl_generics.params).push(parse_quote!(__Dyn: #remote #trait_ty_gen));
+        let (impl_gen, _, _) = impl_generics.split_for_impl();
+        let trait_gen = &r#trait.generics;
+        let consts = (layout_consts.iter()).map(|(name, cst, _)| {
+            let doc = format!("Layout of the object returned by `{name}`.");
+            quote!(#[doc = #doc] const #cst: ::core::alloc::Layout;)
+        });
+        let impl_consts = (layout_consts.iter())
+            .map(|(_, cst, layout)| quote!(const #cst: ::core::alloc::Layout = #layout;));
+        let layouts = (layout_consts.iter())
+            .map(|(name, cst, _)| quote!((#name, <Self as #layouts_name #trait_ty_gen>::#cst)));
+        quote! {
+            #[doc = "Layouts of the objects returned by"]
+            #[doc = ::core::concat!("[`", stringify!(#ident), "`](", stringify!(#remote), ")")]
+            #[doc = "implementations, to be stored in `DynObject`."]
+            #vis trait #layouts_name #trait_gen #where_clause {
+                #(#consts)*
+                /// Layouts of all the returned objects, with the name of their method.
+                const LAYOUTS: &'static [(&'static str, ::core::alloc::Layout)];
+            }
+
+            impl #impl_gen #layouts_name #trait_ty_gen for __Dyn #where_clause {
+                #(#impl_consts)*
+                const LAYOUTS: &'static [(&'static str, ::core::alloc::Layout)] = &[#(#layouts,)*];
+            }
+        }
+    }
+
+    /// Returns the implementations of the original trait for the trait objects of the
+    /// generated trait, as well as for references and smart pointers to them.
+    fn bridge_impls(&self, r#trait: &ItemTrait, dyn_generics: &Generics) -> TokenStream {
+        fields!(self => crate_, remote, dyn_trait_name, bridge_auto_traits);
+        fields!(r#trait => unsafety, supertraits);
+        let (_, trait_ty_gen, _) = r#trait.generics.split_for_impl();
+        let type_params = (self.types.iter())
+            .map(|ty| (format_ident!("__Type{}", ty.ident), &ty.ident, &ty.bounds))
+            .collect_vec();
+        let mut generics = dyn_generics.clone();
+        generics.params.insert(0, parse_quote!('__lt));
+        (generics.params.iter_mut()).for_each(|param| match param {
+            GenericParam::Lifetime(_) => {}
+            GenericParam::Type(p) => p.default = None,
+            GenericParam::Const(p) => p.default = None,
+        });
+        generics.params.extend(
+            type_params
+                .iter()
+                .map(|(param, _, bounds)| -> GenericParam {
+                    // https://github.com/dtolnay/syn/issues/1952
+                    if bounds.is_empty() {
+                        parse_quote!(#param)
+                    } else {
+                        parse_quote!(#param: #bounds)
+                    }
+                }),
+        );
+        let dyn_args = (dyn_generics.params.iter())
+            .map(|param| match param {
+                GenericParam::Lifetime(p) => p.lifetime.to_token_stream(),
+                GenericParam::Type(p) => p.ident.to_token_stream(),
+                GenericParam::Const(p) => p.ident.to_token_stream(),
+            })
+            .chain((type_params.iter()).map(|(param, name, _)| quote!(#name = #param)));
+        let dyn_trait = quote!(#dyn_trait_name<#(#dyn_args),*>);
+        let types = (type_params.iter()).map(|(param, name, _)| quote!(type #name = #param;));
+        let types = quote!(#(#types)*);
+        let methods = &self.bridge_methods;
+        let forwards = &self.bridge_forwards;
+        let mut where_clause = r#trait.generics.where_clause.clone();
+        let (impl_gen, _, _) = generics.split_for_impl();
+        let send = quote!(+ ::core::marker::Send);
+        let sync = quote!(+ ::core::marker::Sync);
+        let dyn_types = [(false, false), (true, false), (true, true)]
+            .into_iter()
+            .filter(|&(is_send, is_sync)| {
+                (is_send || !bridge_auto_traits.send) && (is_sync || !bridge_auto_traits.sync)
+            })
+            .map(|(is_send, is_sync)| {
+                let (send, sync) = (is_send.then_some(&send), is_sync.then_some(&sync));
+                quote!(dyn #dyn_trait + '__lt #send #sync)
+            })
+            .collect_vec();
+        let mut impls = (dyn_types.iter())
+            .map(|dyn_ty| {
+                quote! {
+                    #unsafety impl #impl_gen #remote #trait_ty_gen for #dyn_ty #where_clause {
+                        #types
+                        #(#methods)*
+                    }
+                }
+            })
+            .collect_vec();
+        if bridge_auto_traits.no_pointer {
+            return quote!(#(#impls)*);
+        }
+        if !supertraits.is_empty() {
+            (where_clause
+                .get_or_insert_with(|| parse_quote!(where))
+                .predicates)
+                .push(parse_quote!(Self: #supertraits));
+        }
+        let mut ptr_generics = generics.clone();
+        ptr_generics.params.insert(0, parse_quote!('__ref));
+        let (ptr_impl_gen, _, _) = ptr_generics.split_for_impl();
+        for dyn_ty in &dyn_types {
+            let ptr_impl = |ptr: TokenStream, impl_gen: &dyn ToTokens| {
+                quote! {
+                    #unsafety impl #impl_gen #remote #trait_ty_gen for #ptr #where_clause {
+                        #types
+                        #(#forwards)*
+                    }
+                }
+            };
+            let boxed = ptr_impl(quote!(#crate_::__private::Box<#dyn_ty>), &impl_gen);
+            impls.push(quote!(#crate_::__cfg_alloc! { #boxed }));
+            if !bridge_auto_traits.mutable {
+                impls.push(ptr_impl(quote!(&'__ref (#dyn_ty)), &ptr_impl_gen));
+                // `Arc` is not a fundamental type, so it cannot implement a remote trait
+                if self.include_trait {
+                    let arc = ptr_impl(quote!(#crate_::__private::Arc<#dyn_ty>), &impl_gen);
+                    impls.push(quote!(#crate_::__cfg_arc! { #arc }));
+                }
+            }
+        }
+        quote!(#(#impls)*)
+    }
+}
+
 #[derive(Default)]
 pub(crate) struct MethodAttrs {
     storage: Option<Path>,
     maybe_sync: bool,
+    maybe_inline: Option<Option<Type>>,
 }
 
 impl MethodAttrs {
     fn parse(method: &mut TraitItemFn) -> syn::Result<Self> {
+        let is_constructor = method.sig.receiver().is_none();
         let has_rpit = method.sig.asyncness.is_some()
-            || return_type(&method.sig)
-                .and_then(try_match!(Type::ImplTrait))
-                .is_some();
+            || return_type(&method.sig).is_some_and(|ty| {
+                contains_impl_trait(ty) || (is_constructor && Nested::SelfType.is_contained(ty))
+            });
         let mut attrs = Self::default();
         for attr in (method.attrs).extract_if(.., |attr| attr.path().is_ident("dyn_trait")) {
             if !has_rpit {
@@ -187,75 +687,137 @@ impl MethodAttrs {
                     attrs.storage = Some(meta.input.parse()?);
                 } else if meta.path.is_ident("maybe_sync") {
                     attrs.maybe_sync = true
+                } else if meta.path.is_ident("maybe_inline") {
+                    attrs.maybe_inline =
+                        Some(if meta.input.parse::<Option<Token![=]>>()?.is_some() {
+                            Some(meta.input.parse()?)
+                        } else {
+                            None
+                        });
                 } else {
                     bail!(meta.path, "unknown attribute");
                 }
                 Ok(())
             })?;
         }
+        if attrs.maybe_sync && attrs.maybe_inline.is_some() {
+            bail_method!(method, "`maybe_sync` and `maybe_inline` cannot be combined");
+        }
         Ok(attrs)
     }
 }
 
 struct DynMethod<'a> {
     orig_sig: &'a Signature,
+    erased_sig: Signature,
     crate_: &'a Path,
     dyn_method: TraitItemFn,
     rpit: Option<TypeImplTrait>,
-    storage: Option<Ident>,
+    storages: Vec<Ident>,
+    /// `Send`/`Sync` bounds of the returned impl traits, required on their storages.
+    storage_bounds: Vec<Vec<Path>>,
+    /// Conversion of the returned value `__ret` when impl traits are nested in the returned type.
+    nested_conversion: Option<TokenStream>,
 }
 
 impl<'a> DynMethod<'a> {
-    fn new(crate_: &'a Path, trait_generics: &[Ident], method: &'a TraitItemFn) -> Self {
+    fn new(
+        crate_: &'a Path,
+        trait_generics: &[Ident],
+        method: &'a TraitItemFn,
+        sig: Signature,
+    ) -> syn::Result<Self> {
         let orig_sig = &method.sig;
+        let erased_sig = sig.clone();
         let mut method = TraitItemFn {
             attrs: method.attrs.clone(),
-            sig: method.sig.clone(),
+            sig,
             default: None,
             semi_token: None,
         };
         // patterns are not allowed without default
         PatternAsArg.visit_signature_mut(&mut method.sig);
-        // convert async fn to RPIT
-        if method.sig.asyncness.is_some() {
-            method.sig.asyncness = None;
-            let output = return_type(&method.sig).map_or_else(|| quote!(()), |ty| quote!(#ty));
-            method.sig.output = parse_quote!(-> impl Future<Output = #output>);
-        }
+        desugar_async(&mut method.sig);
+        let method_name = method.sig.ident.to_string().to_pascal_case();
         let rpit = return_type(&method.sig)
             .and_then(try_match!(Type::ImplTrait))
             .cloned();
-        let storage = (rpit.is_some())
-            .then(|| format_ident!("__Storage{}", method.sig.ident.to_string().to_pascal_case()));
-        if let Some(rpit) = &rpit {
-            Self::update_dyn_signature(crate_, trait_generics, &mut method.sig, rpit, &storage);
+        let mut storages = Vec::new();
+        let mut storage_bounds = Vec::new();
+        let mut nested_conversion = None;
+        if let Some(ret) = return_type(&method.sig).filter(|ty| contains_impl_trait(ty)) {
+            let mut ret = ret.clone();
+            let mut rpits = Vec::new();
+            let conversion =
+                Nested::ImplTrait.conversion(crate_, &ret, quote!(__ret), &mut rpits)?;
+            let rpits = (rpits.into_iter())
+                .filter_map(try_match!(Type::ImplTrait))
+                .collect_vec();
+            if rpit.is_some() {
+                storages.push(format_ident!("__Storage{method_name}"));
+            } else {
+                storages
+                    .extend((0..rpits.len()).map(|i| format_ident!("__Storage{method_name}{i}")));
+                nested_conversion = conversion;
+            }
+            storage_bounds = (rpits.iter())
+                .map(|rpit| {
+                    (rpit.bounds.iter())
+                        .filter_map(try_match!(TypeParamBound::Trait))
+                        .filter(|b| {
+                            ["Send", "Sync"]
+                                .iter()
+                                .any(|t| last_segment(&b.path, t).is_some())
+                        })
+                        .map(|b| b.path.clone())
+                        .collect()
+                })
+                .collect();
+            let dyn_rets = Self::update_dyn_signature(
+                crate_,
+                trait_generics,
+                &mut method.sig,
+                &rpits,
+                &storages,
+            );
+            Nested::ImplTrait.replace(&mut ret, dyn_rets);
+            method.sig.output = parse_quote!(-> #ret);
         }
-        Self {
+        Ok(Self {
             orig_sig,
+            erased_sig,
             crate_,
             dyn_method: method,
             rpit,
-            storage,
-        }
+            storages,
+            storage_bounds,
+            nested_conversion,
+        })
     }
 
+    /// Updates the signature for the returned impl traits, and returns their `DynObject`.
     fn update_dyn_signature(
         crate_: &Path,
         trait_generics: &[Ident],
         sig: &mut Signature,
-        rpit: &TypeImplTrait,
-        storage: &Option<Ident>,
-    ) {
-        let mut captured = CapturedLifetimes::new(rpit, &sig.generics);
-        let dyn_ret = TypeTraitObject {
-            dyn_token: Some(Default::default()),
-            bounds: (rpit.bounds.iter())
-                .filter(|b| matches!(b, TypeParamBound::Trait(_)))
-                .cloned()
-                .update(|bound| captured.visit_type_param_bound_mut(bound))
-                .chain([parse_quote!('__dyn)])
-                .collect(),
-        };
+        rpits: &[TypeImplTrait],
+        storages: &[Ident],
+    ) -> Vec<Type> {
+        let mut captured = CapturedLifetimes::new(rpits, &sig.generics);
+        let dyn_rets = (rpits.iter().zip(storages))
+            .map(|(rpit, storage)| {
+                let dyn_ret = TypeTraitObject {
+                    dyn_token: Some(Default::default()),
+                    bounds: (rpit.bounds.iter())
+                        .filter(|b| matches!(b, TypeParamBound::Trait(_)))
+                        .cloned()
+                        .update(|bound| captured.visit_type_param_bound_mut(bound))
+                        .chain([parse_quote!('__dyn)])
+                        .collect(),
+                };
+                parse_quote!(#crate_::DynObject<#dyn_ret, #storage>)
+            })
+            .collect();
         // All type parameters are captured, so their lifetime must be bounded
         sig.generics.make_where_clause().predicates.extend(
             (trait_generics.iter())
@@ -265,17 +827,63 @@ impl<'a> DynMethod<'a> {
         (sig.generics.params.iter_mut()).for_each(|param| captured.visit_generic_param_mut(param));
         sig.generics.params.insert(0, parse_quote!('__dyn));
         (sig.inputs.iter_mut()).for_each(|arg| captured.visit_fn_arg_mut(arg));
-        sig.output = parse_quote!(-> #crate_::DynObject<#dyn_ret, #storage>);
+        dyn_rets
     }
 
-    fn generic_storage(&self, default_storage: Option<Path>) -> Option<GenericParam> {
+    fn generic_storages(&self, default_storage: Option<Path>) -> Vec<GenericParam> {
         let crate_ = &self.crate_;
-        let storage = self.storage.as_ref()?;
         let default_storage =
             default_storage.unwrap_or_else(|| parse_quote!(#crate_::storage::DefaultStorage));
-        Some(parse_quote_spanned! { default_storage.span() =>
-            #storage: #crate_::storage::Storage = #default_storage
-        })
+        (self.storages.iter().zip(&self.storage_bounds))
+            .map(|(storage, bounds)| {
+                parse_quote_spanned! { default_storage.span() =>
+                    #storage: #crate_::storage::Storage #(+ #bounds)* = #default_storage
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the layout constants of the returned objects, with their name, computed from the
+    /// method of `__Dyn`; generic methods are not supported, as their arguments cannot be inferred.
+    fn layout_consts(&self) -> Vec<(String, Ident, TokenStream)> {
+        let crate_ = &self.crate_;
+        let method_name = &self.orig_sig.ident;
+        let is_generic = self.orig_sig.generics.type_params().next().is_some()
+            || (self.orig_sig.inputs.iter())
+                .filter_map(try_match!(FnArg::Typed))
+                .any(|arg| contains_impl_trait(&arg.ty));
+        if self.storages.is_empty() || is_generic {
+            return Vec::new();
+        }
+        let mut projections = Vec::new();
+        if self.rpit.is_some() {
+            projections.push(quote!(__ret));
+        } else if let Some(ret) = return_type(self.orig_sig) {
+            Nested::ImplTrait.projections(ret, quote!(__ret), &mut projections);
+        }
+        let args = self.orig_sig.inputs.iter().map(|_| quote!(_));
+        let args = quote!((#(#args,)*));
+        let const_name = method_name.to_string().to_uppercase();
+        let single = projections.len() == 1 && self.rpit.is_some();
+        (projections.iter().enumerate())
+            .map(|(i, projection)| {
+                let (name, cst) = if single {
+                    (method_name.to_string(), format_ident!("{const_name}"))
+                } else {
+                    (
+                        format!("{method_name}.{i}"),
+                        format_ident!("{const_name}_{i}"),
+                    )
+                };
+                let layout = quote! {
+                    #crate_::__private::return_layout::<#args, _, _>(
+                        &__Dyn::#method_name,
+                        &|__ret| #projection,
+                    )
+                };
+                (name, cst, layout)
+            })
+            .collect()
     }
 
     fn impl_method(&self) -> ImplItemFn {
@@ -285,12 +893,109 @@ impl<'a> DynMethod<'a> {
         let call = quote!(__Dyn::#method_name(#(#args,)*));
         let block = if self.rpit.is_some() {
             parse_quote!({ #crate_::DynObject::new(#call) })
+        } else if let Some(conversion) = &self.nested_conversion {
+            parse_quote!({
+                let __ret = #call;
+                #conversion
+            })
         } else {
             parse_quote!({ #call })
         };
         impl_method(self.dyn_method.sig.clone(), block)
     }
 
+    /// Whether the bridged method wraps the returned future in an async block.
+    ///
+    /// The returned `DynObject` lifetime is the intersection of the captured lifetimes, which
+    /// cannot be named in the hidden type of the bridged method, contrary to an async block
+    /// capturing the arguments.
+    fn is_future_bridge(&self) -> bool {
+        self.orig_sig.asyncness.is_some()
+            || self.rpit.as_ref().is_some_and(|rpit| {
+                future_output(rpit).is_some()
+                    && !(rpit.bounds.iter()).any(|b| matches!(b, TypeParamBound::PreciseCapture(_)))
+            })
+    }
+
+    /// Whether the bridged method returns a future wrapping a borrowed trait object, which must
+    /// be `Send`.
+    fn is_send_future_bridge(&self) -> bool {
+        self.is_future_bridge()
+            && (self.rpit.iter())
+                .flat_map(|rpit| rpit.bounds.iter())
+                .filter_map(try_match!(TypeParamBound::Trait))
+                .any(|b| last_segment(&b.path, "Send").is_some())
+    }
+
+    /// Returns the implementation of the original method for the trait objects, calling the
+    /// generated dyn-compatible method.
+    fn bridge_method(&self, dyn_trait_name: &Ident) -> syn::Result<ImplItemFn> {
+        let crate_ = &self.crate_;
+        let method_name = &self.orig_sig.ident;
+        let mut args = Vec::new();
+        let inputs = self.orig_sig.inputs.iter().zip(&self.erased_sig.inputs);
+        for (arg, (input, erased)) in fn_args(self.orig_sig).zip(inputs).skip(1) {
+            let (FnArg::Typed(input), FnArg::Typed(erased)) = (input, erased) else {
+                unreachable!()
+            };
+            args.push(match (input.ty.as_ref(), erased.ty.as_ref()) {
+                (ty, erased_ty) if ty == erased_ty => arg,
+                (Type::Reference(_), _) => {
+                    bail!(
+                        input.ty,
+                        "generic argument passed by reference cannot be bridged"
+                    )
+                }
+                _ if !self.is_future_bridge() && !self.storages.is_empty() => {
+                    let err =
+                        "generic argument cannot be bridged when returning non-future impl trait";
+                    bail!(input.ty, err)
+                }
+                (
+                    _,
+                    Type::Reference(TypeReference {
+                        mutability: None, ..
+                    }),
+                ) => quote!(&#arg),
+                (_, Type::Reference(_)) => quote!(&mut #arg),
+                _ => quote!(#crate_::DynObject::new(#arg)),
+            });
+        }
+        // generic storages are inferred from `Self`, as the trait object is not `Sized`
+        let call = quote!(#dyn_trait_name::#method_name(self, #(#args,)*));
+        let block = if self.orig_sig.asyncness.is_some() {
+            parse_quote!({ #call.await })
+        } else if self.is_future_bridge() {
+            parse_quote!({ async move { #call.await } })
+        } else {
+            parse_quote!({ #call })
+        };
+        let mut sig = self.orig_sig.clone();
+        PatternAsArg.visit_signature_mut(&mut sig);
+        Ok(impl_method(sig, block))
+    }
+
+    /// Returns the implementation of the original method for pointers to the trait objects,
+    /// forwarding the call to the pointee.
+    fn bridge_forward(&self, remote: &Path) -> ImplItemFn {
+        let method_name = &self.orig_sig.ident;
+        let args = fn_args(self.orig_sig).skip(1);
+        let recv = self.orig_sig.receiver().unwrap();
+        let this = match recv.mutability {
+            Some(_) => quote!(&mut **self),
+            None => quote!(&**self),
+        };
+        let call = quote!(#remote::#method_name(#this, #(#args,)*));
+        let block = if self.orig_sig.asyncness.is_some() {
+            parse_quote!({ #call.await })
+        } else {
+            parse_quote!({ #call })
+        };
+        let mut sig = self.orig_sig.clone();
+        PatternAsArg.visit_signature_mut(&mut sig);
+        impl_method(sig, block)
+    }
+
     fn sync_method(&self) -> syn::Result<TraitItemFn> {
         let Some(output) = self.rpit.as_ref().and_then(future_output) else {
             bail_method!(
@@ -312,6 +1017,81 @@ impl<'a> DynMethod<'a> {
         })
     }
 
+    /// Returns the inline type of `maybe_inline`, defaulting to `Option` iterator for iterators.
+    fn inline_type(&self, inline_ty: Option<Type>) -> syn::Result<Type> {
+        let Some(rpit) = &self.rpit else {
+            bail_method!(
+                self.dyn_method,
+                "`maybe_inline` must be used on methods returning impl trait"
+            );
+        };
+        if let Some(inline_ty) = inline_ty {
+            return Ok(inline_ty);
+        }
+        let Some(item) = iterator_item(rpit) else {
+            bail!(
+                rpit,
+                "`maybe_inline` requires an inline type for non-iterator impl trait"
+            );
+        };
+        Ok(parse_quote!(::core::option::IntoIter<#item>))
+    }
+
+    fn inline_method(&self, inline_ty: &Type) -> TraitItemFn {
+        let args = fn_args(self.orig_sig).skip(1);
+        let mut sig = self.orig_sig.clone();
+        PatternAsArg.visit_signature_mut(&mut sig);
+        TraitItemFn {
+            attrs: vec![parse_quote!(#[doc(hidden)])],
+            sig: Signature {
+                asyncness: None,
+                ident: inline_fn(self.orig_sig),
+                output: parse_quote!(-> #inline_ty),
+                ..sig
+            },
+            default: Some(parse_quote!({ #(let _ = #args;)* ::core::unimplemented!() })),
+            semi_token: None,
+        }
+    }
+
+    fn is_inline_const(&self) -> TraitItemConst {
+        let is_inline = is_inline_const(self.orig_sig);
+        parse_quote!(#[doc(hidden)] const #is_inline: bool = false;)
+    }
+
+    fn maybe_inline_signature(&self, inline_ty: &Type) -> Signature {
+        let crate_ = &self.crate_;
+        let rpits = slice::from_ref(self.rpit.as_ref().unwrap());
+        let mut inline_ty = inline_ty.clone();
+        CapturedLifetimes::new(rpits, &self.orig_sig.generics).visit_type_mut(&mut inline_ty);
+        let mut signature = self.dyn_method.sig.clone();
+        let output = return_type(&self.dyn_method.sig).unwrap();
+        signature.output = parse_quote!(-> #crate_::MaybeInline<#output, #inline_ty>);
+        signature
+    }
+
+    fn maybe_inline_method(&self, inline_ty: &Type) -> TraitItemFn {
+        let mut method = self.dyn_method.clone();
+        method.sig = self.maybe_inline_signature(inline_ty);
+        method
+    }
+
+    fn maybe_inline_impl(&self, inline_ty: &Type) -> ImplItemFn {
+        let crate_ = &self.crate_;
+        let is_inline = is_inline_const(self.orig_sig);
+        let inline_method = inline_fn(self.orig_sig);
+        let method = &self.orig_sig.ident;
+        let args = fn_args(self.orig_sig).collect_vec();
+        let block = parse_quote!({
+            if __Dyn::#is_inline {
+                #crate_::MaybeInline::Inline(__Dyn::#inline_method(#(#args),*))
+            } else {
+                #crate_::MaybeInline::Dyn(#crate_::DynObject::new(__Dyn::#method(#(#args,)*)))
+            }
+        });
+        impl_method(self.maybe_inline_signature(inline_ty), block)
+    }
+
     fn is_sync_const(&self) -> TraitItemConst {
         let is_sync = is_sync_const(self.orig_sig);
         parse_quote!(#[doc(hidden)] const #is_sync: bool = false;)
@@ -355,13 +1135,16 @@ pub struct CapturedLifetimes {
 }
 
 impl CapturedLifetimes {
-    pub(crate) fn new(ret: &TypeImplTrait, generics: &Generics) -> Self {
+    pub(crate) fn new(rets: &[TypeImplTrait], generics: &Generics) -> Self {
+        let precise_captures = (rets.iter())
+            .map(|ret| (ret.bounds.iter()).find_map(try_match!(TypeParamBound::PreciseCapture)))
+            .collect::<Option<Vec<_>>>();
         Self {
             dyn_lt: parse_quote!('__dyn),
             default_lt: parse_quote!('_),
-            captured: match (ret.bounds.iter()).find_map(try_match!(TypeParamBound::PreciseCapture))
-            {
-                Some(c) => (c.params.iter())
+            captured: match precise_captures {
+                Some(captures) => (captures.iter())
+                    .flat_map(|c| c.params.iter())
                     .filter_map(try_match!(CapturedParam::Lifetime(l) => l.clone()))
                     .collect(),
                 None => (generics.params.iter())
@@ -401,6 +1184,423 @@ impl VisitMut for CapturedLifetimes {
     }
 }
 
+/// Returns the `Send` variant of the trait, whose returned futures and other impl traits are
+/// `Send`.
+fn send_variant(r#trait: &ItemTrait) -> ItemTrait {
+    let mut send_trait = r#trait.clone();
+    send_trait.ident = format_ident!("{}Send", r#trait.ident);
+    if send_trait.colon_token.is_none() {
+        send_trait.colon_token = Some(Default::default());
+    }
+    (send_trait.supertraits).push(parse_quote!(::core::marker::Send));
+    for method in send_trait
+        .items
+        .iter_mut()
+        .filter_map(try_match!(TraitItem::Fn))
+    {
+        if method.sig.asyncness.is_some() {
+            desugar_async(&mut method.sig);
+            if let Some(block) = &method.default {
+                method.default = Some(parse_quote!({ async move #block }));
+            }
+        }
+        if let ReturnType::Type(_, ty) = &mut method.sig.output {
+            SendImplTraits.visit_type_mut(ty);
+        }
+    }
+    send_trait
+}
+
+/// Returns the implementation of the original trait for every implementation of its `Send`
+/// variant.
+fn send_blanket_impl(r#trait: &ItemTrait, send_trait: &ItemTrait) -> TokenStream {
+    fields!(r#trait => ident, unsafety);
+    let send_ident = &send_trait.ident;
+    let (_, ty_gen, where_clause) = r#trait.generics.split_for_impl();
+    let mut generics = r#trait.generics.clone();
+    generics
+        .params
+        .push(parse_quote!(__Send: #send_ident #ty_gen));
+    let (impl_gen, _, _) = generics.split_for_impl();
+    let send_trait = quote!(<__Send as #send_ident #ty_gen>);
+    let items = r#trait.items.iter().filter_map(|item| match item {
+        TraitItem::Const(cst) => {
+            fields!(cst => ident, ty);
+            Some(quote!(const #ident: #ty = #send_trait::#ident;))
+        }
+        TraitItem::Type(ty) => {
+            let ident = &ty.ident;
+            let (gen_impl, ty_gen, where_clause) = ty.generics.split_for_impl();
+            Some(quote!(type #ident #gen_impl = #send_trait::#ident #ty_gen #where_clause;))
+        }
+        TraitItem::Fn(method) => {
+            let mut sig = method.sig.clone();
+            PatternAsArg.visit_signature_mut(&mut sig);
+            desugar_async(&mut sig);
+            let method_name = &sig.ident;
+            let args = fn_args(&method.sig);
+            let block = parse_quote!({ #send_trait::#method_name(#(#args,)*) });
+            Some(impl_method(sig, block).into_token_stream())
+        }
+        _ => None,
+    });
+    quote! {
+        #unsafety impl #impl_gen #ident #ty_gen for __Send #where_clause {
+            #(#items)*
+        }
+    }
+}
+
+// Traits which are known not to be dyn-compatible, or whose trait objects cannot implement them
+const NOT_ERASABLE: &[&str] = &[
+    "Clone",
+    "Copy",
+    "Default",
+    "Eq",
+    "FnOnce",
+    "From",
+    "Hash",
+    "Into",
+    "IntoIterator",
+    "Ord",
+    "PartialEq",
+    "PartialOrd",
+    "Sized",
+    "TryFrom",
+    "TryInto",
+];
+const AUTO_TRAITS: &[&str] = &["Send", "Sync", "Unpin"];
+
+/// Rewrites the arguments whose type is a generic parameter or an `impl Trait` into trait
+/// objects, removing the erased generic parameters from the signature.
+///
+/// Arguments passed by value are converted to `DynObject<dyn Trait>`, except `Fn`/`FnMut` which
+/// are converted to `&dyn Fn`/`&mut dyn FnMut`. Arguments passed by reference are converted to
+/// `&dyn Trait` if the parameter is `?Sized`, to `&DynObject<dyn Trait>` otherwise.
+///
+/// Returns `None` if a generic parameter cannot be erased.
+fn erase_generic_args(crate_: &Path, sig: &Signature) -> Option<Signature> {
+    let mut sig = sig.clone();
+    let mut params = HashMap::new();
+    for param in mem::take(&mut sig.generics.params) {
+        match param {
+            GenericParam::Type(param) => {
+                params.insert(param.ident, param.bounds);
+            }
+            GenericParam::Const(_) => return None,
+            param => sig.generics.params.push(param),
+        }
+    }
+    if let Some(where_clause) = &mut sig.generics.where_clause {
+        for predicate in mem::take(&mut where_clause.predicates) {
+            let bounds =
+                try_match!(&predicate, WherePredicate::Type(p) if p.lifetimes.is_none() => p)
+                    .and_then(|p| params.get_mut(type_param(&p.bounded_ty)?));
+            match (bounds, predicate) {
+                (Some(bounds), WherePredicate::Type(p)) => bounds.extend(p.bounds),
+                (_, predicate) => where_clause.predicates.push(predicate),
+            }
+        }
+        if where_clause.predicates.is_empty() {
+            sig.generics.where_clause = None;
+        }
+    }
+    let mut erased = HashSet::new();
+    for arg in sig.inputs.iter_mut().filter_map(try_match!(FnArg::Typed)) {
+        let mut erase = |ty: &Type| match ty {
+            Type::ImplTrait(ty) => Some(ty.bounds.clone()),
+            ty => {
+                let (param, bounds) = params.get_key_value(type_param(ty)?)?;
+                erased.insert(param.clone()).then(|| bounds.clone())
+            }
+        };
+        if let Some(bounds) = erase(&arg.ty) {
+            *arg.ty = erase_by_value(crate_, bounds)?;
+        } else if let Type::Reference(ty) = arg.ty.as_mut()
+            && let Some(bounds) = erase(&ty.elem)
+        {
+            *ty.elem = erase_by_ref(crate_, bounds)?;
+        }
+    }
+    let mut uses = GenericUses {
+        params: params.keys().collect(),
+        found: erased.len() != params.len(),
+    };
+    uses.visit_signature(&sig);
+    (!uses.found).then_some(sig)
+}
+
+fn type_param(ty: &Type) -> Option<&Ident> {
+    try_match!(ty, Type::Path(TypePath { qself: None, path }) => path.get_ident())?
+}
+
+fn erase_by_value(crate_: &Path, bounds: Punctuated<TypeParamBound, Token![+]>) -> Option<Type> {
+    let (trait_name, bounds) = erasable_bounds(bounds)?;
+    Some(match trait_name.as_str() {
+        "Fn" => parse_quote!(&dyn #bounds),
+        "FnMut" => parse_quote!(&mut dyn #bounds),
+        _ if bounds
+            .iter()
+            .any(|b| matches!(b, TypeParamBound::Lifetime(_))) =>
+        {
+            parse_quote!(#crate_::DynObject<dyn #bounds>)
+        }
+        _ => parse_quote!(#crate_::DynObject<dyn #bounds + '_>),
+    })
+}
+
+fn erase_by_ref(crate_: &Path, bounds: Punctuated<TypeParamBound, Token![+]>) -> Option<Type> {
+    let is_unsized = (bounds.iter())
+        .filter_map(try_match!(TypeParamBound::Trait))
+        .any(|b| matches!(b.modifier, TraitBoundModifier::Maybe(_)));
+    if is_unsized {
+        let (_, bounds) = erasable_bounds(bounds)?;
+        Some(parse_quote!(dyn #bounds))
+    } else {
+        let ty = erase_by_value(crate_, bounds)?;
+        try_match!(ty, Type::Path(_) => ty)
+    }
+}
+
+/// Returns the name of the main trait of the bounds, and the bounds without `?Sized`.
+fn erasable_bounds(
+    bounds: Punctuated<TypeParamBound, Token![+]>,
+) -> Option<(String, Punctuated<TypeParamBound, Token![+]>)> {
+    let bounds: Punctuated<_, Token![+]> = (bounds.into_iter())
+        .filter(|b| {
+            try_match!(b, TypeParamBound::Trait)
+                .is_none_or(|b| matches!(b.modifier, TraitBoundModifier::None))
+        })
+        .collect();
+    let trait_names = (bounds.iter())
+        .filter_map(try_match!(TypeParamBound::Trait))
+        .map(|b| b.path.segments.last().unwrap().ident.to_string())
+        .collect_vec();
+    if (trait_names.iter()).any(|name| NOT_ERASABLE.contains(&name.as_str())) {
+        return None;
+    }
+    let trait_name =
+        (trait_names.into_iter()).find(|name| !AUTO_TRAITS.contains(&name.as_str()))?;
+    Some((trait_name, bounds))
+}
+
+/// Checks whether erased generic parameters or `impl Trait` arguments are still used.
+struct GenericUses<'a> {
+    params: HashSet<&'a Ident>,
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for GenericUses<'_> {
+    fn visit_fn_arg(&mut self, i: &'ast FnArg) {
+        if let FnArg::Typed(arg) = i {
+            self.found |= contains_impl_trait(&arg.ty);
+        }
+        syn::visit::visit_fn_arg(self, i);
+    }
+
+    fn visit_type_path(&mut self, i: &'ast TypePath) {
+        if i.qself.is_none() {
+            let first = i.path.segments.first().map(|s| &s.ident);
+            self.found |= first.is_some_and(|ident| self.params.contains(ident));
+        }
+        syn::visit::visit_type_path(self, i);
+    }
+}
+
+/// Types converted into `DynObject` when nested in a returned type.
+#[derive(Clone, Copy)]
+enum Nested {
+    ImplTrait,
+    SelfType,
+}
+
+impl Nested {
+    fn is(self, ty: &Type) -> bool {
+        match self {
+            Self::ImplTrait => matches!(ty, Type::ImplTrait(_)),
+            Self::SelfType => try_match!(ty, Type::Path(p) => p)
+                .is_some_and(|p| p.qself.is_none() && p.path.is_ident("Self")),
+        }
+    }
+
+    fn is_contained(self, ty: &Type) -> bool {
+        struct Contains(Nested, bool);
+        impl<'ast> Visit<'ast> for Contains {
+            fn visit_type(&mut self, i: &'ast Type) {
+                self.1 |= self.0.is(i);
+                syn::visit::visit_type(self, i);
+            }
+        }
+        let mut visitor = Contains(self, false);
+        visitor.visit_type(ty);
+        visitor.1
+    }
+
+    /// Returns the conversion of the value `expr` of type `ty` into a value where every nested
+    /// type is converted into `DynObject`, collecting the nested types.
+    fn conversion(
+        self,
+        crate_: &Path,
+        ty: &Type,
+        expr: TokenStream,
+        nested: &mut Vec<Type>,
+    ) -> syn::Result<Option<TokenStream>> {
+        if !self.is_contained(ty) {
+            return Ok(None);
+        }
+        let mut convert = |ty, expr| self.conversion(crate_, ty, expr, nested);
+        let name = match self {
+            Self::ImplTrait => "impl trait",
+            Self::SelfType => "`Self`",
+        };
+        let unsupported = format!(
+            "{name} can only be nested in tuples, `Option`, `Result`, `Poll`, `Box` and `Vec`"
+        );
+        Ok(Some(match ty {
+            ty if self.is(ty) => {
+                nested.push(ty.clone());
+                quote!(#crate_::DynObject::new(#expr))
+            }
+            Type::Group(ty) => return convert(&ty.elem, expr),
+            Type::Paren(ty) => return convert(&ty.elem, expr),
+            Type::Tuple(tuple) => {
+                let vars = (0..tuple.elems.len())
+                    .map(|i| format_ident!("__{i}"))
+                    .collect_vec();
+                let mut elems = Vec::new();
+                for (ty, var) in tuple.elems.iter().zip(&vars) {
+                    elems.push(convert(ty, quote!(#var))?.unwrap_or_else(|| quote!(#var)));
+                }
+                quote!({
+                    let (#(#vars,)*) = #expr;
+                    (#(#elems,)*)
+                })
+            }
+            Type::Path(TypePath { qself: None, path }) => {
+                let segment = path.segments.last().unwrap();
+                let args = try_match!(&segment.arguments, PathArguments::AngleBracketed);
+                let types = (args.iter())
+                    .flat_map(|args| args.args.iter())
+                    .filter_map(try_match!(GenericArgument::Type))
+                    .collect_vec();
+                match (segment.ident.to_string().as_str(), types.as_slice()) {
+                    ("Option" | "Poll", [ty]) => {
+                        let map = convert(ty, quote!(__value))?.unwrap();
+                        quote!(#expr.map(|__value| #map))
+                    }
+                    ("Result", [ok, err @ ..]) if err.len() <= 1 => {
+                        let mut expr = expr;
+                        if let Some(map) = convert(ok, quote!(__value))? {
+                            expr = quote!(#expr.map(|__value| #map));
+                        }
+                        if let Some(err) = err.first()
+                            && let Some(map) = convert(err, quote!(__value))?
+                        {
+                            expr = quote!(#expr.map_err(|__value| #map));
+                        }
+                        expr
+                    }
+                    ("Box", [ty]) => {
+                        let boxed = convert(ty, quote!((*#expr)))?.unwrap();
+                        quote!(#crate_::__private::Box::new(#boxed))
+                    }
+                    ("Vec", [ty]) => {
+                        let map = convert(ty, quote!(__value))?.unwrap();
+                        quote!(#expr
+                            .into_iter()
+                            .map(|__value| #map)
+                            .collect::<#crate_::__private::Vec<_>>())
+                    }
+                    ("Pin", _) => bail!(ty, format!("{name} cannot be moved out of `Pin`")),
+                    _ => bail!(ty, unsupported),
+                }
+            }
+            _ => bail!(ty, unsupported),
+        }))
+    }
+
+    /// Collects the projections of the value `expr` of type `ty` into its nested types, in the
+    /// order of appearance; the projections are only used for type inference.
+    fn projections(self, ty: &Type, expr: TokenStream, projections: &mut Vec<TokenStream>) {
+        if !self.is_contained(ty) {
+            return;
+        }
+        let mut project = |ty, expr| self.projections(ty, expr, projections);
+        match ty {
+            ty if self.is(ty) => projections.push(expr),
+            Type::Group(ty) => project(&ty.elem, expr),
+            Type::Paren(ty) => project(&ty.elem, expr),
+            Type::Tuple(tuple) => {
+                for (i, ty) in tuple.elems.iter().enumerate() {
+                    let index = syn::Index::from(i);
+                    project(ty, quote!(#expr.#index));
+                }
+            }
+            Type::Path(TypePath { qself: None, path }) => {
+                let segment = path.segments.last().unwrap();
+                let args = try_match!(&segment.arguments, PathArguments::AngleBracketed);
+                let types = (args.iter())
+                    .flat_map(|args| args.args.iter())
+                    .filter_map(try_match!(GenericArgument::Type))
+                    .collect_vec();
+                match (segment.ident.to_string().as_str(), types.as_slice()) {
+                    ("Option", [ty]) => project(ty, quote!(#expr.unwrap())),
+                    ("Poll", [ty]) => project(
+                        ty,
+                        quote!(match #expr {
+                            ::core::task::Poll::Ready(__value) => __value,
+                            ::core::task::Poll::Pending => ::core::unreachable!(),
+                        }),
+                    ),
+                    ("Result", [ok, err @ ..]) => {
+                        project(ok, quote!(#expr.ok().unwrap()));
+                        if let Some(err) = err.first() {
+                            project(err, quote!(#expr.err().unwrap()));
+                        }
+                    }
+                    ("Box", [ty]) => project(ty, quote!((*#expr))),
+                    ("Vec", [ty]) => project(ty, quote!(#expr.into_iter().next().unwrap())),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Replaces the nested types by the given types, in the order of appearance.
+    fn replace(self, ty: &mut Type, replacements: impl IntoIterator<Item = Type>) {
+        struct Replace<I>(Nested, I);
+        impl<I: Iterator<Item = Type>> VisitMut for Replace<I> {
+            fn visit_type_mut(&mut self, i: &mut Type) {
+                if self.0.is(i) {
+                    *i = self.1.next().unwrap();
+                } else {
+                    syn::visit_mut::visit_type_mut(self, i);
+                }
+            }
+        }
+        Replace(self, replacements.into_iter()).visit_type_mut(ty);
+    }
+}
+
+/// Adds `Send` bound to impl traits.
+struct SendImplTraits;
+
+impl VisitMut for SendImplTraits {
+    fn visit_type_impl_trait_mut(&mut self, i: &mut TypeImplTrait) {
+        let is_send = (i.bounds.iter())
+            .filter_map(try_match!(TypeParamBound::Trait))
+            .any(|b| last_segment(&b.path, "Send").is_some());
+        if !is_send {
+            i.bounds.push(parse_quote!(::core::marker::Send));
+        }
+    }
+}
+
+pub(crate) fn contains_impl_trait(ty: &Type) -> bool {
+    Nested::ImplTrait.is_contained(ty)
+}
+
 fn extract_dyn_trait_attrs(r#trait: &mut ItemTrait) -> syn::Result<Vec<Attribute>> {
     (r#trait.attrs)
         .extract_if(.., |attr| attr.path().is_ident("dyn_trait"))
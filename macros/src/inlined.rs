@@ -0,0 +1,33 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ImplItemFn, Signature, parse_quote};
+
+use crate::{dyn_trait::contains_impl_trait, macros::bail_method, utils::return_type};
+
+pub(super) fn inlined_impl(mut method: ImplItemFn, _: ()) -> syn::Result<TokenStream> {
+    if method.sig.asyncness.is_some() || return_type(&method.sig).is_none_or(contains_impl_trait) {
+        bail_method!(
+            method,
+            "`inlined` must be used on method returning a concrete type"
+        );
+    }
+    let mut inline_method = method.clone();
+    inline_method.sig.ident = inline_fn(&method.sig);
+    let is_inline = is_inline_const(&method.sig);
+    method
+        .attrs
+        .push(parse_quote!(#[allow(refining_impl_trait)]));
+    Ok(quote! {
+        #method
+        #inline_method
+        const #is_inline: bool = true;
+    })
+}
+
+pub(crate) fn inline_fn(sig: &Signature) -> Ident {
+    format_ident!("{}_inline", sig.ident)
+}
+
+pub(crate) fn is_inline_const(sig: &Signature) -> Ident {
+    format_ident!("{}_IS_INLINE", sig.ident.to_string().to_uppercase())
+}
@@ -5,11 +5,13 @@ use proc_macro::TokenStream;
 use syn::{Path, meta::ParseNestedMeta, parse::Parse, parse_macro_input, parse_quote};
 
 use crate::{
-    dyn_object::dyn_object_impl, dyn_trait::dyn_trait_impl, macros::bail, sync::sync_impl,
+    dyn_object::dyn_object_impl, dyn_trait::dyn_trait_impl, inlined::inlined_impl, macros::bail,
+    sync::sync_impl,
 };
 
 mod dyn_object;
 mod dyn_trait;
+mod inlined;
 mod macros;
 mod sync;
 mod utils;
@@ -51,6 +53,11 @@ pub fn sync(args: TokenStream, item: TokenStream) -> TokenStream {
     macro_impl(sync_impl, item, args)
 }
 
+#[proc_macro_attribute]
+pub fn inlined(args: TokenStream, item: TokenStream) -> TokenStream {
+    macro_impl(inlined_impl, item, args)
+}
+
 #[proc_macro_attribute]
 pub fn dyn_object(args: TokenStream, item: TokenStream) -> TokenStream {
     macro_impl(dyn_object_impl, item, args)
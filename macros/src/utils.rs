@@ -27,14 +27,22 @@ pub(crate) fn is_not_generic(ty: &TraitItemType) -> bool {
     ty.generics.params.is_empty() && ty.generics.where_clause.is_none()
 }
 
-pub(crate) fn is_dispatchable(method: &TraitItemFn) -> bool {
-    let has_dyn_trait_receiver = (method.sig.receiver())
+pub(crate) fn is_dispatchable(sig: &Signature) -> bool {
+    let has_dyn_trait_receiver = (sig.receiver())
         .is_some_and(|recv| recv.reference.is_some() || pinned_ref(&recv.ty).is_some());
     let has_no_generic_parameter_except_lifetime =
-        (method.sig.generics.params.iter()).all(|p| matches!(p, GenericParam::Lifetime(_)));
+        (sig.generics.params.iter()).all(|p| matches!(p, GenericParam::Lifetime(_)));
     has_dyn_trait_receiver && has_no_generic_parameter_except_lifetime
 }
 
+pub(crate) fn is_consuming(method: &TraitItemFn) -> bool {
+    let has_value_receiver = (method.sig.receiver())
+        .is_some_and(|recv| recv.reference.is_none() && *recv.ty == parse_quote!(Self));
+    let has_no_generic_parameter_except_lifetime =
+        (method.sig.generics.params.iter()).all(|p| matches!(p, GenericParam::Lifetime(_)));
+    has_value_receiver && has_no_generic_parameter_except_lifetime
+}
+
 pub(crate) fn return_type(sig: &Signature) -> Option<&Type> {
     try_match!(&sig.output, ReturnType::Type(_, ty) => ty.as_ref())
 }
@@ -51,14 +59,30 @@ pub(crate) fn pinned_ref(ty: &Type) -> Option<&TypeReference> {
 }
 
 pub(crate) fn future_output(ret: &TypeImplTrait) -> Option<&Type> {
-    let future = (ret.bounds.iter())
+    assoc_type_binding(ret, "Future", "Output")
+}
+
+pub(crate) fn iterator_item(ret: &TypeImplTrait) -> Option<&Type> {
+    assoc_type_binding(ret, "Iterator", "Item")
+}
+
+fn assoc_type_binding<'a>(ret: &'a TypeImplTrait, trait_: &str, assoc: &str) -> Option<&'a Type> {
+    let bound = (ret.bounds.iter())
         .filter_map(try_match!(TypeParamBound::Trait))
-        .find_map(|bound| last_segment(&bound.path, "Future"))?;
-    let args = try_match!(&future.arguments, PathArguments::AngleBracketed)?;
-    let output = (args.args.iter())
+        .find_map(|bound| last_segment(&bound.path, trait_))?;
+    let args = try_match!(&bound.arguments, PathArguments::AngleBracketed)?;
+    let binding = (args.args.iter())
         .filter_map(try_match!(GenericArgument::AssocType))
-        .find(|t| t.ident == "Output")?;
-    Some(&output.ty)
+        .find(|t| t.ident == assoc)?;
+    Some(&binding.ty)
+}
+
+/// Converts an async fn into a function returning `impl Future`.
+pub(crate) fn desugar_async(sig: &mut Signature) {
+    if sig.asyncness.take().is_some() {
+        let output = return_type(sig).map_or_else(|| quote!(()), |ty| quote!(#ty));
+        sig.output = parse_quote!(-> impl Future<Output = #output>);
+    }
 }
 
 pub(crate) struct PatternAsArg;
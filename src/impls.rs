@@ -1,12 +1,27 @@
+#[cfg(feature = "futures-io")]
+mod async_io;
+mod error;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "futures-core")]
+mod stream;
+#[cfg(feature = "tokio")]
+mod tokio_io;
+
 // `dyn_object` cannot be used because `Any` has a blanket impl
 // anyway, it allows optimizing type_id as a field and not as a method
 macro_rules! any_impl {
-    ($dyn_any:ty) => {
+    (dyn Any $(+ $bound:ident)* $(=> $($target:ty),+)?) => {
+        any_impl!(@vtable dyn Any $(+ $bound)*, [Any $(+ $bound)*] $(=> $($target),+)?);
+    };
+    (@vtable $dyn_any:ty, [$($dyn_bounds:tt)*] $(=> $($target:ty),+)?) => {
         const _: () = {
             #[derive(Debug)]
+            #[repr(C)]
             pub struct __Vtable {
                 __drop_in_place: Option<unsafe fn(core::ptr::NonNull<()>)>,
                 __layout: core::alloc::Layout,
+                __unsize: fn(core::ptr::NonNull<()>) -> core::ptr::NonNull<$dyn_any>,
                 type_id: core::any::TypeId,
             }
 
@@ -23,65 +38,51 @@ macro_rules! any_impl {
             }
 
             // SAFETY: vtable fields respect trait contract
-            unsafe impl<__Dyn: core::any::Any> crate::object::Vtable<__Dyn> for $dyn_any {
-                fn vtable<__Storage: crate::storage::Storage>() -> &'static Self::Vtable {
+            unsafe impl<__Dyn: $($dyn_bounds)*> crate::object::Vtable<__Dyn> for $dyn_any {
+                fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
                     &const {
                         __Vtable {
                             __drop_in_place:
                                 <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
                             __layout: core::alloc::Layout::new::<__Dyn>(),
+                            __unsize: |ptr| ptr.cast::<__Dyn>(),
                             type_id: core::any::TypeId::of::<__Dyn>(),
                         }
                     }
                 }
             }
 
-            impl<__Storage: crate::storage::Storage> crate::DynObject<$dyn_any, __Storage> {
-                /// Returns the [`TypeId`](core::any::TypeId) of the underlying concrete type.
-                pub fn type_id(&self) -> core::any::TypeId {
-                    self.vtable().type_id
+            // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+            unsafe impl crate::object::DynUnsize for $dyn_any {
+                fn unsize_fn(
+                    vtable: &Self::Vtable,
+                ) -> fn(core::ptr::NonNull<()>) -> core::ptr::NonNull<Self> {
+                    vtable.__unsize
                 }
+            }
 
-                /// Returns `true` if the inner type is the same as `T`.
-                pub fn is<T: Any>(&self) -> bool {
-                    self.type_id() == core::any::TypeId::of::<T>()
+            // SAFETY: `type_id` is initialized with the concrete type of the vtable
+            unsafe impl crate::object::DynDowncast for $dyn_any {
+                fn type_id(vtable: &Self::Vtable) -> core::any::TypeId {
+                    vtable.type_id
                 }
+            }
 
-                /// Returns some reference to the inner value if it is of type `T`,
-                /// or `None` if it isn’t.
-                pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
-                    // SAFETY: `is` ensures that the storage has been initialized with `T`
-                    self.is::<T>().then(|| unsafe { self.storage().as_ref() })
+            #[cfg(feature = "alloc")]
+            impl<__Storage: crate::storage::Storage>
+                From<crate::DynObject<$dyn_any, __Storage>> for alloc::boxed::Box<$dyn_any>
+            {
+                fn from(object: crate::DynObject<$dyn_any, __Storage>) -> Self {
+                    object.into_box()
                 }
+            }
 
-                /// Returns some mutable reference to the inner value if it is of type `T`,
-                /// or `None` if it isn’t.
-                pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T> {
-                    self.is::<T>()
-                        // SAFETY: `is` ensures that the storage has been initialized with `T`
-                        .then(|| unsafe { self.storage_mut().as_mut() })
-                }
+            $($(
+                // SAFETY: both trait objects have the same vtable fields,
+                // and the target has less bounds
+                unsafe impl crate::object::Downgrade<$target> for $dyn_any {}
+            )+)?
 
-                /// Attempts to downcast the object to a concrete type.
-                // TODO understand why it prevents 100% coverage
-                #[cfg_attr(coverage_nightly, coverage(off))]
-                pub fn downcast<T: Any>(self) -> Result<T, Self> {
-                    if self.is::<T>() {
-                        let mut this = core::mem::ManuallyDrop::new(self);
-                        let storage = this.storage_mut();
-                        // SAFETY: `is` ensures that the storage has been initialized with `T`
-                        let obj = unsafe { storage.ptr_mut().cast().read() };
-                        // SAFETY: the storage is no longer used after,
-                        // and `is` ensures that the storage has been initialized with `T`
-                        unsafe {
-                            __Storage::drop_in_place(storage, core::alloc::Layout::new::<T>())
-                        };
-                        Ok(obj)
-                    } else {
-                        Err(self)
-                    }
-                }
-            }
         };
     };
 }
@@ -115,9 +116,11 @@ pub(crate) use any_test;
 
 const _: () = {
     #[derive(Debug)]
+    #[repr(C)]
     pub struct __Vtable {
         __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
         __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
         poll: unsafe fn(),
     }
     impl<'__lt, __TypeOutput> crate::object::DynTrait for dyn Future<Output = __TypeOutput> + '__lt {
@@ -133,11 +136,18 @@ const _: () = {
     unsafe impl<'__lt, __TypeOutput, __Dyn: Future<Output = __TypeOutput> + '__lt>
         crate::object::Vtable<__Dyn> for dyn Future<Output = __TypeOutput> + '__lt
     {
-        fn vtable<__Storage: crate::storage::Storage>() -> &'static Self::Vtable {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
             &const {
                 __Vtable {
                     __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
                     __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
                     #[allow(
                         clippy::missing_transmute_annotations,
                         clippy::useless_transmute
@@ -153,7 +163,13 @@ const _: () = {
                             unsafe fn(),
                         >(|__self, cx| {
                             ::core::mem::transmute(__Dyn::poll(
-                                __self.as_pinned_mut(),
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
                                 ::core::mem::transmute(cx),
                             ))
                         })
@@ -162,6 +178,60 @@ const _: () = {
             }
         }
     }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt, __TypeOutput> crate::object::DynUnsize
+        for dyn Future<Output = __TypeOutput> + '__lt
+    {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __TypeOutput, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt, __Storage>,
+        > for alloc::boxed::Box<dyn Future<Output = __TypeOutput> + '__lt>
+    {
+        fn from(
+            object: crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __TypeOutput, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt, __Storage>,
+        > for core::pin::Pin<alloc::boxed::Box<dyn Future<Output = __TypeOutput> + '__lt>>
+    {
+        fn from(
+            object: crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target, __TypeOutput>
+        crate::object::Downgrade<dyn Future<Output = __TypeOutput> + '__target>
+        for dyn Future<Output = __TypeOutput> + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target, __TypeOutput>
+        crate::object::Downgrade<dyn Future<Output = __TypeOutput> + '__target>
+        for dyn Future<Output = __TypeOutput> + '__lt + Send
+    {
+    }
     impl<'__lt, __TypeOutput, __Storage: crate::storage::Storage> Future
         for crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt, __Storage>
     {
@@ -182,12 +252,34 @@ const _: () = {
             }
         }
     }
+    impl<'__lt, __TypeOutput, __Storage: crate::storage::ThinStorage> Future
+        for crate::ThinDynObject<dyn Future<Output = __TypeOutput> + '__lt, __Storage>
+    {
+        type Output = __TypeOutput;
+        fn poll(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<Self::Output> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<Self::Output>,
+                >(self.vtable().poll)(self.storage_pinned_mut(), cx)
+            }
+        }
+    }
 };
 const _: () = {
     #[derive(Debug)]
+    #[repr(C)]
     pub struct __Vtable {
         __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
         __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
         poll: unsafe fn(),
     }
     impl<'__lt, __TypeOutput> crate::object::DynTrait
@@ -205,11 +297,18 @@ const _: () = {
     unsafe impl<'__lt, __TypeOutput, __Dyn: Future<Output = __TypeOutput> + '__lt + Send>
         crate::object::Vtable<__Dyn> for dyn Future<Output = __TypeOutput> + '__lt + Send
     {
-        fn vtable<__Storage: crate::storage::Storage>() -> &'static Self::Vtable {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
             &const {
                 __Vtable {
                     __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
                     __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
                     #[allow(
                         clippy::missing_transmute_annotations,
                         clippy::useless_transmute
@@ -225,7 +324,13 @@ const _: () = {
                             unsafe fn(),
                         >(|__self, cx| {
                             ::core::mem::transmute(__Dyn::poll(
-                                __self.as_pinned_mut(),
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
                                 ::core::mem::transmute(cx),
                             ))
                         })
@@ -234,6 +339,53 @@ const _: () = {
             }
         }
     }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt, __TypeOutput> crate::object::DynUnsize
+        for dyn Future<Output = __TypeOutput> + '__lt + Send
+    {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __TypeOutput, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt + Send, __Storage>,
+        > for alloc::boxed::Box<dyn Future<Output = __TypeOutput> + '__lt + Send>
+    {
+        fn from(
+            object: crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __TypeOutput, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt + Send, __Storage>,
+        > for core::pin::Pin<alloc::boxed::Box<dyn Future<Output = __TypeOutput> + '__lt + Send>>
+    {
+        fn from(
+            object: crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target, __TypeOutput>
+        crate::object::Downgrade<dyn Future<Output = __TypeOutput> + '__target + Send>
+        for dyn Future<Output = __TypeOutput> + '__lt + Send
+    {
+    }
     impl<'__lt, __TypeOutput, __Storage: crate::storage::Storage> Future
         for crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt + Send, __Storage>
     {
@@ -254,13 +406,35 @@ const _: () = {
             }
         }
     }
+    impl<'__lt, __TypeOutput, __Storage: crate::storage::ThinStorage> Future
+        for crate::ThinDynObject<dyn Future<Output = __TypeOutput> + '__lt + Send, __Storage>
+    {
+        type Output = __TypeOutput;
+        fn poll(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<Self::Output> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<Self::Output>,
+                >(self.vtable().poll)(self.storage_pinned_mut(), cx)
+            }
+        }
+    }
 };
 
 const _: () = {
     #[derive(Debug)]
+    #[repr(C)]
     pub struct __Vtable {
         __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
         __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
         next: unsafe fn(),
         size_hint: unsafe fn(),
         nth: unsafe fn(),
@@ -278,11 +452,18 @@ const _: () = {
     unsafe impl<'__lt, __TypeItem, __Dyn: Iterator<Item = __TypeItem> + '__lt>
         crate::object::Vtable<__Dyn> for dyn Iterator<Item = __TypeItem> + '__lt
     {
-        fn vtable<__Storage: crate::storage::Storage>() -> &'static Self::Vtable {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
             &const {
                 __Vtable {
                     __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
                     __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
                     #[allow(
                         clippy::missing_transmute_annotations,
                         clippy::useless_transmute
@@ -294,7 +475,9 @@ const _: () = {
                             fn(&mut __Storage) -> Option<__Dyn::Item>,
                             unsafe fn(),
                         >(|__self| {
-                            ::core::mem::transmute(__Dyn::next(__self.as_mut()))
+                            ::core::mem::transmute(__Dyn::next(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                            ))
                         })
                     },
                     #[allow(
@@ -323,7 +506,7 @@ const _: () = {
                             unsafe fn(),
                         >(|__self, n| {
                             ::core::mem::transmute(__Dyn::nth(
-                                __self.as_mut(),
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
                                 ::core::mem::transmute(n),
                             ))
                         })
@@ -332,6 +515,47 @@ const _: () = {
             }
         }
     }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt, __TypeItem> crate::object::DynUnsize
+        for dyn Iterator<Item = __TypeItem> + '__lt
+    {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __TypeItem, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn Iterator<Item = __TypeItem> + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn Iterator<Item = __TypeItem> + '__lt>
+    {
+        fn from(
+            object: crate::DynObject<dyn Iterator<Item = __TypeItem> + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target, __TypeItem>
+        crate::object::Downgrade<dyn Iterator<Item = __TypeItem> + '__target>
+        for dyn Iterator<Item = __TypeItem> + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target, __TypeItem>
+        crate::object::Downgrade<dyn Iterator<Item = __TypeItem> + '__target>
+        for dyn Iterator<Item = __TypeItem> + '__lt + Send
+    {
+    }
     impl<'__lt, __TypeItem, __Storage: crate::storage::Storage> Iterator
         for crate::DynObject<dyn Iterator<Item = __TypeItem> + '__lt, __Storage>
     {
@@ -361,11 +585,580 @@ const _: () = {
             }
         }
     }
+    impl<'__lt, __TypeItem, __Storage: crate::storage::ThinStorage> Iterator
+        for crate::ThinDynObject<dyn Iterator<Item = __TypeItem> + '__lt, __Storage>
+    {
+        type Item = __TypeItem;
+        fn next(&mut self) -> Option<Self::Item> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> Option<Self::Item>>(
+                    self.vtable().next,
+                )(self.storage_mut())
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&__Storage) -> (usize, Option<usize>)>(
+                    self.vtable().size_hint,
+                )(self.storage())
+            }
+        }
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage, usize) -> Option<Self::Item>>(
+                    self.vtable().nth,
+                )(self.storage_mut(), n)
+            }
+        }
+    }
+};
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        next: unsafe fn(),
+        size_hint: unsafe fn(),
+        nth: unsafe fn(),
+    }
+    impl<'__lt, __TypeItem> crate::object::DynTrait for dyn Iterator<Item = __TypeItem> + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __TypeItem, __Dyn: Iterator<Item = __TypeItem> + '__lt + Send>
+        crate::object::Vtable<__Dyn> for dyn Iterator<Item = __TypeItem> + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    next: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> Option<__Dyn::Item>,
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::next(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                            ))
+                        })
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    size_hint: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage) -> (usize, Option<usize>),
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::size_hint(__self.as_ref()))
+                        })
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    nth: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, usize) -> Option<__Dyn::Item>,
+                            unsafe fn(),
+                        >(|__self, n| {
+                            ::core::mem::transmute(__Dyn::nth(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(n),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt, __TypeItem> crate::object::DynUnsize
+        for dyn Iterator<Item = __TypeItem> + '__lt + Send
+    {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __TypeItem, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn Iterator<Item = __TypeItem> + '__lt + Send, __Storage>,
+        > for alloc::boxed::Box<dyn Iterator<Item = __TypeItem> + '__lt + Send>
+    {
+        fn from(
+            object: crate::DynObject<dyn Iterator<Item = __TypeItem> + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target, __TypeItem>
+        crate::object::Downgrade<dyn Iterator<Item = __TypeItem> + '__target + Send>
+        for dyn Iterator<Item = __TypeItem> + '__lt + Send
+    {
+    }
+    impl<'__lt, __TypeItem, __Storage: crate::storage::Storage> Iterator
+        for crate::DynObject<dyn Iterator<Item = __TypeItem> + '__lt + Send, __Storage>
+    {
+        type Item = __TypeItem;
+        fn next(&mut self) -> Option<Self::Item> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> Option<Self::Item>>(
+                    self.vtable().next,
+                )(self.storage_mut())
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&__Storage) -> (usize, Option<usize>)>(
+                    self.vtable().size_hint,
+                )(self.storage())
+            }
+        }
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage, usize) -> Option<Self::Item>>(
+                    self.vtable().nth,
+                )(self.storage_mut(), n)
+            }
+        }
+    }
+    impl<'__lt, __TypeItem, __Storage: crate::storage::ThinStorage> Iterator
+        for crate::ThinDynObject<dyn Iterator<Item = __TypeItem> + '__lt + Send, __Storage>
+    {
+        type Item = __TypeItem;
+        fn next(&mut self) -> Option<Self::Item> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> Option<Self::Item>>(
+                    self.vtable().next,
+                )(self.storage_mut())
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&__Storage) -> (usize, Option<usize>)>(
+                    self.vtable().size_hint,
+                )(self.storage())
+            }
+        }
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage, usize) -> Option<Self::Item>>(
+                    self.vtable().nth,
+                )(self.storage_mut(), n)
+            }
+        }
+    }
+};
+
+// `dyn_object` cannot be used because of the parenthesized generic arguments,
+// and because closure traits cannot be implemented on stable Rust;
+// inherent `call`/`call_mut`/`call_once` methods are provided instead.
+// Higher-ranked closures are only supported with a single reference argument,
+// e.g. `dyn Fn(&str)`, with `Call`/`CallMut`/`CallOnce` traits, as inherent methods
+// would conflict with the ones of `dyn Fn(T)`.
+macro_rules! fn_impl {
+    ($($arg:ident: $ty:ident),*) => {
+        fn_impl!(inherent[$($ty,)*] $($arg: $ty),*);
+    };
+    ($mode:ident[$($gen:tt)*] $($arg:ident: $ty:ty),*) => {
+        fn_impl!(FnOnce $mode[$($gen)*]($($arg: $ty),*));
+        fn_impl!(FnOnce $mode[$($gen)*]($($arg: $ty),*) + Send);
+        fn_impl!(FnOnce $mode[$($gen)*]($($arg: $ty),*) + Send + Sync);
+        fn_impl!(FnMut $mode[$($gen)*]($($arg: $ty),*));
+        fn_impl!(FnMut $mode[$($gen)*]($($arg: $ty),*) + Send);
+        fn_impl!(FnMut $mode[$($gen)*]($($arg: $ty),*) + Send + Sync);
+        fn_impl!(Fn $mode[$($gen)*]($($arg: $ty),*));
+        fn_impl!(Fn $mode[$($gen)*]($($arg: $ty),*) + Send);
+        fn_impl!(Fn $mode[$($gen)*]($($arg: $ty),*) + Send + Sync);
+        fn_impl!(@downgrades FnOnce[$($gen)*]($($ty),*));
+        fn_impl!(@downgrades FnMut[$($gen)*]($($ty),*));
+        fn_impl!(@downgrades Fn[$($gen)*]($($ty),*));
+    };
+    (FnOnce $mode:ident[$($gen:tt)*]($($arg:ident: $ty:ty),*) $(+ $bound:ident)*) => {
+        const _: () = {
+            #[derive(Debug)]
+            #[repr(C)]
+            pub struct __Vtable {
+                __drop_in_place: Option<unsafe fn(core::ptr::NonNull<()>)>,
+                __layout: core::alloc::Layout,
+                __unsize: unsafe fn(),
+                call_once: unsafe fn(),
+            }
+            fn_impl!(@dyn_trait FnOnce[$($gen)*]($($ty),*) $(+ $bound)*);
+            // SAFETY: vtable fields respect trait contract
+            unsafe impl<'__lt, __TypeOutput, $($gen)* __Dyn: FnOnce($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*>
+                crate::object::Vtable<__Dyn> for dyn FnOnce($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*
+            {
+                fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+                    &const {
+                        __Vtable {
+                            __drop_in_place:
+                                <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                            __layout: core::alloc::Layout::new::<__Dyn>(),
+                            __unsize: fn_impl!(@unsize __Dyn),
+                            call_once: fn_impl!(@call_once __Dyn($($arg: $ty),*)),
+                        }
+                    }
+                }
+            }
+            fn_impl!(@$mode FnOnce[$($gen)*]($($arg: $ty),*) $(+ $bound)*);
+        };
+    };
+    (FnMut $mode:ident[$($gen:tt)*]($($arg:ident: $ty:ty),*) $(+ $bound:ident)*) => {
+        const _: () = {
+            #[derive(Debug)]
+            #[repr(C)]
+            pub struct __Vtable {
+                __drop_in_place: Option<unsafe fn(core::ptr::NonNull<()>)>,
+                __layout: core::alloc::Layout,
+                __unsize: unsafe fn(),
+                call_mut: unsafe fn(),
+                call_once: unsafe fn(),
+            }
+            fn_impl!(@dyn_trait FnMut[$($gen)*]($($ty),*) $(+ $bound)*);
+            // SAFETY: vtable fields respect trait contract
+            unsafe impl<'__lt, __TypeOutput, $($gen)* __Dyn: FnMut($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*>
+                crate::object::Vtable<__Dyn> for dyn FnMut($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*
+            {
+                fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+                    &const {
+                        __Vtable {
+                            __drop_in_place:
+                                <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                            __layout: core::alloc::Layout::new::<__Dyn>(),
+                            __unsize: fn_impl!(@unsize __Dyn),
+                            // SAFETY: transmutation is only used to erase the function type;
+                            // `call_mut` is only called with a `Storage`, which doesn't share
+                            // its data
+                            call_mut: unsafe {
+                                core::mem::transmute::<
+                                    fn(&mut __Storage, $($ty),*) -> __TypeOutput,
+                                    unsafe fn(),
+                                >(|__self, $($arg),*| {
+                                    __self.ptr_mut().cast::<__Dyn>().as_mut()($($arg),*)
+                                })
+                            },
+                            call_once: fn_impl!(@call_once __Dyn($($arg: $ty),*)),
+                        }
+                    }
+                }
+            }
+            fn_impl!(@$mode FnMut[$($gen)*]($($arg: $ty),*) $(+ $bound)*);
+        };
+    };
+    (Fn $mode:ident[$($gen:tt)*]($($arg:ident: $ty:ty),*) $(+ $bound:ident)*) => {
+        const _: () = {
+            #[derive(Debug)]
+            #[repr(C)]
+            pub struct __Vtable {
+                __drop_in_place: Option<unsafe fn(core::ptr::NonNull<()>)>,
+                __layout: core::alloc::Layout,
+                __unsize: unsafe fn(),
+                call: unsafe fn(),
+                call_once: unsafe fn(),
+            }
+            fn_impl!(@dyn_trait Fn[$($gen)*]($($ty),*) $(+ $bound)*);
+            // SAFETY: vtable fields respect trait contract
+            unsafe impl<'__lt, __TypeOutput, $($gen)* __Dyn: Fn($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*>
+                crate::object::Vtable<__Dyn> for dyn Fn($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*
+            {
+                fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+                    &const {
+                        __Vtable {
+                            __drop_in_place:
+                                <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                            __layout: core::alloc::Layout::new::<__Dyn>(),
+                            __unsize: fn_impl!(@unsize __Dyn),
+                            // SAFETY: transmutation is only used to erase the function type
+                            call: unsafe {
+                                core::mem::transmute::<
+                                    fn(&__Storage, $($ty),*) -> __TypeOutput,
+                                    unsafe fn(),
+                                >(|__self, $($arg),*| __self.as_ref::<__Dyn>()($($arg),*))
+                            },
+                            call_once: fn_impl!(@call_once __Dyn($($arg: $ty),*)),
+                        }
+                    }
+                }
+            }
+            fn_impl!(@$mode Fn[$($gen)*]($($arg: $ty),*) $(+ $bound)*);
+        };
+    };
+    (@inherent FnOnce[$($gen:tt)*]($($arg:ident: $ty:ty),*) $(+ $bound:ident)*) => {
+        fn_impl!(@inherent_impl FnOnce[$($gen)*]($($ty),*) $(+ $bound)* {
+            fn_impl!(@impl_call_once[pub][where __Storage: crate::storage::Storage]($($arg: $ty),*));
+        });
+    };
+    (@inherent FnMut[$($gen:tt)*]($($arg:ident: $ty:ty),*) $(+ $bound:ident)*) => {
+        fn_impl!(@inherent_impl FnMut[$($gen)*]($($ty),*) $(+ $bound)* {
+            fn_impl!(@impl_call_mut[pub][where __Storage: crate::storage::Storage]($($arg: $ty),*));
+
+            fn_impl!(@impl_call_once[pub][where __Storage: crate::storage::Storage]($($arg: $ty),*));
+        });
+    };
+    (@inherent Fn[$($gen:tt)*]($($arg:ident: $ty:ty),*) $(+ $bound:ident)*) => {
+        fn_impl!(@inherent_impl Fn[$($gen)*]($($ty),*) $(+ $bound)* {
+            fn_impl!(@impl_call[pub] call[]($($arg: $ty),*));
+
+            fn_impl!(@impl_call[pub] call_mut[mut]($($arg: $ty),*));
+
+            fn_impl!(@impl_call_once[pub][where __Storage: crate::storage::Storage]($($arg: $ty),*));
+        });
+    };
+    (@inherent_impl $fn:ident[$($gen:tt)*]($($ty:ty),*) $(+ $bound:ident)* { $($body:tt)* }) => {
+        impl<'__lt, __TypeOutput, $($gen)* __Storage: crate::storage::SharedStorage>
+            crate::DynObject<dyn $fn($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*, __Storage>
+        {
+            $($body)*
+        }
+        impl<'__lt, __TypeOutput, $($gen)* __Storage: crate::storage::ThinStorage>
+            crate::ThinDynObject<dyn $fn($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*, __Storage>
+        {
+            $($body)*
+        }
+    };
+    (@traits FnOnce[$($gen:tt)*]($($arg:ident: $ty:ty),*) $(+ $bound:ident)*) => {
+        fn_impl!(@trait_impl CallOnce: Storage, FnOnce[$($gen)*]($($ty),*) $(+ $bound)* {
+            fn_impl!(@impl_call_once[][]($($arg: $ty),*));
+        });
+    };
+    (@traits FnMut[$($gen:tt)*]($($arg:ident: $ty:ty),*) $(+ $bound:ident)*) => {
+        fn_impl!(@trait_impl CallMut: Storage, FnMut[$($gen)*]($($ty),*) $(+ $bound)* {
+            fn_impl!(@impl_call_mut[][]($($arg: $ty),*));
+        });
+        fn_impl!(@trait_impl CallOnce: Storage, FnMut[$($gen)*]($($ty),*) $(+ $bound)* {
+            fn_impl!(@impl_call_once[][]($($arg: $ty),*));
+        });
+    };
+    (@traits Fn[$($gen:tt)*]($($arg:ident: $ty:ty),*) $(+ $bound:ident)*) => {
+        fn_impl!(@trait_impl Call: SharedStorage, Fn[$($gen)*]($($ty),*) $(+ $bound)* {
+            fn_impl!(@impl_call[] call[]($($arg: $ty),*));
+        });
+        fn_impl!(@trait_impl CallMut: SharedStorage, Fn[$($gen)*]($($ty),*) $(+ $bound)* {
+            fn_impl!(@impl_call[] call_mut[mut]($($arg: $ty),*));
+        });
+        fn_impl!(@trait_impl CallOnce: Storage, Fn[$($gen)*]($($ty),*) $(+ $bound)* {
+            fn_impl!(@impl_call_once[][]($($arg: $ty),*));
+        });
+    };
+    (@trait_impl $trait:ident: $storage:ident, $fn:ident[$($gen:tt)*]($($ty:ty),*) $(+ $bound:ident)* {
+        $($body:tt)*
+    }) => {
+        impl<'__lt, __TypeOutput, $($gen)* __Storage: crate::storage::$storage>
+            crate::object::$trait<$($ty),*>
+            for crate::DynObject<dyn $fn($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*, __Storage>
+        {
+            type Output = __TypeOutput;
+            $($body)*
+        }
+        impl<'__lt, __TypeOutput, $($gen)* __Storage: crate::storage::ThinStorage + crate::storage::$storage>
+            crate::object::$trait<$($ty),*>
+            for crate::ThinDynObject<dyn $fn($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*, __Storage>
+        {
+            type Output = __TypeOutput;
+            $($body)*
+        }
+    };
+    (@downgrades $fn:ident[$($gen:tt)*]($($ty:ty),*)) => {
+        fn_impl!(@downgrade $fn[$($gen)*]($($ty),*) =>);
+        fn_impl!(@downgrade $fn[$($gen)*]($($ty),*) + Send =>);
+        fn_impl!(@downgrade $fn[$($gen)*]($($ty),*) + Send => + Send);
+        fn_impl!(@downgrade $fn[$($gen)*]($($ty),*) + Send + Sync =>);
+        fn_impl!(@downgrade $fn[$($gen)*]($($ty),*) + Send + Sync => + Send);
+        fn_impl!(@downgrade $fn[$($gen)*]($($ty),*) + Send + Sync => + Send + Sync);
+    };
+    (@downgrade $fn:ident[$($gen:tt)*]($($ty:ty),*) $(+ $bound:ident)* => $(+ $target:ident)*) => {
+        // SAFETY: both trait objects have the same vtable fields,
+        // and the target has less bounds and a shorter lifetime
+        unsafe impl<'__target, '__lt: '__target, __TypeOutput, $($gen)*>
+            crate::object::Downgrade<dyn $fn($($ty),*) -> __TypeOutput + '__target $(+ $target)*>
+            for dyn $fn($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*
+        {
+        }
+    };
+    (@dyn_trait $fn:ident[$($gen:tt)*]($($ty:ty),*) $(+ $bound:ident)*) => {
+        impl<'__lt, __TypeOutput, $($gen)*> crate::object::DynTrait
+            for dyn $fn($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*
+        {
+            type Vtable = __Vtable;
+            fn drop_in_place_fn(
+                vtable: &Self::Vtable,
+            ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+                vtable.__drop_in_place
+            }
+            fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+                vtable.__layout
+            }
+        }
+        // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+        unsafe impl<'__lt, __TypeOutput, $($gen)*> crate::object::DynUnsize
+            for dyn $fn($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*
+        {
+            fn unsize_fn(
+                vtable: &Self::Vtable,
+            ) -> fn(core::ptr::NonNull<()>) -> core::ptr::NonNull<Self> {
+                // SAFETY: transmutation is only used to erase the function type
+                unsafe {
+                    core::mem::transmute::<
+                        unsafe fn(),
+                        fn(core::ptr::NonNull<()>) -> core::ptr::NonNull<Self>,
+                    >(vtable.__unsize)
+                }
+            }
+        }
+        #[cfg(feature = "alloc")]
+        impl<'__lt, __TypeOutput, $($gen)* __Storage: crate::storage::Storage>
+            core::convert::From<
+                crate::DynObject<dyn $fn($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*, __Storage>,
+            > for alloc::boxed::Box<dyn $fn($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*>
+        {
+            fn from(
+                object: crate::DynObject<
+                    dyn $fn($($ty),*) -> __TypeOutput + '__lt $(+ $bound)*,
+                    __Storage,
+                >,
+            ) -> Self {
+                object.into_box()
+            }
+        }
+    };
+    (@unsize $dyn:ident) => {
+        // SAFETY: transmutation is only used to erase the function type
+        unsafe {
+            core::mem::transmute::<
+                fn(core::ptr::NonNull<()>) -> core::ptr::NonNull<Self>,
+                unsafe fn(),
+            >(|__ptr| __ptr.cast::<$dyn>())
+        }
+    };
+    (@call_once $dyn:ident($($arg:ident: $ty:ty),*)) => {
+        // SAFETY: transmutation is only used to erase the function type;
+        // `call_once` is only called with a `Storage`, so the closure is moved out of it,
+        // which is then dropped in place, so the `DynObject` must not be dropped after the call
+        unsafe {
+            core::mem::transmute::<fn(&mut __Storage, $($ty),*) -> __TypeOutput, unsafe fn()>(
+                |__self, $($arg),*| {
+                    let __object = __self.ptr_mut().cast::<$dyn>().read();
+                    __self.drop_object(None, core::alloc::Layout::new::<$dyn>());
+                    // braces force the closure to be called by value
+                    ({ __object })($($arg),*)
+                },
+            )
+        }
+    };
+    (@impl_call[$($vis:tt)*] $method:ident[$($mut:tt)?]($($arg:ident: $ty:ty),*)) => {
+        /// Calls the underlying closure.
+        $($vis)* fn $method(&$($mut)? self, $($arg: $ty),*) -> __TypeOutput {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, $($ty),*) -> __TypeOutput,
+                >(self.vtable().call)(self.storage(), $($arg),*)
+            }
+        }
+    };
+    (@impl_call_mut[$($vis:tt)*][$($where:tt)*]($($arg:ident: $ty:ty),*)) => {
+        /// Calls the underlying closure.
+        $($vis)* fn call_mut(&mut self, $($arg: $ty),*) -> __TypeOutput
+        $($where)*
+        {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, $($ty),*) -> __TypeOutput,
+                >(self.vtable().call_mut)(self.storage_mut(), $($arg),*)
+            }
+        }
+    };
+    (@impl_call_once[$($vis:tt)*][$($where:tt)*]($($arg:ident: $ty:ty),*)) => {
+        /// Calls the underlying closure, consuming the object.
+        $($vis)* fn call_once(self, $($arg: $ty),*) -> __TypeOutput
+        $($where)*
+        {
+            let mut this = core::mem::ManuallyDrop::new(self);
+            // SAFETY: the vtable method has been initialized with the given type,
+            // and the storage is dropped in place by the vtable method,
+            // so `self` must not be dropped
+            unsafe {
+                core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, $($ty),*) -> __TypeOutput,
+                >(this.vtable().call_once)(this.storage_mut(), $($arg),*)
+            }
+        }
+    };
+}
+
+fn_impl!();
+fn_impl!(arg0: __TypeArg0);
+fn_impl!(arg0: __TypeArg0, arg1: __TypeArg1);
+fn_impl!(arg0: __TypeArg0, arg1: __TypeArg1, arg2: __TypeArg2);
+fn_impl!(arg0: __TypeArg0, arg1: __TypeArg1, arg2: __TypeArg2, arg3: __TypeArg3);
+fn_impl!(arg0: __TypeArg0, arg1: __TypeArg1, arg2: __TypeArg2, arg3: __TypeArg3, arg4: __TypeArg4);
+#[rustfmt::skip]
+fn_impl!(arg0: __TypeArg0, arg1: __TypeArg1, arg2: __TypeArg2, arg3: __TypeArg3, arg4: __TypeArg4, arg5: __TypeArg5);
+// Higher-ranked `dyn Fn(&T)` is a distinct type from `dyn Fn(T)` for any `T`,
+// but coherence still warns about it, see rust-lang/rust#56105.
+#[allow(coherence_leak_check)]
+const _: () = {
+    fn_impl!(traits[__TypeArg0: ?Sized,] arg0: &__TypeArg0);
+    fn_impl!(traits[__TypeArg0: ?Sized,] arg0: &mut __TypeArg0);
 };
 
 #[cfg_attr(coverage_nightly, coverage(off))]
 #[cfg(test)]
 mod tests {
+    use core::sync::atomic::{AtomicBool, Ordering};
+
     use futures::FutureExt;
 
     use crate::DynObject;
@@ -387,6 +1180,105 @@ mod tests {
         assert_eq!(future.now_or_never(), Some(42));
     }
 
+    #[test]
+    fn dyn_fn() {
+        let array = [1, 2, 3];
+        let f = DynObject::<dyn Fn(usize) -> usize>::new(|n| array[n]);
+        assert_eq!(f.call(1), 2);
+        let mut f = DynObject::<dyn Fn(usize, usize) -> usize + Send>::new(|a, b| a + b);
+        assert_send(&f);
+        assert_eq!(f.call_mut(1, 2), 3);
+        assert_eq!(f.call_once(3, 4), 7);
+    }
+
+    #[test]
+    fn dyn_fn_mut() {
+        let mut count = 0;
+        let mut f = DynObject::<dyn FnMut() -> usize>::new(|| {
+            count += 1;
+            count
+        });
+        assert_eq!(f.call_mut(), 1);
+        assert_eq!(f.call_mut(), 2);
+        assert_eq!(f.call_once(), 3);
+    }
+
+    #[test]
+    fn dyn_fn_once() {
+        struct SetDropped<'a>(&'a AtomicBool);
+        impl Drop for SetDropped<'_> {
+            fn drop(&mut self) {
+                assert!(!self.0.swap(true, Ordering::Relaxed));
+            }
+        }
+        fn check<S: crate::storage::Storage>() {
+            let dropped = AtomicBool::new(false);
+            let set_dropped = SetDropped(&dropped);
+            let f = DynObject::<dyn FnOnce(bool) -> bool, S>::new(move |b| {
+                let _set_dropped = set_dropped;
+                b
+            });
+            assert!(!dropped.load(Ordering::Relaxed));
+            assert!(f.call_once(true));
+            assert!(dropped.load(Ordering::Relaxed));
+            let dropped = AtomicBool::new(false);
+            let set_dropped = SetDropped(&dropped);
+            drop(DynObject::<dyn FnOnce() + Send + Sync, S>::new(move || {
+                drop(set_dropped);
+            }));
+            assert!(dropped.load(Ordering::Relaxed));
+        }
+        check::<crate::storage::Raw<{ size_of::<SetDropped>() }>>();
+        #[cfg(feature = "alloc")]
+        check::<crate::storage::Box>();
+    }
+
+    #[test]
+    fn dyn_fn_ref() {
+        use crate::object::{Call, CallMut, CallOnce};
+
+        let mut f = DynObject::<dyn Fn(&str) -> usize + Send>::new(|s: &str| s.len());
+        assert_send(&f);
+        assert_eq!(f.call("hello"), 5);
+        let bytes = *b"hi";
+        assert_eq!(f.call_mut(core::str::from_utf8(&bytes).unwrap()), 2);
+        assert_eq!(f.call_once("a"), 1);
+        let mut count = 0;
+        let mut f = DynObject::<dyn FnMut(&mut usize) -> usize>::new(|n: &mut usize| {
+            count += 1;
+            *n += count;
+            count
+        });
+        let mut n = 0;
+        assert_eq!(f.call_mut(&mut n), 1);
+        assert_eq!(f.call_once(&mut n), 2);
+        assert_eq!(n, 3);
+        let f = DynObject::<dyn FnOnce(&[usize]) -> usize>::new(|s: &[usize]| s.iter().sum());
+        let f = f.downgrade::<dyn FnOnce(&[usize]) -> usize + '_>();
+        assert_eq!(f.call_once(&[1, 2, 3]), 6);
+    }
+
+    #[test]
+    fn downgrade() {
+        fn shorten<'a>(
+            future: DynObject<dyn Future<Output = usize> + Send>,
+        ) -> DynObject<dyn Future<Output = usize> + 'a> {
+            future.downgrade()
+        }
+        assert_eq!(
+            shorten(DynObject::new(async { 42 })).now_or_never(),
+            Some(42)
+        );
+        let f = DynObject::<dyn Fn(usize) -> usize + Send + Sync>::new(|n| n + 1);
+        let f = f.downgrade::<dyn Fn(usize) -> usize + Send>();
+        assert_eq!(f.downgrade::<dyn Fn(usize) -> usize>().call(41), 42);
+        let iter = DynObject::<dyn Iterator<Item = usize>>::new(0..2);
+        assert_eq!(
+            iter.downgrade::<dyn Iterator<Item = usize> + '_>().count(),
+            2
+        );
+    }
+
     #[test]
     fn dyn_iterator() {
         let mut iter = DynObject::<dyn Iterator<Item = usize>>::new([0, 1, 2, 3].into_iter());
@@ -394,4 +1286,64 @@ mod tests {
         assert_eq!(iter.nth(2), Some(2));
         assert_eq!(iter.next(), Some(3));
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn thin() {
+        use core::any::Any;
+
+        use crate::{ThinDynObject, storage::ThinBox};
+
+        let future = ThinDynObject::<dyn Future<Output = usize> + Send, ThinBox>::new(async { 42 });
+        assert_send(&future);
+        assert_eq!(future.now_or_never(), Some(42));
+        let mut iter = ThinDynObject::<dyn Iterator<Item = usize>, ThinBox>::new(0..4);
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        assert_eq!(iter.nth(2), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        let f = ThinDynObject::<dyn Fn(usize) -> usize, ThinBox>::new(|n| n + 1);
+        assert_eq!(f.call(41), 42);
+        assert_eq!(f.call_once(42), 43);
+        let f = ThinDynObject::<dyn Fn(&str) -> usize, ThinBox>::new(|s: &str| s.len());
+        assert_eq!(crate::object::Call::call(&f, "hello"), 5);
+        let mut any = ThinDynObject::<dyn Any, ThinBox>::new(false);
+        assert_eq!(any.downcast_ref::<()>(), None);
+        *any.downcast_mut::<bool>().unwrap() = true;
+        let any = any.downcast::<()>().unwrap_err();
+        assert!(any.downcast::<bool>().unwrap());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn into_box() {
+        use alloc::{boxed::Box, string::String};
+        use core::{any::Any, pin::Pin, ptr::NonNull};
+
+        use crate::storage::{Raw, RawOrBox, SharedStorage};
+
+        let future = DynObject::<dyn Future<Output = usize> + Send, Raw<8>>::new(async { 42 });
+        let future: Pin<Box<dyn Future<Output = usize> + Send>> = future.into();
+        assert_eq!(future.now_or_never(), Some(42));
+        let iter = DynObject::<dyn Iterator<Item = usize>, RawOrBox<0>>::new(0..4);
+        let ptr = iter.storage().ptr();
+        let iter: Box<dyn Iterator<Item = usize>> = iter.into();
+        assert_eq!(NonNull::from(&*iter).cast(), ptr);
+        assert_eq!(iter.sum::<usize>(), 6);
+        let any = DynObject::<dyn Any + Send + Sync, RawOrBox<24>>::new(String::from("a"));
+        let any: Box<dyn Any + Send + Sync> = any.into();
+        assert_eq!(any.downcast_ref::<String>().unwrap(), "a");
+        let any = DynObject::<dyn Any, Raw<0>>::new(());
+        assert!(Box::<dyn Any>::from(any).is::<()>());
+        let f = DynObject::<dyn Fn(usize) -> usize, RawOrBox<0>>::new(|n| n + 1);
+        let f: Box<dyn Fn(usize) -> usize> = f.into();
+        assert_eq!(f(41), 42);
+        let mut count = 0;
+        let f = DynObject::<dyn FnMut(&str) -> usize + Send, Raw<8>>::new(|s: &str| {
+            count += s.len();
+            count
+        });
+        let mut f: Box<dyn FnMut(&str) -> usize + Send> = f.into_box();
+        assert_eq!(f("a"), 1);
+        assert_eq!(f("bc"), 3);
+    }
 }
@@ -0,0 +1,1628 @@
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_read: unsafe fn(),
+        poll_read_vectored: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn futures_io::AsyncRead + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: futures_io::AsyncRead + '__lt> crate::object::Vtable<__Dyn>
+        for dyn futures_io::AsyncRead + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_read: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &mut [u8],
+                            )
+                                -> core::task::Poll<futures_io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, buf| {
+                            ::core::mem::transmute(__Dyn::poll_read(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_read_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &mut [futures_io::IoSliceMut<'_>],
+                            )
+                                -> core::task::Poll<futures_io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, bufs| {
+                            ::core::mem::transmute(__Dyn::poll_read_vectored(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(bufs),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn futures_io::AsyncRead + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn futures_io::AsyncRead + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn futures_io::AsyncRead + '__lt>
+    {
+        fn from(object: crate::DynObject<dyn futures_io::AsyncRead + '__lt, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn futures_io::AsyncRead + '__lt, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn futures_io::AsyncRead + '__lt>>
+    {
+        fn from(object: crate::DynObject<dyn futures_io::AsyncRead + '__lt, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn futures_io::AsyncRead + '__target>
+        for dyn futures_io::AsyncRead + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn futures_io::AsyncRead + '__target>
+        for dyn futures_io::AsyncRead + '__lt + Send
+    {
+    }
+    impl<
+        '__lt,
+        __Dyn: crate::object::Upcast<dyn futures_io::AsyncRead + '__lt> + ?Sized,
+        __Storage: crate::storage::Storage,
+    > futures_io::AsyncRead for crate::DynObject<__Dyn, __Storage>
+    {
+        fn poll_read(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut [u8],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut [u8],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(<__Dyn as crate::object::Upcast<dyn futures_io::AsyncRead + '__lt>>::upcast_vtable(self.vtable()).poll_read)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_read_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &mut [futures_io::IoSliceMut<'_>],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut [futures_io::IoSliceMut<'_>],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(<__Dyn as crate::object::Upcast<dyn futures_io::AsyncRead + '__lt>>::upcast_vtable(self.vtable()).poll_read_vectored)(
+                    self.storage_pinned_mut(), cx, bufs
+                )
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Dyn: crate::object::Upcast<dyn futures_io::AsyncRead + '__lt> + ?Sized,
+        __Storage: crate::storage::ThinStorage,
+    > futures_io::AsyncRead for crate::ThinDynObject<__Dyn, __Storage>
+    {
+        fn poll_read(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut [u8],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut [u8],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(<__Dyn as crate::object::Upcast<dyn futures_io::AsyncRead + '__lt>>::upcast_vtable(self.vtable()).poll_read)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_read_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &mut [futures_io::IoSliceMut<'_>],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut [futures_io::IoSliceMut<'_>],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(<__Dyn as crate::object::Upcast<dyn futures_io::AsyncRead + '__lt>>::upcast_vtable(self.vtable()).poll_read_vectored)(
+                    self.storage_pinned_mut(), cx, bufs
+                )
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_read: unsafe fn(),
+        poll_read_vectored: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn futures_io::AsyncRead + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: futures_io::AsyncRead + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn futures_io::AsyncRead + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_read: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &mut [u8],
+                            )
+                                -> core::task::Poll<futures_io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, buf| {
+                            ::core::mem::transmute(__Dyn::poll_read(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_read_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &mut [futures_io::IoSliceMut<'_>],
+                            )
+                                -> core::task::Poll<futures_io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, bufs| {
+                            ::core::mem::transmute(__Dyn::poll_read_vectored(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(bufs),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn futures_io::AsyncRead + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn futures_io::AsyncRead + '__lt + Send, __Storage>>
+        for alloc::boxed::Box<dyn futures_io::AsyncRead + '__lt + Send>
+    {
+        fn from(
+            object: crate::DynObject<dyn futures_io::AsyncRead + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn futures_io::AsyncRead + '__lt + Send, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn futures_io::AsyncRead + '__lt + Send>>
+    {
+        fn from(
+            object: crate::DynObject<dyn futures_io::AsyncRead + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn futures_io::AsyncRead + '__target + Send>
+        for dyn futures_io::AsyncRead + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> futures_io::AsyncRead
+        for crate::DynObject<dyn futures_io::AsyncRead + '__lt + Send, __Storage>
+    {
+        fn poll_read(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut [u8],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut [u8],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(self.vtable().poll_read)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_read_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &mut [futures_io::IoSliceMut<'_>],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut [futures_io::IoSliceMut<'_>],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(self.vtable().poll_read_vectored)(
+                    self.storage_pinned_mut(), cx, bufs
+                )
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> futures_io::AsyncRead
+        for crate::ThinDynObject<dyn futures_io::AsyncRead + '__lt + Send, __Storage>
+    {
+        fn poll_read(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut [u8],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut [u8],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(self.vtable().poll_read)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_read_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &mut [futures_io::IoSliceMut<'_>],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut [futures_io::IoSliceMut<'_>],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(self.vtable().poll_read_vectored)(
+                    self.storage_pinned_mut(), cx, bufs
+                )
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_write: unsafe fn(),
+        poll_write_vectored: unsafe fn(),
+        poll_flush: unsafe fn(),
+        poll_close: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn futures_io::AsyncWrite + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: futures_io::AsyncWrite + '__lt> crate::object::Vtable<__Dyn>
+        for dyn futures_io::AsyncWrite + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_write: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &[u8],
+                            )
+                                -> core::task::Poll<futures_io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, buf| {
+                            ::core::mem::transmute(__Dyn::poll_write(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_write_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &[futures_io::IoSlice<'_>],
+                            )
+                                -> core::task::Poll<futures_io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, bufs| {
+                            ::core::mem::transmute(__Dyn::poll_write_vectored(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(bufs),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_flush: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<futures_io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_flush(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_close: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<futures_io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_close(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn futures_io::AsyncWrite + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn futures_io::AsyncWrite + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn futures_io::AsyncWrite + '__lt>
+    {
+        fn from(object: crate::DynObject<dyn futures_io::AsyncWrite + '__lt, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn futures_io::AsyncWrite + '__lt, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn futures_io::AsyncWrite + '__lt>>
+    {
+        fn from(object: crate::DynObject<dyn futures_io::AsyncWrite + '__lt, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn futures_io::AsyncWrite + '__target>
+        for dyn futures_io::AsyncWrite + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn futures_io::AsyncWrite + '__target>
+        for dyn futures_io::AsyncWrite + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> futures_io::AsyncWrite
+        for crate::DynObject<dyn futures_io::AsyncWrite + '__lt, __Storage>
+    {
+        fn poll_write(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &[u8],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[u8],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(self.vtable().poll_write)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_write_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &[futures_io::IoSlice<'_>],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[futures_io::IoSlice<'_>],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(self.vtable().poll_write_vectored)(
+                    self.storage_pinned_mut(), cx, bufs
+                )
+            }
+        }
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<futures_io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<futures_io::Result<()>>,
+                >(self.vtable().poll_flush)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_close(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<futures_io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<futures_io::Result<()>>,
+                >(self.vtable().poll_close)(self.storage_pinned_mut(), cx)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> futures_io::AsyncWrite
+        for crate::ThinDynObject<dyn futures_io::AsyncWrite + '__lt, __Storage>
+    {
+        fn poll_write(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &[u8],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[u8],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(self.vtable().poll_write)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_write_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &[futures_io::IoSlice<'_>],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[futures_io::IoSlice<'_>],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(self.vtable().poll_write_vectored)(
+                    self.storage_pinned_mut(), cx, bufs
+                )
+            }
+        }
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<futures_io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<futures_io::Result<()>>,
+                >(self.vtable().poll_flush)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_close(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<futures_io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<futures_io::Result<()>>,
+                >(self.vtable().poll_close)(self.storage_pinned_mut(), cx)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_write: unsafe fn(),
+        poll_write_vectored: unsafe fn(),
+        poll_flush: unsafe fn(),
+        poll_close: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn futures_io::AsyncWrite + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: futures_io::AsyncWrite + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn futures_io::AsyncWrite + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_write: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &[u8],
+                            )
+                                -> core::task::Poll<futures_io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, buf| {
+                            ::core::mem::transmute(__Dyn::poll_write(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_write_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &[futures_io::IoSlice<'_>],
+                            )
+                                -> core::task::Poll<futures_io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, bufs| {
+                            ::core::mem::transmute(__Dyn::poll_write_vectored(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(bufs),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_flush: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<futures_io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_flush(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_close: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<futures_io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_close(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn futures_io::AsyncWrite + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn futures_io::AsyncWrite + '__lt + Send, __Storage>,
+        > for alloc::boxed::Box<dyn futures_io::AsyncWrite + '__lt + Send>
+    {
+        fn from(
+            object: crate::DynObject<dyn futures_io::AsyncWrite + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn futures_io::AsyncWrite + '__lt + Send, __Storage>,
+        > for core::pin::Pin<alloc::boxed::Box<dyn futures_io::AsyncWrite + '__lt + Send>>
+    {
+        fn from(
+            object: crate::DynObject<dyn futures_io::AsyncWrite + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn futures_io::AsyncWrite + '__target + Send>
+        for dyn futures_io::AsyncWrite + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> futures_io::AsyncWrite
+        for crate::DynObject<dyn futures_io::AsyncWrite + '__lt + Send, __Storage>
+    {
+        fn poll_write(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &[u8],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[u8],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(self.vtable().poll_write)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_write_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &[futures_io::IoSlice<'_>],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[futures_io::IoSlice<'_>],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(self.vtable().poll_write_vectored)(
+                    self.storage_pinned_mut(), cx, bufs
+                )
+            }
+        }
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<futures_io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<futures_io::Result<()>>,
+                >(self.vtable().poll_flush)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_close(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<futures_io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<futures_io::Result<()>>,
+                >(self.vtable().poll_close)(self.storage_pinned_mut(), cx)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> futures_io::AsyncWrite
+        for crate::ThinDynObject<dyn futures_io::AsyncWrite + '__lt + Send, __Storage>
+    {
+        fn poll_write(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &[u8],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[u8],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(self.vtable().poll_write)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_write_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &[futures_io::IoSlice<'_>],
+        ) -> core::task::Poll<futures_io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[futures_io::IoSlice<'_>],
+                    ) -> core::task::Poll<futures_io::Result<usize>>,
+                >(self.vtable().poll_write_vectored)(
+                    self.storage_pinned_mut(), cx, bufs
+                )
+            }
+        }
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<futures_io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<futures_io::Result<()>>,
+                >(self.vtable().poll_flush)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_close(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<futures_io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<futures_io::Result<()>>,
+                >(self.vtable().poll_close)(self.storage_pinned_mut(), cx)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_fill_buf: unsafe fn(),
+        consume: unsafe fn(),
+        __upcast0: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn futures_io::AsyncBufRead + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: futures_io::AsyncBufRead + '__lt> crate::object::Vtable<__Dyn>
+        for dyn futures_io::AsyncBufRead + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_fill_buf: unsafe {
+                        ::core::mem::transmute::<
+                            for<'a> fn(
+                                ::core::pin::Pin<&'a mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<futures_io::Result<&'a [u8]>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_fill_buf(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    consume: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::pin::Pin<&mut __Storage>, usize),
+                            unsafe fn(),
+                        >(|__self, amt| {
+                            __Dyn::consume(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(amt),
+                            );
+                        })
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn futures_io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn futures_io::AsyncRead + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn futures_io::AsyncBufRead + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn futures_io::AsyncBufRead + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn futures_io::AsyncBufRead + '__lt>
+    {
+        fn from(object: crate::DynObject<dyn futures_io::AsyncBufRead + '__lt, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn futures_io::AsyncBufRead + '__lt, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn futures_io::AsyncBufRead + '__lt>>
+    {
+        fn from(object: crate::DynObject<dyn futures_io::AsyncBufRead + '__lt, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: `__upcast0` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn futures_io::AsyncRead + '__lt>
+        for dyn futures_io::AsyncBufRead + '__lt
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn futures_io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable
+        {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn futures_io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn futures_io::AsyncBufRead + '__target>
+        for dyn futures_io::AsyncBufRead + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn futures_io::AsyncBufRead + '__target>
+        for dyn futures_io::AsyncBufRead + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> futures_io::AsyncBufRead
+        for crate::DynObject<dyn futures_io::AsyncBufRead + '__lt, __Storage>
+    where
+        Self: futures_io::AsyncRead,
+    {
+        fn poll_fill_buf<'a>(
+            self: core::pin::Pin<&'a mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<futures_io::Result<&'a [u8]>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&'a mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<futures_io::Result<&'a [u8]>>,
+                >(self.vtable().poll_fill_buf)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn consume(self: core::pin::Pin<&mut Self>, amt: usize) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(::core::pin::Pin<&mut __Storage>, usize)>(
+                    self.vtable().consume,
+                )(self.storage_pinned_mut(), amt);
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> futures_io::AsyncBufRead
+        for crate::ThinDynObject<dyn futures_io::AsyncBufRead + '__lt, __Storage>
+    where
+        Self: futures_io::AsyncRead,
+    {
+        fn poll_fill_buf<'a>(
+            self: core::pin::Pin<&'a mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<futures_io::Result<&'a [u8]>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&'a mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<futures_io::Result<&'a [u8]>>,
+                >(self.vtable().poll_fill_buf)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn consume(self: core::pin::Pin<&mut Self>, amt: usize) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(::core::pin::Pin<&mut __Storage>, usize)>(
+                    self.vtable().consume,
+                )(self.storage_pinned_mut(), amt);
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_fill_buf: unsafe fn(),
+        consume: unsafe fn(),
+        __upcast0: unsafe fn(),
+        __upcast1: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn futures_io::AsyncBufRead + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: futures_io::AsyncBufRead + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn futures_io::AsyncBufRead + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_fill_buf: unsafe {
+                        ::core::mem::transmute::<
+                            for<'a> fn(
+                                ::core::pin::Pin<&'a mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<futures_io::Result<&'a [u8]>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_fill_buf(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    consume: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::pin::Pin<&mut __Storage>, usize),
+                            unsafe fn(),
+                        >(|__self, amt| {
+                            __Dyn::consume(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(amt),
+                            );
+                        })
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn futures_io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn futures_io::AsyncRead + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast1: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn futures_io::AsyncRead + '__lt + Send as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn futures_io::AsyncRead + '__lt + Send as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn futures_io::AsyncBufRead + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn futures_io::AsyncBufRead + '__lt + Send, __Storage>,
+        > for alloc::boxed::Box<dyn futures_io::AsyncBufRead + '__lt + Send>
+    {
+        fn from(
+            object: crate::DynObject<dyn futures_io::AsyncBufRead + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn futures_io::AsyncBufRead + '__lt + Send, __Storage>,
+        > for core::pin::Pin<alloc::boxed::Box<dyn futures_io::AsyncBufRead + '__lt + Send>>
+    {
+        fn from(
+            object: crate::DynObject<dyn futures_io::AsyncBufRead + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: `__upcast0` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn futures_io::AsyncRead + '__lt>
+        for dyn futures_io::AsyncBufRead + '__lt + Send
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn futures_io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable
+        {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn futures_io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    // SAFETY: `__upcast1` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn futures_io::AsyncRead + '__lt + Send>
+        for dyn futures_io::AsyncBufRead + '__lt + Send
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn futures_io::AsyncRead + '__lt + Send as crate::object::DynTrait>::Vtable
+        {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn futures_io::AsyncRead + '__lt + Send as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast1)()
+            }
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn futures_io::AsyncBufRead + '__target + Send>
+        for dyn futures_io::AsyncBufRead + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> futures_io::AsyncBufRead
+        for crate::DynObject<dyn futures_io::AsyncBufRead + '__lt + Send, __Storage>
+    where
+        Self: futures_io::AsyncRead,
+    {
+        fn poll_fill_buf<'a>(
+            self: core::pin::Pin<&'a mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<futures_io::Result<&'a [u8]>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&'a mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<futures_io::Result<&'a [u8]>>,
+                >(self.vtable().poll_fill_buf)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn consume(self: core::pin::Pin<&mut Self>, amt: usize) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(::core::pin::Pin<&mut __Storage>, usize)>(
+                    self.vtable().consume,
+                )(self.storage_pinned_mut(), amt);
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> futures_io::AsyncBufRead
+        for crate::ThinDynObject<dyn futures_io::AsyncBufRead + '__lt + Send, __Storage>
+    where
+        Self: futures_io::AsyncRead,
+    {
+        fn poll_fill_buf<'a>(
+            self: core::pin::Pin<&'a mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<futures_io::Result<&'a [u8]>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&'a mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<futures_io::Result<&'a [u8]>>,
+                >(self.vtable().poll_fill_buf)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn consume(self: core::pin::Pin<&mut Self>, amt: usize) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(::core::pin::Pin<&mut __Storage>, usize)>(
+                    self.vtable().consume,
+                )(self.storage_pinned_mut(), amt);
+            }
+        }
+    }
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use core::pin::pin;
+    use std::{string::String, vec::Vec};
+
+    use futures::{
+        AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, FutureExt,
+        io::{BufReader, Cursor},
+    };
+    use futures_io::{AsyncBufRead, AsyncRead, AsyncWrite, IoSlice, IoSliceMut};
+
+    use crate::DynObject;
+
+    #[test]
+    fn dyn_async_read() {
+        let mut reader = pin!(DynObject::<dyn AsyncRead>::new(Cursor::new(b"hello")));
+        let mut buf = [0; 5];
+        assert_eq!(reader.read(&mut buf).now_or_never().unwrap().unwrap(), 5);
+        assert_eq!(&buf, b"hello");
+        let reader = DynObject::<dyn AsyncRead + Send>::new(Cursor::new(b"hello world"));
+        let mut reader = pin!(reader);
+        let (mut a, mut b) = ([0; 5], [0; 6]);
+        let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+        let read = reader.read_vectored(&mut bufs).now_or_never();
+        assert_eq!(read.unwrap().unwrap(), 11);
+        assert_eq!((&a, &b), (b"hello", b" world"));
+    }
+
+    #[test]
+    fn dyn_async_write() {
+        let writer = DynObject::<dyn AsyncWrite + Send>::new(Cursor::new(Vec::new()));
+        let mut writer = pin!(writer);
+        writer.write_all(b"hello").now_or_never().unwrap().unwrap();
+        let bufs = [IoSlice::new(b" "), IoSlice::new(b"world")];
+        let written = writer.write_vectored(&bufs).now_or_never();
+        assert_eq!(written.unwrap().unwrap(), 6);
+        writer.flush().now_or_never().unwrap().unwrap();
+        writer.close().now_or_never().unwrap().unwrap();
+        let mut buf = [0; 2];
+        let mut writer = pin!(DynObject::<dyn AsyncWrite>::new(Cursor::new(&mut buf[..])));
+        assert!(writer.write_all(b"abc").now_or_never().unwrap().is_err());
+    }
+
+    #[test]
+    fn dyn_async_buf_read() {
+        let reader = BufReader::new(&b"hello\nworld"[..]);
+        let mut reader = pin!(DynObject::<dyn AsyncBufRead + Send>::new(reader));
+        let mut line = String::new();
+        reader.read_line(&mut line).now_or_never().unwrap().unwrap();
+        assert_eq!(line, "hello\n");
+        let mut rest = String::new();
+        reader
+            .read_to_string(&mut rest)
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+        assert_eq!(rest, "world");
+        let reader = DynObject::<dyn AsyncBufRead>::new(Cursor::new(b"abc"));
+        let mut reader = pin!(reader.upcast::<dyn AsyncRead>());
+        let mut buf = [0; 3];
+        reader.read_exact(&mut buf).now_or_never().unwrap().unwrap();
+        assert_eq!(&buf, b"abc");
+    }
+}
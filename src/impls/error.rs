@@ -0,0 +1,1568 @@
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fmt: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::fmt::Debug + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: core::fmt::Debug + '__lt> crate::object::Vtable<__Dyn>
+        for dyn core::fmt::Debug + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    fmt: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                            unsafe fn(),
+                        >(|__self, f| {
+                            ::core::mem::transmute(__Dyn::fmt(
+                                __self.as_ref(),
+                                ::core::mem::transmute(f),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn core::fmt::Debug + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn core::fmt::Debug + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn core::fmt::Debug + '__lt>
+    {
+        fn from(object: crate::DynObject<dyn core::fmt::Debug + '__lt, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn core::fmt::Debug + '__lt, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn core::fmt::Debug + '__lt>>
+    {
+        fn from(object: crate::DynObject<dyn core::fmt::Debug + '__lt, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::fmt::Debug + '__target>
+        for dyn core::fmt::Debug + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::fmt::Debug + '__target>
+        for dyn core::fmt::Debug + '__lt + Send
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::fmt::Debug + '__target>
+        for dyn core::fmt::Debug + '__lt + Send + Sync
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Debug
+        for crate::DynObject<dyn core::fmt::Debug + '__lt, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Debug
+        for crate::ThinDynObject<dyn core::fmt::Debug + '__lt, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fmt: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::fmt::Debug + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: core::fmt::Debug + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn core::fmt::Debug + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    fmt: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                            unsafe fn(),
+                        >(|__self, f| {
+                            ::core::mem::transmute(__Dyn::fmt(
+                                __self.as_ref(),
+                                ::core::mem::transmute(f),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn core::fmt::Debug + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn core::fmt::Debug + '__lt + Send, __Storage>>
+        for alloc::boxed::Box<dyn core::fmt::Debug + '__lt + Send>
+    {
+        fn from(object: crate::DynObject<dyn core::fmt::Debug + '__lt + Send, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn core::fmt::Debug + '__lt + Send, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn core::fmt::Debug + '__lt + Send>>
+    {
+        fn from(object: crate::DynObject<dyn core::fmt::Debug + '__lt + Send, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::fmt::Debug + '__target + Send>
+        for dyn core::fmt::Debug + '__lt + Send
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::fmt::Debug + '__target + Send>
+        for dyn core::fmt::Debug + '__lt + Send + Sync
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Debug
+        for crate::DynObject<dyn core::fmt::Debug + '__lt + Send, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Debug
+        for crate::ThinDynObject<dyn core::fmt::Debug + '__lt + Send, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fmt: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::fmt::Debug + '__lt + Send + Sync {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: core::fmt::Debug + '__lt + Send + Sync> crate::object::Vtable<__Dyn>
+        for dyn core::fmt::Debug + '__lt + Send + Sync
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    fmt: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                            unsafe fn(),
+                        >(|__self, f| {
+                            ::core::mem::transmute(__Dyn::fmt(
+                                __self.as_ref(),
+                                ::core::mem::transmute(f),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn core::fmt::Debug + '__lt + Send + Sync {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn core::fmt::Debug + '__lt + Send + Sync, __Storage>,
+        > for alloc::boxed::Box<dyn core::fmt::Debug + '__lt + Send + Sync>
+    {
+        fn from(
+            object: crate::DynObject<dyn core::fmt::Debug + '__lt + Send + Sync, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn core::fmt::Debug + '__lt + Send + Sync, __Storage>,
+        > for core::pin::Pin<alloc::boxed::Box<dyn core::fmt::Debug + '__lt + Send + Sync>>
+    {
+        fn from(
+            object: crate::DynObject<dyn core::fmt::Debug + '__lt + Send + Sync, __Storage>,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::fmt::Debug + '__target + Send + Sync>
+        for dyn core::fmt::Debug + '__lt + Send + Sync
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Debug
+        for crate::DynObject<dyn core::fmt::Debug + '__lt + Send + Sync, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Debug
+        for crate::ThinDynObject<dyn core::fmt::Debug + '__lt + Send + Sync, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fmt: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::fmt::Display + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: core::fmt::Display + '__lt> crate::object::Vtable<__Dyn>
+        for dyn core::fmt::Display + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    fmt: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                            unsafe fn(),
+                        >(|__self, f| {
+                            ::core::mem::transmute(__Dyn::fmt(
+                                __self.as_ref(),
+                                ::core::mem::transmute(f),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn core::fmt::Display + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn core::fmt::Display + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn core::fmt::Display + '__lt>
+    {
+        fn from(object: crate::DynObject<dyn core::fmt::Display + '__lt, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn core::fmt::Display + '__lt, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn core::fmt::Display + '__lt>>
+    {
+        fn from(object: crate::DynObject<dyn core::fmt::Display + '__lt, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::fmt::Display + '__target>
+        for dyn core::fmt::Display + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::fmt::Display + '__target>
+        for dyn core::fmt::Display + '__lt + Send
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::fmt::Display + '__target>
+        for dyn core::fmt::Display + '__lt + Send + Sync
+    {
+    }
+    impl<
+        '__lt,
+        __Dyn: crate::object::Upcast<dyn core::fmt::Display + '__lt> + ?Sized,
+        __Storage: crate::storage::SharedStorage,
+    > core::fmt::Display for crate::DynObject<__Dyn, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(<__Dyn as crate::object::Upcast<dyn core::fmt::Display + '__lt>>::upcast_vtable(self.vtable()).fmt)(self.storage(), f)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Dyn: crate::object::Upcast<dyn core::fmt::Display + '__lt> + ?Sized,
+        __Storage: crate::storage::ThinStorage,
+    > core::fmt::Display for crate::ThinDynObject<__Dyn, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(<__Dyn as crate::object::Upcast<dyn core::fmt::Display + '__lt>>::upcast_vtable(self.vtable()).fmt)(self.storage(), f)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fmt: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::fmt::Display + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: core::fmt::Display + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn core::fmt::Display + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    fmt: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                            unsafe fn(),
+                        >(|__self, f| {
+                            ::core::mem::transmute(__Dyn::fmt(
+                                __self.as_ref(),
+                                ::core::mem::transmute(f),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn core::fmt::Display + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn core::fmt::Display + '__lt + Send, __Storage>>
+        for alloc::boxed::Box<dyn core::fmt::Display + '__lt + Send>
+    {
+        fn from(
+            object: crate::DynObject<dyn core::fmt::Display + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn core::fmt::Display + '__lt + Send, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn core::fmt::Display + '__lt + Send>>
+    {
+        fn from(
+            object: crate::DynObject<dyn core::fmt::Display + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::fmt::Display + '__target + Send>
+        for dyn core::fmt::Display + '__lt + Send
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::fmt::Display + '__target + Send>
+        for dyn core::fmt::Display + '__lt + Send + Sync
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Display
+        for crate::DynObject<dyn core::fmt::Display + '__lt + Send, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Display
+        for crate::ThinDynObject<dyn core::fmt::Display + '__lt + Send, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fmt: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::fmt::Display + '__lt + Send + Sync {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: core::fmt::Display + '__lt + Send + Sync> crate::object::Vtable<__Dyn>
+        for dyn core::fmt::Display + '__lt + Send + Sync
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    fmt: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                            unsafe fn(),
+                        >(|__self, f| {
+                            ::core::mem::transmute(__Dyn::fmt(
+                                __self.as_ref(),
+                                ::core::mem::transmute(f),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn core::fmt::Display + '__lt + Send + Sync {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn core::fmt::Display + '__lt + Send + Sync, __Storage>,
+        > for alloc::boxed::Box<dyn core::fmt::Display + '__lt + Send + Sync>
+    {
+        fn from(
+            object: crate::DynObject<dyn core::fmt::Display + '__lt + Send + Sync, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn core::fmt::Display + '__lt + Send + Sync, __Storage>,
+        > for core::pin::Pin<alloc::boxed::Box<dyn core::fmt::Display + '__lt + Send + Sync>>
+    {
+        fn from(
+            object: crate::DynObject<dyn core::fmt::Display + '__lt + Send + Sync, __Storage>,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::fmt::Display + '__target + Send + Sync>
+        for dyn core::fmt::Display + '__lt + Send + Sync
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Display
+        for crate::DynObject<dyn core::fmt::Display + '__lt + Send + Sync, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Display
+        for crate::ThinDynObject<dyn core::fmt::Display + '__lt + Send + Sync, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        source: unsafe fn(),
+        __upcast0: unsafe fn(),
+        __upcast1: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::error::Error + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: core::error::Error + '__lt> crate::object::Vtable<__Dyn>
+        for dyn core::error::Error + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    source: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::source(__self.as_ref()))
+                        })
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Debug + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast1: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Display + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn core::error::Error + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    // `From` is not implemented for `Box`, as it conflicts with alloc blanket
+    // `impl<E: Error> From<E> for Box<dyn Error>`
+    // SAFETY: `__upcast0` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Debug + '__lt>
+        for dyn core::error::Error + '__lt
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable {
+            // SAFETY: the vtable field has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    // SAFETY: `__upcast1` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Display + '__lt>
+        for dyn core::error::Error + '__lt
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable {
+            // SAFETY: the vtable field has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast1)()
+            }
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::error::Error + '__target>
+        for dyn core::error::Error + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::error::Error + '__target>
+        for dyn core::error::Error + '__lt + Send
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::error::Error + '__target>
+        for dyn core::error::Error + '__lt + Send + Sync
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::error::Error
+        for crate::DynObject<dyn core::error::Error + '__lt, __Storage>
+    where
+        Self: core::fmt::Debug + core::fmt::Display,
+    {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                >(self.vtable().source)(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::error::Error
+        for crate::ThinDynObject<dyn core::error::Error + '__lt, __Storage>
+    where
+        Self: core::fmt::Debug + core::fmt::Display,
+    {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                >(self.vtable().source)(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Debug
+        for crate::DynObject<dyn core::error::Error + '__lt, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(
+                    <dyn core::error::Error + '__lt as crate::object::Upcast<
+                        dyn core::fmt::Debug + '__lt,
+                    >>::upcast_vtable(self.vtable())
+                    .fmt,
+                )(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Debug
+        for crate::ThinDynObject<dyn core::error::Error + '__lt, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(
+                    <dyn core::error::Error + '__lt as crate::object::Upcast<
+                        dyn core::fmt::Debug + '__lt,
+                    >>::upcast_vtable(self.vtable())
+                    .fmt,
+                )(self.storage(), f)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        source: unsafe fn(),
+        __upcast0: unsafe fn(),
+        __upcast1: unsafe fn(),
+        __upcast2: unsafe fn(),
+        __upcast3: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::error::Error + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: core::error::Error + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn core::error::Error + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    source: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::source(__self.as_ref()))
+                        })
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Debug + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast1: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Display + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast2: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Debug + '__lt + Send as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Debug + '__lt + Send as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast3: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Display + '__lt + Send as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Display + '__lt + Send as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn core::error::Error + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn core::error::Error + '__lt + Send, __Storage>>
+        for alloc::boxed::Box<dyn core::error::Error + '__lt + Send>
+    {
+        fn from(
+            object: crate::DynObject<dyn core::error::Error + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn core::error::Error + '__lt + Send, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn core::error::Error + '__lt + Send>>
+    {
+        fn from(
+            object: crate::DynObject<dyn core::error::Error + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: `__upcast0` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Debug + '__lt>
+        for dyn core::error::Error + '__lt + Send
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable {
+            // SAFETY: the vtable field has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    // SAFETY: `__upcast1` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Display + '__lt>
+        for dyn core::error::Error + '__lt + Send
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable {
+            // SAFETY: the vtable field has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast1)()
+            }
+        }
+    }
+    // SAFETY: `__upcast2` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Debug + '__lt + Send>
+        for dyn core::error::Error + '__lt + Send
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Debug + '__lt + Send as crate::object::DynTrait>::Vtable
+        {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Debug + '__lt + Send as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast2)()
+            }
+        }
+    }
+    // SAFETY: `__upcast3` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Display + '__lt + Send>
+        for dyn core::error::Error + '__lt + Send
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Display + '__lt + Send as crate::object::DynTrait>::Vtable
+        {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Display + '__lt + Send as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast3)()
+            }
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::error::Error + '__target + Send>
+        for dyn core::error::Error + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::error::Error
+        for crate::DynObject<dyn core::error::Error + '__lt + Send, __Storage>
+    where
+        Self: core::fmt::Debug + core::fmt::Display,
+    {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                >(self.vtable().source)(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::error::Error
+        for crate::ThinDynObject<dyn core::error::Error + '__lt + Send, __Storage>
+    where
+        Self: core::fmt::Debug + core::fmt::Display,
+    {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                >(self.vtable().source)(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Debug
+        for crate::DynObject<dyn core::error::Error + '__lt + Send, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(
+                    <dyn core::error::Error + '__lt + Send as crate::object::Upcast<
+                        dyn core::fmt::Debug + '__lt,
+                    >>::upcast_vtable(self.vtable())
+                    .fmt,
+                )(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Debug
+        for crate::ThinDynObject<dyn core::error::Error + '__lt + Send, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(
+                    <dyn core::error::Error + '__lt + Send as crate::object::Upcast<
+                        dyn core::fmt::Debug + '__lt,
+                    >>::upcast_vtable(self.vtable())
+                    .fmt,
+                )(self.storage(), f)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        source: unsafe fn(),
+        __upcast0: unsafe fn(),
+        __upcast1: unsafe fn(),
+        __upcast2: unsafe fn(),
+        __upcast3: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::error::Error + '__lt + Send + Sync {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: core::error::Error + '__lt + Send + Sync> crate::object::Vtable<__Dyn>
+        for dyn core::error::Error + '__lt + Send + Sync
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    source: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::source(__self.as_ref()))
+                        })
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Debug + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast1: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Display + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast2: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Debug + '__lt + Send + Sync as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Debug + '__lt + Send + Sync as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast3: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Display + '__lt + Send + Sync as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Display + '__lt + Send + Sync as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn core::error::Error + '__lt + Send + Sync {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    // `From` is not implemented for `Box`, as it conflicts with alloc blanket
+    // `impl<E: Error> From<E> for Box<dyn Error>`
+    // SAFETY: `__upcast0` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Debug + '__lt>
+        for dyn core::error::Error + '__lt + Send + Sync
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable {
+            // SAFETY: the vtable field has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    // SAFETY: `__upcast1` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Display + '__lt>
+        for dyn core::error::Error + '__lt + Send + Sync
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable {
+            // SAFETY: the vtable field has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast1)()
+            }
+        }
+    }
+    // SAFETY: `__upcast2` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Debug + '__lt + Send + Sync>
+        for dyn core::error::Error + '__lt + Send + Sync
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Debug + '__lt + Send + Sync as crate::object::DynTrait>::Vtable
+        {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Debug + '__lt + Send + Sync as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast2)()
+            }
+        }
+    }
+    // SAFETY: `__upcast3` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Display + '__lt + Send + Sync>
+        for dyn core::error::Error + '__lt + Send + Sync
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Display + '__lt + Send + Sync as crate::object::DynTrait>::Vtable{
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Display + '__lt + Send + Sync as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast3)()
+            }
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn core::error::Error + '__target + Send + Sync>
+        for dyn core::error::Error + '__lt + Send + Sync
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::error::Error
+        for crate::DynObject<dyn core::error::Error + '__lt + Send + Sync, __Storage>
+    where
+        Self: core::fmt::Debug + core::fmt::Display,
+    {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                >(self.vtable().source)(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::error::Error
+        for crate::ThinDynObject<dyn core::error::Error + '__lt + Send + Sync, __Storage>
+    where
+        Self: core::fmt::Debug + core::fmt::Display,
+    {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                >(self.vtable().source)(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Debug
+        for crate::DynObject<dyn core::error::Error + '__lt + Send + Sync, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(
+                    <dyn core::error::Error + '__lt + Send + Sync as crate::object::Upcast<
+                        dyn core::fmt::Debug + '__lt,
+                    >>::upcast_vtable(self.vtable())
+                    .fmt,
+                )(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Debug
+        for crate::ThinDynObject<dyn core::error::Error + '__lt + Send + Sync, __Storage>
+    {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(
+                    <dyn core::error::Error + '__lt + Send + Sync as crate::object::Upcast<
+                        dyn core::fmt::Debug + '__lt,
+                    >>::upcast_vtable(self.vtable())
+                    .fmt,
+                )(self.storage(), f)
+            }
+        }
+    }
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::{format, string::ToString};
+    use core::{error::Error, fmt};
+
+    use crate::{DynObject, storage};
+
+    #[derive(Debug)]
+    struct Outer(Inner);
+
+    impl fmt::Display for Outer {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "outer error")
+        }
+    }
+
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[derive(Debug)]
+    struct Inner;
+
+    impl fmt::Display for Inner {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "inner error")
+        }
+    }
+
+    impl Error for Inner {}
+
+    #[test]
+    fn dyn_error() {
+        let error = DynObject::<dyn Error + Send + Sync>::new(Outer(Inner));
+        assert_eq!(error.to_string(), "outer error");
+        assert_eq!(format!("{error:?}"), "Outer(Inner)");
+        assert_eq!(error.source().unwrap().to_string(), "inner error");
+        let display = error.upcast::<dyn fmt::Display + Send + Sync>();
+        assert_eq!(display.to_string(), "outer error");
+        let error = DynObject::<dyn Error + Send, storage::Raw<0>>::new(Inner);
+        assert!(error.source().is_none());
+        assert_eq!(format!("{error} {error:?}"), "inner error Inner");
+        let debug = error.upcast::<dyn fmt::Debug + Send>();
+        assert_eq!(format!("{debug:?}"), "Inner");
+        let error = DynObject::<dyn Error>::new(Inner);
+        assert_eq!(format!("{:?}", error.upcast::<dyn fmt::Debug>()), "Inner");
+    }
+
+    #[test]
+    fn dyn_fmt() {
+        let debug = DynObject::<dyn fmt::Debug>::new("hello");
+        assert_eq!(format!("{debug:?}"), "\"hello\"");
+        let display = DynObject::<dyn fmt::Display + Send>::new(42);
+        assert_eq!(format!("{display:>4}"), "  42");
+    }
+}
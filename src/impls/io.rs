@@ -0,0 +1,1937 @@
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        read: unsafe fn(),
+        read_vectored: unsafe fn(),
+        read_to_end: unsafe fn(),
+        read_to_string: unsafe fn(),
+        read_exact: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::Read + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: std::io::Read + '__lt> crate::object::Vtable<__Dyn>
+        for dyn std::io::Read + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut [u8]) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::read(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                &mut [std::io::IoSliceMut<'_>],
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, bufs| {
+                            ::core::mem::transmute(__Dyn::read_vectored(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(bufs),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read_to_end: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::read_to_end(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read_to_string: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut std::string::String) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::read_to_string(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read_exact: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut [u8]) -> std::io::Result<()>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::read_exact(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn std::io::Read + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::Read + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn std::io::Read + '__lt>
+    {
+        fn from(object: crate::DynObject<dyn std::io::Read + '__lt, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::Read + '__lt, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn std::io::Read + '__lt>>
+    {
+        fn from(object: crate::DynObject<dyn std::io::Read + '__lt, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target> crate::object::Downgrade<dyn std::io::Read + '__target>
+        for dyn std::io::Read + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target> crate::object::Downgrade<dyn std::io::Read + '__target>
+        for dyn std::io::Read + '__lt + Send
+    {
+    }
+    impl<
+        '__lt,
+        __Dyn: crate::object::Upcast<dyn std::io::Read + '__lt> + ?Sized,
+        __Storage: crate::storage::Storage,
+    > std::io::Read for crate::DynObject<__Dyn, __Storage>
+    {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<usize>,
+                >(
+                    <__Dyn as crate::object::Upcast<dyn std::io::Read + '__lt>>::upcast_vtable(
+                        self.vtable(),
+                    )
+                    .read,
+                )(self.storage_mut(), buf)
+            }
+        }
+        fn read_vectored(
+            &mut self,
+            bufs: &mut [std::io::IoSliceMut<'_>],
+        ) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize>,
+                >(
+                    <__Dyn as crate::object::Upcast<dyn std::io::Read + '__lt>>::upcast_vtable(
+                        self.vtable(),
+                    )
+                    .read_vectored,
+                )(self.storage_mut(), bufs)
+            }
+        }
+        fn read_to_end(&mut self, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                >(
+                    <__Dyn as crate::object::Upcast<dyn std::io::Read + '__lt>>::upcast_vtable(
+                        self.vtable(),
+                    )
+                    .read_to_end,
+                )(self.storage_mut(), buf)
+            }
+        }
+        fn read_to_string(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::string::String) -> std::io::Result<usize>,
+                >(
+                    <__Dyn as crate::object::Upcast<dyn std::io::Read + '__lt>>::upcast_vtable(
+                        self.vtable(),
+                    )
+                    .read_to_string,
+                )(self.storage_mut(), buf)
+            }
+        }
+        fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<()>,
+                >(
+                    <__Dyn as crate::object::Upcast<dyn std::io::Read + '__lt>>::upcast_vtable(
+                        self.vtable(),
+                    )
+                    .read_exact,
+                )(self.storage_mut(), buf)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Dyn: crate::object::Upcast<dyn std::io::Read + '__lt> + ?Sized,
+        __Storage: crate::storage::ThinStorage,
+    > std::io::Read for crate::ThinDynObject<__Dyn, __Storage>
+    {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<usize>,
+                >(
+                    <__Dyn as crate::object::Upcast<dyn std::io::Read + '__lt>>::upcast_vtable(
+                        self.vtable(),
+                    )
+                    .read,
+                )(self.storage_mut(), buf)
+            }
+        }
+        fn read_vectored(
+            &mut self,
+            bufs: &mut [std::io::IoSliceMut<'_>],
+        ) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize>,
+                >(
+                    <__Dyn as crate::object::Upcast<dyn std::io::Read + '__lt>>::upcast_vtable(
+                        self.vtable(),
+                    )
+                    .read_vectored,
+                )(self.storage_mut(), bufs)
+            }
+        }
+        fn read_to_end(&mut self, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                >(
+                    <__Dyn as crate::object::Upcast<dyn std::io::Read + '__lt>>::upcast_vtable(
+                        self.vtable(),
+                    )
+                    .read_to_end,
+                )(self.storage_mut(), buf)
+            }
+        }
+        fn read_to_string(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::string::String) -> std::io::Result<usize>,
+                >(
+                    <__Dyn as crate::object::Upcast<dyn std::io::Read + '__lt>>::upcast_vtable(
+                        self.vtable(),
+                    )
+                    .read_to_string,
+                )(self.storage_mut(), buf)
+            }
+        }
+        fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<()>,
+                >(
+                    <__Dyn as crate::object::Upcast<dyn std::io::Read + '__lt>>::upcast_vtable(
+                        self.vtable(),
+                    )
+                    .read_exact,
+                )(self.storage_mut(), buf)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        read: unsafe fn(),
+        read_vectored: unsafe fn(),
+        read_to_end: unsafe fn(),
+        read_to_string: unsafe fn(),
+        read_exact: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::Read + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: std::io::Read + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn std::io::Read + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut [u8]) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::read(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                &mut [std::io::IoSliceMut<'_>],
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, bufs| {
+                            ::core::mem::transmute(__Dyn::read_vectored(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(bufs),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read_to_end: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::read_to_end(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read_to_string: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut std::string::String) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::read_to_string(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read_exact: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut [u8]) -> std::io::Result<()>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::read_exact(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn std::io::Read + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::Read + '__lt + Send, __Storage>>
+        for alloc::boxed::Box<dyn std::io::Read + '__lt + Send>
+    {
+        fn from(object: crate::DynObject<dyn std::io::Read + '__lt + Send, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::Read + '__lt + Send, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn std::io::Read + '__lt + Send>>
+    {
+        fn from(object: crate::DynObject<dyn std::io::Read + '__lt + Send, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn std::io::Read + '__target + Send>
+        for dyn std::io::Read + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::Read
+        for crate::DynObject<dyn std::io::Read + '__lt + Send, __Storage>
+    {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<usize>,
+                >(self.vtable().read)(self.storage_mut(), buf)
+            }
+        }
+        fn read_vectored(
+            &mut self,
+            bufs: &mut [std::io::IoSliceMut<'_>],
+        ) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize>,
+                >(self.vtable().read_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn read_to_end(&mut self, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                >(self.vtable().read_to_end)(self.storage_mut(), buf)
+            }
+        }
+        fn read_to_string(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::string::String) -> std::io::Result<usize>,
+                >(self.vtable().read_to_string)(self.storage_mut(), buf)
+            }
+        }
+        fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<()>,
+                >(self.vtable().read_exact)(self.storage_mut(), buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::Read
+        for crate::ThinDynObject<dyn std::io::Read + '__lt + Send, __Storage>
+    {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<usize>,
+                >(self.vtable().read)(self.storage_mut(), buf)
+            }
+        }
+        fn read_vectored(
+            &mut self,
+            bufs: &mut [std::io::IoSliceMut<'_>],
+        ) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize>,
+                >(self.vtable().read_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn read_to_end(&mut self, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                >(self.vtable().read_to_end)(self.storage_mut(), buf)
+            }
+        }
+        fn read_to_string(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::string::String) -> std::io::Result<usize>,
+                >(self.vtable().read_to_string)(self.storage_mut(), buf)
+            }
+        }
+        fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<()>,
+                >(self.vtable().read_exact)(self.storage_mut(), buf)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        write: unsafe fn(),
+        write_vectored: unsafe fn(),
+        flush: unsafe fn(),
+        write_all: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::Write + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: std::io::Write + '__lt> crate::object::Vtable<__Dyn>
+        for dyn std::io::Write + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    write: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &[u8]) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::write(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    write_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &[std::io::IoSlice<'_>]) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, bufs| {
+                            ::core::mem::transmute(__Dyn::write_vectored(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(bufs),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    flush: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> std::io::Result<()>,
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::flush(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    write_all: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &[u8]) -> std::io::Result<()>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::write_all(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn std::io::Write + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::Write + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn std::io::Write + '__lt>
+    {
+        fn from(object: crate::DynObject<dyn std::io::Write + '__lt, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::Write + '__lt, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn std::io::Write + '__lt>>
+    {
+        fn from(object: crate::DynObject<dyn std::io::Write + '__lt, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn std::io::Write + '__target> for dyn std::io::Write + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn std::io::Write + '__target>
+        for dyn std::io::Write + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::Write
+        for crate::DynObject<dyn std::io::Write + '__lt, __Storage>
+    {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<usize>,
+                >(self.vtable().write)(self.storage_mut(), buf)
+            }
+        }
+        fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[std::io::IoSlice<'_>]) -> std::io::Result<usize>,
+                >(self.vtable().write_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> std::io::Result<()>>(
+                    self.vtable().flush,
+                )(self.storage_mut())
+            }
+        }
+        fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<()>,
+                >(self.vtable().write_all)(self.storage_mut(), buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::Write
+        for crate::ThinDynObject<dyn std::io::Write + '__lt, __Storage>
+    {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<usize>,
+                >(self.vtable().write)(self.storage_mut(), buf)
+            }
+        }
+        fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[std::io::IoSlice<'_>]) -> std::io::Result<usize>,
+                >(self.vtable().write_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> std::io::Result<()>>(
+                    self.vtable().flush,
+                )(self.storage_mut())
+            }
+        }
+        fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<()>,
+                >(self.vtable().write_all)(self.storage_mut(), buf)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        write: unsafe fn(),
+        write_vectored: unsafe fn(),
+        flush: unsafe fn(),
+        write_all: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::Write + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: std::io::Write + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn std::io::Write + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    write: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &[u8]) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::write(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    write_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &[std::io::IoSlice<'_>]) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, bufs| {
+                            ::core::mem::transmute(__Dyn::write_vectored(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(bufs),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    flush: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> std::io::Result<()>,
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::flush(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    write_all: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &[u8]) -> std::io::Result<()>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::write_all(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn std::io::Write + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::Write + '__lt + Send, __Storage>>
+        for alloc::boxed::Box<dyn std::io::Write + '__lt + Send>
+    {
+        fn from(object: crate::DynObject<dyn std::io::Write + '__lt + Send, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::Write + '__lt + Send, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn std::io::Write + '__lt + Send>>
+    {
+        fn from(object: crate::DynObject<dyn std::io::Write + '__lt + Send, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn std::io::Write + '__target + Send>
+        for dyn std::io::Write + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::Write
+        for crate::DynObject<dyn std::io::Write + '__lt + Send, __Storage>
+    {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<usize>,
+                >(self.vtable().write)(self.storage_mut(), buf)
+            }
+        }
+        fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[std::io::IoSlice<'_>]) -> std::io::Result<usize>,
+                >(self.vtable().write_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> std::io::Result<()>>(
+                    self.vtable().flush,
+                )(self.storage_mut())
+            }
+        }
+        fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<()>,
+                >(self.vtable().write_all)(self.storage_mut(), buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::Write
+        for crate::ThinDynObject<dyn std::io::Write + '__lt + Send, __Storage>
+    {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<usize>,
+                >(self.vtable().write)(self.storage_mut(), buf)
+            }
+        }
+        fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[std::io::IoSlice<'_>]) -> std::io::Result<usize>,
+                >(self.vtable().write_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> std::io::Result<()>>(
+                    self.vtable().flush,
+                )(self.storage_mut())
+            }
+        }
+        fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<()>,
+                >(self.vtable().write_all)(self.storage_mut(), buf)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fill_buf: unsafe fn(),
+        consume: unsafe fn(),
+        read_until: unsafe fn(),
+        read_line: unsafe fn(),
+        __upcast0: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::BufRead + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: std::io::BufRead + '__lt> crate::object::Vtable<__Dyn>
+        for dyn std::io::BufRead + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    fill_buf: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> std::io::Result<&[u8]>,
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::fill_buf(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    consume: unsafe {
+                        ::core::mem::transmute::<fn(&mut __Storage, usize), unsafe fn()>(
+                            |__self, amt| {
+                                __Dyn::consume(
+                                    __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                    ::core::mem::transmute(amt),
+                                );
+                            },
+                        )
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read_until: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                u8,
+                                &mut std::vec::Vec<u8>,
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, byte, buf| {
+                            ::core::mem::transmute(__Dyn::read_until(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(byte),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read_line: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut std::string::String) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::read_line(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn std::io::Read + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn std::io::Read + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn std::io::BufRead + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::BufRead + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn std::io::BufRead + '__lt>
+    {
+        fn from(object: crate::DynObject<dyn std::io::BufRead + '__lt, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::BufRead + '__lt, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn std::io::BufRead + '__lt>>
+    {
+        fn from(object: crate::DynObject<dyn std::io::BufRead + '__lt, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: `__upcast0` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn std::io::Read + '__lt>
+        for dyn std::io::BufRead + '__lt
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn std::io::Read + '__lt as crate::object::DynTrait>::Vtable {
+            // SAFETY: the vtable field has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn std::io::Read + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn std::io::BufRead + '__target>
+        for dyn std::io::BufRead + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn std::io::BufRead + '__target>
+        for dyn std::io::BufRead + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::BufRead
+        for crate::DynObject<dyn std::io::BufRead + '__lt, __Storage>
+    where
+        Self: std::io::Read,
+    {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> std::io::Result<&[u8]>>(
+                    self.vtable().fill_buf,
+                )(self.storage_mut())
+            }
+        }
+        fn consume(&mut self, amt: usize) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage, usize)>(
+                    self.vtable().consume,
+                )(self.storage_mut(), amt);
+            }
+        }
+        fn read_until(&mut self, byte: u8, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, u8, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                >(self.vtable().read_until)(self.storage_mut(), byte, buf)
+            }
+        }
+        fn read_line(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::string::String) -> std::io::Result<usize>,
+                >(self.vtable().read_line)(self.storage_mut(), buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::BufRead
+        for crate::ThinDynObject<dyn std::io::BufRead + '__lt, __Storage>
+    where
+        Self: std::io::Read,
+    {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> std::io::Result<&[u8]>>(
+                    self.vtable().fill_buf,
+                )(self.storage_mut())
+            }
+        }
+        fn consume(&mut self, amt: usize) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage, usize)>(
+                    self.vtable().consume,
+                )(self.storage_mut(), amt);
+            }
+        }
+        fn read_until(&mut self, byte: u8, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, u8, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                >(self.vtable().read_until)(self.storage_mut(), byte, buf)
+            }
+        }
+        fn read_line(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::string::String) -> std::io::Result<usize>,
+                >(self.vtable().read_line)(self.storage_mut(), buf)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fill_buf: unsafe fn(),
+        consume: unsafe fn(),
+        read_until: unsafe fn(),
+        read_line: unsafe fn(),
+        __upcast0: unsafe fn(),
+        __upcast1: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::BufRead + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: std::io::BufRead + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn std::io::BufRead + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    fill_buf: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> std::io::Result<&[u8]>,
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::fill_buf(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    consume: unsafe {
+                        ::core::mem::transmute::<fn(&mut __Storage, usize), unsafe fn()>(
+                            |__self, amt| {
+                                __Dyn::consume(
+                                    __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                    ::core::mem::transmute(amt),
+                                );
+                            },
+                        )
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read_until: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                u8,
+                                &mut std::vec::Vec<u8>,
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, byte, buf| {
+                            ::core::mem::transmute(__Dyn::read_until(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(byte),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    read_line: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut std::string::String) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| {
+                            ::core::mem::transmute(__Dyn::read_line(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn std::io::Read + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn std::io::Read + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast1: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn std::io::Read + '__lt + Send as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn std::io::Read + '__lt + Send as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn std::io::BufRead + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::BufRead + '__lt + Send, __Storage>>
+        for alloc::boxed::Box<dyn std::io::BufRead + '__lt + Send>
+    {
+        fn from(object: crate::DynObject<dyn std::io::BufRead + '__lt + Send, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::BufRead + '__lt + Send, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn std::io::BufRead + '__lt + Send>>
+    {
+        fn from(object: crate::DynObject<dyn std::io::BufRead + '__lt + Send, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: `__upcast0` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn std::io::Read + '__lt>
+        for dyn std::io::BufRead + '__lt + Send
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn std::io::Read + '__lt as crate::object::DynTrait>::Vtable {
+            // SAFETY: the vtable field has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn std::io::Read + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    // SAFETY: `__upcast1` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn std::io::Read + '__lt + Send>
+        for dyn std::io::BufRead + '__lt + Send
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn std::io::Read + '__lt + Send as crate::object::DynTrait>::Vtable
+        {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn std::io::Read + '__lt + Send as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast1)()
+            }
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn std::io::BufRead + '__target + Send>
+        for dyn std::io::BufRead + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::BufRead
+        for crate::DynObject<dyn std::io::BufRead + '__lt + Send, __Storage>
+    where
+        Self: std::io::Read,
+    {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> std::io::Result<&[u8]>>(
+                    self.vtable().fill_buf,
+                )(self.storage_mut())
+            }
+        }
+        fn consume(&mut self, amt: usize) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage, usize)>(
+                    self.vtable().consume,
+                )(self.storage_mut(), amt);
+            }
+        }
+        fn read_until(&mut self, byte: u8, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, u8, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                >(self.vtable().read_until)(self.storage_mut(), byte, buf)
+            }
+        }
+        fn read_line(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::string::String) -> std::io::Result<usize>,
+                >(self.vtable().read_line)(self.storage_mut(), buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::BufRead
+        for crate::ThinDynObject<dyn std::io::BufRead + '__lt + Send, __Storage>
+    where
+        Self: std::io::Read,
+    {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> std::io::Result<&[u8]>>(
+                    self.vtable().fill_buf,
+                )(self.storage_mut())
+            }
+        }
+        fn consume(&mut self, amt: usize) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage, usize)>(
+                    self.vtable().consume,
+                )(self.storage_mut(), amt);
+            }
+        }
+        fn read_until(&mut self, byte: u8, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, u8, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                >(self.vtable().read_until)(self.storage_mut(), byte, buf)
+            }
+        }
+        fn read_line(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::string::String) -> std::io::Result<usize>,
+                >(self.vtable().read_line)(self.storage_mut(), buf)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        seek: unsafe fn(),
+        stream_position: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::Seek + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: std::io::Seek + '__lt> crate::object::Vtable<__Dyn>
+        for dyn std::io::Seek + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    seek: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, std::io::SeekFrom) -> std::io::Result<u64>,
+                            unsafe fn(),
+                        >(|__self, pos| {
+                            ::core::mem::transmute(__Dyn::seek(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(pos),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    stream_position: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> std::io::Result<u64>,
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::stream_position(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn std::io::Seek + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::Seek + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn std::io::Seek + '__lt>
+    {
+        fn from(object: crate::DynObject<dyn std::io::Seek + '__lt, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::Seek + '__lt, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn std::io::Seek + '__lt>>
+    {
+        fn from(object: crate::DynObject<dyn std::io::Seek + '__lt, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target> crate::object::Downgrade<dyn std::io::Seek + '__target>
+        for dyn std::io::Seek + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target> crate::object::Downgrade<dyn std::io::Seek + '__target>
+        for dyn std::io::Seek + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::Seek
+        for crate::DynObject<dyn std::io::Seek + '__lt, __Storage>
+    {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, std::io::SeekFrom) -> std::io::Result<u64>,
+                >(self.vtable().seek)(self.storage_mut(), pos)
+            }
+        }
+        fn stream_position(&mut self) -> std::io::Result<u64> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> std::io::Result<u64>>(
+                    self.vtable().stream_position,
+                )(self.storage_mut())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::Seek
+        for crate::ThinDynObject<dyn std::io::Seek + '__lt, __Storage>
+    {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, std::io::SeekFrom) -> std::io::Result<u64>,
+                >(self.vtable().seek)(self.storage_mut(), pos)
+            }
+        }
+        fn stream_position(&mut self) -> std::io::Result<u64> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> std::io::Result<u64>>(
+                    self.vtable().stream_position,
+                )(self.storage_mut())
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        seek: unsafe fn(),
+        stream_position: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::Seek + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: std::io::Seek + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn std::io::Seek + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    seek: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, std::io::SeekFrom) -> std::io::Result<u64>,
+                            unsafe fn(),
+                        >(|__self, pos| {
+                            ::core::mem::transmute(__Dyn::seek(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(pos),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    stream_position: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> std::io::Result<u64>,
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::stream_position(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn std::io::Seek + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::Seek + '__lt + Send, __Storage>>
+        for alloc::boxed::Box<dyn std::io::Seek + '__lt + Send>
+    {
+        fn from(object: crate::DynObject<dyn std::io::Seek + '__lt + Send, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn std::io::Seek + '__lt + Send, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn std::io::Seek + '__lt + Send>>
+    {
+        fn from(object: crate::DynObject<dyn std::io::Seek + '__lt + Send, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn std::io::Seek + '__target + Send>
+        for dyn std::io::Seek + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::Seek
+        for crate::DynObject<dyn std::io::Seek + '__lt + Send, __Storage>
+    {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, std::io::SeekFrom) -> std::io::Result<u64>,
+                >(self.vtable().seek)(self.storage_mut(), pos)
+            }
+        }
+        fn stream_position(&mut self) -> std::io::Result<u64> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> std::io::Result<u64>>(
+                    self.vtable().stream_position,
+                )(self.storage_mut())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::Seek
+        for crate::ThinDynObject<dyn std::io::Seek + '__lt + Send, __Storage>
+    {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, std::io::SeekFrom) -> std::io::Result<u64>,
+                >(self.vtable().seek)(self.storage_mut(), pos)
+            }
+        }
+        fn stream_position(&mut self) -> std::io::Result<u64> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&mut __Storage) -> std::io::Result<u64>>(
+                    self.vtable().stream_position,
+                )(self.storage_mut())
+            }
+        }
+    }
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, Cursor, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write},
+        string::String,
+        vec,
+        vec::Vec,
+    };
+
+    use crate::DynObject;
+
+    #[test]
+    fn dyn_read() {
+        let mut reader = DynObject::<dyn Read>::new(&b"hello world!"[..]);
+        let (mut a, mut b) = ([0; 5], [0; 1]);
+        let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+        assert_eq!(reader.read_vectored(&mut bufs).unwrap(), 6);
+        assert_eq!((&a, &b), (b"hello", b" "));
+        let mut buf = [0; 2];
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf, b"wo");
+        let mut rest = Vec::new();
+        assert_eq!(reader.read_to_end(&mut rest).unwrap(), 4);
+        assert_eq!(rest, b"rld!");
+        let mut reader = DynObject::<dyn Read + Send>::new(&b"hello world"[..]);
+        let mut buf = [0; 6];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"hello ");
+        let mut rest = String::new();
+        assert_eq!(reader.read_to_string(&mut rest).unwrap(), 5);
+        assert_eq!(rest, "world");
+    }
+
+    #[test]
+    fn dyn_write() {
+        let mut buf = Vec::new();
+        let mut writer = DynObject::<dyn Write + Send>::new(&mut buf);
+        assert_eq!(writer.write(b"hello").unwrap(), 5);
+        let bufs = [IoSlice::new(b" "), IoSlice::new(b"world")];
+        assert_eq!(writer.write_vectored(&bufs).unwrap(), 6);
+        let end = '!';
+        write!(writer, "{end}").unwrap();
+        writer.flush().unwrap();
+        drop(writer);
+        assert_eq!(buf, b"hello world!");
+        let mut buf = [0; 2];
+        let mut writer = DynObject::<dyn Write>::new(&mut buf[..]);
+        assert!(writer.write_all(b"abc").is_err());
+    }
+
+    #[test]
+    fn dyn_buf_read() {
+        let reader = Cursor::new(b"hello\nworld!");
+        let mut reader = DynObject::<dyn BufRead + Send>::new(reader);
+        let mut line = String::new();
+        assert_eq!(reader.read_line(&mut line).unwrap(), 6);
+        assert_eq!(line, "hello\n");
+        let mut until = Vec::new();
+        assert_eq!(reader.read_until(b'r', &mut until).unwrap(), 3);
+        assert_eq!(until, b"wor");
+        assert_eq!(reader.fill_buf().unwrap(), b"ld!");
+        reader.consume(2);
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "!");
+        let reader = DynObject::<dyn BufRead>::new(Cursor::new(vec![0, 1, 2]));
+        let mut reader = reader.upcast::<dyn Read>();
+        let mut buf = [0; 3];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [0, 1, 2]);
+    }
+
+    #[test]
+    fn dyn_seek() {
+        let mut seek = DynObject::<dyn Seek>::new(Cursor::new(b"hello"));
+        assert_eq!(seek.seek(SeekFrom::End(-2)).unwrap(), 3);
+        assert_eq!(seek.stream_position().unwrap(), 3);
+        let mut seek = DynObject::<dyn Seek + Send>::new(Cursor::new(b"hello"));
+        assert_eq!(seek.seek(SeekFrom::Start(1)).unwrap(), 1);
+        assert_eq!(seek.stream_position().unwrap(), 1);
+    }
+}
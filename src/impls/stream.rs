@@ -0,0 +1,422 @@
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_next: unsafe fn(),
+        size_hint: unsafe fn(),
+    }
+    impl<'__lt, __TypeItem> crate::object::DynTrait
+        for dyn futures_core::Stream<Item = __TypeItem> + '__lt
+    {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __TypeItem, __Dyn: futures_core::Stream<Item = __TypeItem> + '__lt>
+        crate::object::Vtable<__Dyn> for dyn futures_core::Stream<Item = __TypeItem> + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_next: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<Option<__Dyn::Item>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_next(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    size_hint: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage) -> (usize, Option<usize>),
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::size_hint(__self.as_ref()))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt, __TypeItem> crate::object::DynUnsize
+        for dyn futures_core::Stream<Item = __TypeItem> + '__lt
+    {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __TypeItem, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn futures_core::Stream<Item = __TypeItem> + '__lt, __Storage>,
+        > for alloc::boxed::Box<dyn futures_core::Stream<Item = __TypeItem> + '__lt>
+    {
+        fn from(
+            object: crate::DynObject<
+                dyn futures_core::Stream<Item = __TypeItem> + '__lt,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __TypeItem, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn futures_core::Stream<Item = __TypeItem> + '__lt, __Storage>,
+        >
+        for core::pin::Pin<alloc::boxed::Box<dyn futures_core::Stream<Item = __TypeItem> + '__lt>>
+    {
+        fn from(
+            object: crate::DynObject<
+                dyn futures_core::Stream<Item = __TypeItem> + '__lt,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target, __TypeItem>
+        crate::object::Downgrade<dyn futures_core::Stream<Item = __TypeItem> + '__target>
+        for dyn futures_core::Stream<Item = __TypeItem> + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target, __TypeItem>
+        crate::object::Downgrade<dyn futures_core::Stream<Item = __TypeItem> + '__target>
+        for dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send
+    {
+    }
+    impl<'__lt, __TypeItem, __Storage: crate::storage::Storage> futures_core::Stream
+        for crate::DynObject<dyn futures_core::Stream<Item = __TypeItem> + '__lt, __Storage>
+    {
+        type Item = __TypeItem;
+        fn poll_next(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<Option<Self::Item>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<Option<Self::Item>>,
+                >(self.vtable().poll_next)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&__Storage) -> (usize, Option<usize>)>(
+                    self.vtable().size_hint,
+                )(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __TypeItem, __Storage: crate::storage::ThinStorage> futures_core::Stream
+        for crate::ThinDynObject<dyn futures_core::Stream<Item = __TypeItem> + '__lt, __Storage>
+    {
+        type Item = __TypeItem;
+        fn poll_next(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<Option<Self::Item>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<Option<Self::Item>>,
+                >(self.vtable().poll_next)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&__Storage) -> (usize, Option<usize>)>(
+                    self.vtable().size_hint,
+                )(self.storage())
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_next: unsafe fn(),
+        size_hint: unsafe fn(),
+    }
+    impl<'__lt, __TypeItem> crate::object::DynTrait
+        for dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send
+    {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __TypeItem, __Dyn: futures_core::Stream<Item = __TypeItem> + '__lt + Send>
+        crate::object::Vtable<__Dyn>
+        for dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_next: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<Option<__Dyn::Item>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_next(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    size_hint: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage) -> (usize, Option<usize>),
+                            unsafe fn(),
+                        >(|__self| {
+                            ::core::mem::transmute(__Dyn::size_hint(__self.as_ref()))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt, __TypeItem> crate::object::DynUnsize
+        for dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send
+    {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __TypeItem, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send, __Storage>,
+        > for alloc::boxed::Box<dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send>
+    {
+        fn from(
+            object: crate::DynObject<
+                dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __TypeItem, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send, __Storage>,
+        >
+        for core::pin::Pin<
+            alloc::boxed::Box<dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send>,
+        >
+    {
+        fn from(
+            object: crate::DynObject<
+                dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target, __TypeItem>
+        crate::object::Downgrade<dyn futures_core::Stream<Item = __TypeItem> + '__target + Send>
+        for dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send
+    {
+    }
+    impl<'__lt, __TypeItem, __Storage: crate::storage::Storage> futures_core::Stream
+        for crate::DynObject<dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send, __Storage>
+    {
+        type Item = __TypeItem;
+        fn poll_next(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<Option<Self::Item>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<Option<Self::Item>>,
+                >(self.vtable().poll_next)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&__Storage) -> (usize, Option<usize>)>(
+                    self.vtable().size_hint,
+                )(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __TypeItem, __Storage: crate::storage::ThinStorage> futures_core::Stream
+        for crate::ThinDynObject<
+            dyn futures_core::Stream<Item = __TypeItem> + '__lt + Send,
+            __Storage,
+        >
+    {
+        type Item = __TypeItem;
+        fn poll_next(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<Option<Self::Item>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<Option<Self::Item>>,
+                >(self.vtable().poll_next)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&__Storage) -> (usize, Option<usize>)>(
+                    self.vtable().size_hint,
+                )(self.storage())
+            }
+        }
+    }
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use core::pin::pin;
+
+    use futures::{FutureExt, StreamExt, stream};
+    use futures_core::Stream;
+
+    use crate::DynObject;
+
+    #[test]
+    fn dyn_stream() {
+        let mut stream = pin!(DynObject::<dyn Stream<Item = usize>>::new(stream::iter(
+            0..3
+        )));
+        assert_eq!(stream.size_hint(), (3, Some(3)));
+        assert_eq!(stream.next().now_or_never(), Some(Some(0)));
+        let stream = DynObject::<dyn Stream<Item = usize> + Send>::new(stream::iter(0..3));
+        assert_eq!(stream.size_hint(), (3, Some(3)));
+        assert_eq!(stream.count().now_or_never(), Some(3));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn into_box() {
+        use alloc::boxed::Box;
+        use core::pin::Pin;
+
+        let stream = DynObject::<dyn Stream<Item = usize> + Send>::new(stream::iter(0..3));
+        let mut stream: Pin<Box<dyn Stream<Item = usize> + Send>> = stream.into();
+        assert_eq!(stream.next().now_or_never(), Some(Some(0)));
+    }
+}
@@ -0,0 +1,1554 @@
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_read: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn tokio::io::AsyncRead + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: tokio::io::AsyncRead + '__lt> crate::object::Vtable<__Dyn>
+        for dyn tokio::io::AsyncRead + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_read: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &mut tokio::io::ReadBuf<'_>,
+                            )
+                                -> core::task::Poll<std::io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx, buf| {
+                            ::core::mem::transmute(__Dyn::poll_read(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn tokio::io::AsyncRead + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn tokio::io::AsyncRead + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn tokio::io::AsyncRead + '__lt>
+    {
+        fn from(object: crate::DynObject<dyn tokio::io::AsyncRead + '__lt, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn tokio::io::AsyncRead + '__lt, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn tokio::io::AsyncRead + '__lt>>
+    {
+        fn from(object: crate::DynObject<dyn tokio::io::AsyncRead + '__lt, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn tokio::io::AsyncRead + '__target>
+        for dyn tokio::io::AsyncRead + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn tokio::io::AsyncRead + '__target>
+        for dyn tokio::io::AsyncRead + '__lt + Send
+    {
+    }
+    impl<
+        '__lt,
+        __Dyn: crate::object::Upcast<dyn tokio::io::AsyncRead + '__lt> + ?Sized,
+        __Storage: crate::storage::Storage,
+    > tokio::io::AsyncRead for crate::DynObject<__Dyn, __Storage>
+    {
+        fn poll_read(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut tokio::io::ReadBuf<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(<__Dyn as crate::object::Upcast<dyn tokio::io::AsyncRead + '__lt>>::upcast_vtable(self.vtable()).poll_read)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Dyn: crate::object::Upcast<dyn tokio::io::AsyncRead + '__lt> + ?Sized,
+        __Storage: crate::storage::ThinStorage,
+    > tokio::io::AsyncRead for crate::ThinDynObject<__Dyn, __Storage>
+    {
+        fn poll_read(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut tokio::io::ReadBuf<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(<__Dyn as crate::object::Upcast<dyn tokio::io::AsyncRead + '__lt>>::upcast_vtable(self.vtable()).poll_read)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_read: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn tokio::io::AsyncRead + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: tokio::io::AsyncRead + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn tokio::io::AsyncRead + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_read: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &mut tokio::io::ReadBuf<'_>,
+                            )
+                                -> core::task::Poll<std::io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx, buf| {
+                            ::core::mem::transmute(__Dyn::poll_read(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn tokio::io::AsyncRead + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn tokio::io::AsyncRead + '__lt + Send, __Storage>>
+        for alloc::boxed::Box<dyn tokio::io::AsyncRead + '__lt + Send>
+    {
+        fn from(
+            object: crate::DynObject<dyn tokio::io::AsyncRead + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn tokio::io::AsyncRead + '__lt + Send, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn tokio::io::AsyncRead + '__lt + Send>>
+    {
+        fn from(
+            object: crate::DynObject<dyn tokio::io::AsyncRead + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn tokio::io::AsyncRead + '__target + Send>
+        for dyn tokio::io::AsyncRead + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> tokio::io::AsyncRead
+        for crate::DynObject<dyn tokio::io::AsyncRead + '__lt + Send, __Storage>
+    {
+        fn poll_read(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut tokio::io::ReadBuf<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_read)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> tokio::io::AsyncRead
+        for crate::ThinDynObject<dyn tokio::io::AsyncRead + '__lt + Send, __Storage>
+    {
+        fn poll_read(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut tokio::io::ReadBuf<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_read)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_write: unsafe fn(),
+        poll_flush: unsafe fn(),
+        poll_shutdown: unsafe fn(),
+        poll_write_vectored: unsafe fn(),
+        is_write_vectored: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn tokio::io::AsyncWrite + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: tokio::io::AsyncWrite + '__lt> crate::object::Vtable<__Dyn>
+        for dyn tokio::io::AsyncWrite + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_write: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &[u8],
+                            )
+                                -> core::task::Poll<std::io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, buf| {
+                            ::core::mem::transmute(__Dyn::poll_write(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_flush: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<std::io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_flush(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_shutdown: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<std::io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_shutdown(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_write_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &[std::io::IoSlice<'_>],
+                            )
+                                -> core::task::Poll<std::io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, bufs| {
+                            ::core::mem::transmute(__Dyn::poll_write_vectored(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(bufs),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    is_write_vectored: unsafe {
+                        ::core::mem::transmute::<fn(&__Storage) -> bool, unsafe fn()>(|__self| {
+                            ::core::mem::transmute(__Dyn::is_write_vectored(__self.as_ref()))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn tokio::io::AsyncWrite + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn tokio::io::AsyncWrite + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn tokio::io::AsyncWrite + '__lt>
+    {
+        fn from(object: crate::DynObject<dyn tokio::io::AsyncWrite + '__lt, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn tokio::io::AsyncWrite + '__lt, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn tokio::io::AsyncWrite + '__lt>>
+    {
+        fn from(object: crate::DynObject<dyn tokio::io::AsyncWrite + '__lt, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn tokio::io::AsyncWrite + '__target>
+        for dyn tokio::io::AsyncWrite + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn tokio::io::AsyncWrite + '__target>
+        for dyn tokio::io::AsyncWrite + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> tokio::io::AsyncWrite
+        for crate::DynObject<dyn tokio::io::AsyncWrite + '__lt, __Storage>
+    {
+        fn poll_write(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &[u8],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[u8],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_flush)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_shutdown(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_shutdown)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_write_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[std::io::IoSlice<'_>],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write_vectored)(
+                    self.storage_pinned_mut(), cx, bufs
+                )
+            }
+        }
+        fn is_write_vectored(&self) -> bool {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&__Storage) -> bool>(
+                    self.vtable().is_write_vectored,
+                )(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> tokio::io::AsyncWrite
+        for crate::ThinDynObject<dyn tokio::io::AsyncWrite + '__lt, __Storage>
+    {
+        fn poll_write(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &[u8],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[u8],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_flush)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_shutdown(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_shutdown)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_write_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[std::io::IoSlice<'_>],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write_vectored)(
+                    self.storage_pinned_mut(), cx, bufs
+                )
+            }
+        }
+        fn is_write_vectored(&self) -> bool {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&__Storage) -> bool>(
+                    self.vtable().is_write_vectored,
+                )(self.storage())
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_write: unsafe fn(),
+        poll_flush: unsafe fn(),
+        poll_shutdown: unsafe fn(),
+        poll_write_vectored: unsafe fn(),
+        is_write_vectored: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn tokio::io::AsyncWrite + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: tokio::io::AsyncWrite + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn tokio::io::AsyncWrite + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_write: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &[u8],
+                            )
+                                -> core::task::Poll<std::io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, buf| {
+                            ::core::mem::transmute(__Dyn::poll_write(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(buf),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_flush: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<std::io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_flush(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_shutdown: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<std::io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_shutdown(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_write_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &[std::io::IoSlice<'_>],
+                            )
+                                -> core::task::Poll<std::io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, bufs| {
+                            ::core::mem::transmute(__Dyn::poll_write_vectored(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(bufs),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    is_write_vectored: unsafe {
+                        ::core::mem::transmute::<fn(&__Storage) -> bool, unsafe fn()>(|__self| {
+                            ::core::mem::transmute(__Dyn::is_write_vectored(__self.as_ref()))
+                        })
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn tokio::io::AsyncWrite + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn tokio::io::AsyncWrite + '__lt + Send, __Storage>>
+        for alloc::boxed::Box<dyn tokio::io::AsyncWrite + '__lt + Send>
+    {
+        fn from(
+            object: crate::DynObject<dyn tokio::io::AsyncWrite + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn tokio::io::AsyncWrite + '__lt + Send, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn tokio::io::AsyncWrite + '__lt + Send>>
+    {
+        fn from(
+            object: crate::DynObject<dyn tokio::io::AsyncWrite + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn tokio::io::AsyncWrite + '__target + Send>
+        for dyn tokio::io::AsyncWrite + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> tokio::io::AsyncWrite
+        for crate::DynObject<dyn tokio::io::AsyncWrite + '__lt + Send, __Storage>
+    {
+        fn poll_write(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &[u8],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[u8],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_flush)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_shutdown(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_shutdown)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_write_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[std::io::IoSlice<'_>],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write_vectored)(
+                    self.storage_pinned_mut(), cx, bufs
+                )
+            }
+        }
+        fn is_write_vectored(&self) -> bool {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&__Storage) -> bool>(
+                    self.vtable().is_write_vectored,
+                )(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> tokio::io::AsyncWrite
+        for crate::ThinDynObject<dyn tokio::io::AsyncWrite + '__lt + Send, __Storage>
+    {
+        fn poll_write(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &[u8],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[u8],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_flush)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_shutdown(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_shutdown)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_write_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[std::io::IoSlice<'_>],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write_vectored)(
+                    self.storage_pinned_mut(), cx, bufs
+                )
+            }
+        }
+        fn is_write_vectored(&self) -> bool {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(&__Storage) -> bool>(
+                    self.vtable().is_write_vectored,
+                )(self.storage())
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_fill_buf: unsafe fn(),
+        consume: unsafe fn(),
+        __upcast0: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn tokio::io::AsyncBufRead + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: tokio::io::AsyncBufRead + '__lt> crate::object::Vtable<__Dyn>
+        for dyn tokio::io::AsyncBufRead + '__lt
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_fill_buf: unsafe {
+                        ::core::mem::transmute::<
+                            for<'a> fn(
+                                ::core::pin::Pin<&'a mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<std::io::Result<&'a [u8]>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_fill_buf(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    consume: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::pin::Pin<&mut __Storage>, usize),
+                            unsafe fn(),
+                        >(|__self, amt| {
+                            __Dyn::consume(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(amt),
+                            );
+                        })
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn tokio::io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn tokio::io::AsyncRead + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn tokio::io::AsyncBufRead + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt, __Storage>>
+        for alloc::boxed::Box<dyn tokio::io::AsyncBufRead + '__lt>
+    {
+        fn from(object: crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt, __Storage>) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt, __Storage>>
+        for core::pin::Pin<alloc::boxed::Box<dyn tokio::io::AsyncBufRead + '__lt>>
+    {
+        fn from(object: crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt, __Storage>) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: `__upcast0` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn tokio::io::AsyncRead + '__lt>
+        for dyn tokio::io::AsyncBufRead + '__lt
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn tokio::io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable
+        {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn tokio::io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn tokio::io::AsyncBufRead + '__target>
+        for dyn tokio::io::AsyncBufRead + '__lt
+    {
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn tokio::io::AsyncBufRead + '__target>
+        for dyn tokio::io::AsyncBufRead + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> tokio::io::AsyncBufRead
+        for crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt, __Storage>
+    where
+        Self: tokio::io::AsyncRead,
+    {
+        fn poll_fill_buf<'a>(
+            self: core::pin::Pin<&'a mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<&'a [u8]>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&'a mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<&'a [u8]>>,
+                >(self.vtable().poll_fill_buf)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn consume(self: core::pin::Pin<&mut Self>, amt: usize) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(::core::pin::Pin<&mut __Storage>, usize)>(
+                    self.vtable().consume,
+                )(self.storage_pinned_mut(), amt);
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> tokio::io::AsyncBufRead
+        for crate::ThinDynObject<dyn tokio::io::AsyncBufRead + '__lt, __Storage>
+    where
+        Self: tokio::io::AsyncRead,
+    {
+        fn poll_fill_buf<'a>(
+            self: core::pin::Pin<&'a mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<&'a [u8]>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&'a mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<&'a [u8]>>,
+                >(self.vtable().poll_fill_buf)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn consume(self: core::pin::Pin<&mut Self>, amt: usize) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(::core::pin::Pin<&mut __Storage>, usize)>(
+                    self.vtable().consume,
+                )(self.storage_pinned_mut(), amt);
+            }
+        }
+    }
+};
+
+const _: () = {
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_fill_buf: unsafe fn(),
+        consume: unsafe fn(),
+        __upcast0: unsafe fn(),
+        __upcast1: unsafe fn(),
+    }
+    impl<'__lt> crate::object::DynTrait for dyn tokio::io::AsyncBufRead + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(vtable: &Self::Vtable) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    // SAFETY: vtable fields respect trait contract
+    unsafe impl<'__lt, __Dyn: tokio::io::AsyncBufRead + '__lt + Send> crate::object::Vtable<__Dyn>
+        for dyn tokio::io::AsyncBufRead + '__lt + Send
+    {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    // SAFETY: transmutation is only used to erase the function type
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    poll_fill_buf: unsafe {
+                        ::core::mem::transmute::<
+                            for<'a> fn(
+                                ::core::pin::Pin<&'a mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            )
+                                -> core::task::Poll<std::io::Result<&'a [u8]>>,
+                            unsafe fn(),
+                        >(|__self, cx| {
+                            ::core::mem::transmute(__Dyn::poll_fill_buf(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ))
+                        })
+                    },
+                    #[allow(clippy::missing_transmute_annotations, clippy::useless_transmute)]
+                    // SAFETY: transmutation are only used to erase lifetime,
+                    // the real lifetime being enforced in the trait implementation
+                    consume: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::pin::Pin<&mut __Storage>, usize),
+                            unsafe fn(),
+                        >(|__self, amt| {
+                            __Dyn::consume(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(amt),
+                            );
+                        })
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn tokio::io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn tokio::io::AsyncRead + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    // SAFETY: transmutation is only used to erase the function type
+                    __upcast1: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn tokio::io::AsyncRead + '__lt + Send as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn tokio::io::AsyncRead + '__lt + Send as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    // SAFETY: `__unsize` is initialized with the concrete type of the vtable
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn tokio::io::AsyncBufRead + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            // SAFETY: transmutation is only used to erase the function type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt + Send, __Storage>,
+        > for alloc::boxed::Box<dyn tokio::io::AsyncBufRead + '__lt + Send>
+    {
+        fn from(
+            object: crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    #[cfg(feature = "alloc")]
+    impl<'__lt, __Storage: crate::storage::Storage>
+        ::core::convert::From<
+            crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt + Send, __Storage>,
+        > for core::pin::Pin<alloc::boxed::Box<dyn tokio::io::AsyncBufRead + '__lt + Send>>
+    {
+        fn from(
+            object: crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box().into()
+        }
+    }
+    // SAFETY: `__upcast0` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn tokio::io::AsyncRead + '__lt>
+        for dyn tokio::io::AsyncBufRead + '__lt + Send
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn tokio::io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable
+        {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn tokio::io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    // SAFETY: `__upcast1` is initialized with the same concrete type and storage
+    unsafe impl<'__lt> crate::object::Upcast<dyn tokio::io::AsyncRead + '__lt + Send>
+        for dyn tokio::io::AsyncBufRead + '__lt + Send
+    {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn tokio::io::AsyncRead + '__lt + Send as crate::object::DynTrait>::Vtable
+        {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn tokio::io::AsyncRead + '__lt + Send as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast1)()
+            }
+        }
+    }
+    // SAFETY: both trait objects are generated by `dyn_object`, with the same fields,
+    // and the target has less bounds and a shorter lifetime
+    unsafe impl<'__target, '__lt: '__target>
+        crate::object::Downgrade<dyn tokio::io::AsyncBufRead + '__target + Send>
+        for dyn tokio::io::AsyncBufRead + '__lt + Send
+    {
+    }
+    impl<'__lt, __Storage: crate::storage::Storage> tokio::io::AsyncBufRead
+        for crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt + Send, __Storage>
+    where
+        Self: tokio::io::AsyncRead,
+    {
+        fn poll_fill_buf<'a>(
+            self: core::pin::Pin<&'a mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<&'a [u8]>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&'a mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<&'a [u8]>>,
+                >(self.vtable().poll_fill_buf)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn consume(self: core::pin::Pin<&mut Self>, amt: usize) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(::core::pin::Pin<&mut __Storage>, usize)>(
+                    self.vtable().consume,
+                )(self.storage_pinned_mut(), amt);
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> tokio::io::AsyncBufRead
+        for crate::ThinDynObject<dyn tokio::io::AsyncBufRead + '__lt + Send, __Storage>
+    where
+        Self: tokio::io::AsyncRead,
+    {
+        fn poll_fill_buf<'a>(
+            self: core::pin::Pin<&'a mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<&'a [u8]>> {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&'a mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<&'a [u8]>>,
+                >(self.vtable().poll_fill_buf)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn consume(self: core::pin::Pin<&mut Self>, amt: usize) {
+            // SAFETY: the vtable method has been initialized with the given type
+            unsafe {
+                ::core::mem::transmute::<unsafe fn(), fn(::core::pin::Pin<&mut __Storage>, usize)>(
+                    self.vtable().consume,
+                )(self.storage_pinned_mut(), amt);
+            }
+        }
+    }
+};
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use core::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+    use std::{
+        io::{Cursor, IoSlice},
+        vec::Vec,
+    };
+
+    use tokio::io::{AsyncBufRead, AsyncRead, AsyncWrite, ReadBuf};
+
+    use crate::DynObject;
+
+    #[test]
+    fn dyn_async_read() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut reader = pin!(DynObject::<dyn AsyncRead>::new(&b"hello"[..]));
+        let mut buf = [0; 5];
+        let mut read_buf = ReadBuf::new(&mut buf);
+        let poll = reader.as_mut().poll_read(&mut cx, &mut read_buf);
+        assert!(matches!(poll, Poll::Ready(Ok(()))));
+        assert_eq!(read_buf.filled(), b"hello");
+        let mut reader = pin!(DynObject::<dyn AsyncRead + Send>::new(&b"world"[..]));
+        let mut read_buf = ReadBuf::new(&mut buf[..3]);
+        let poll = reader.as_mut().poll_read(&mut cx, &mut read_buf);
+        assert!(matches!(poll, Poll::Ready(Ok(()))));
+        assert_eq!(read_buf.filled(), b"wor");
+    }
+
+    #[test]
+    fn dyn_async_write() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut writer = pin!(DynObject::<dyn AsyncWrite + Send>::new(Vec::new()));
+        assert!(writer.is_write_vectored());
+        let poll = writer.as_mut().poll_write(&mut cx, b"hello");
+        assert!(matches!(poll, Poll::Ready(Ok(5))));
+        let bufs = [IoSlice::new(b" "), IoSlice::new(b"world")];
+        let poll = writer.as_mut().poll_write_vectored(&mut cx, &bufs);
+        assert!(matches!(poll, Poll::Ready(Ok(6))));
+        assert!(matches!(
+            writer.as_mut().poll_flush(&mut cx),
+            Poll::Ready(Ok(()))
+        ));
+        assert!(matches!(
+            writer.as_mut().poll_shutdown(&mut cx),
+            Poll::Ready(Ok(()))
+        ));
+        let mut buf = [0; 2];
+        let mut writer = pin!(DynObject::<dyn AsyncWrite>::new(Cursor::new(&mut buf[..])));
+        let poll = writer.as_mut().poll_write(&mut cx, b"abc");
+        assert!(matches!(poll, Poll::Ready(Ok(2))));
+    }
+
+    #[test]
+    fn dyn_async_buf_read() {
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut reader = pin!(DynObject::<dyn AsyncBufRead + Send>::new(&b"hello"[..]));
+        let poll = reader.as_mut().poll_fill_buf(&mut cx);
+        assert!(matches!(poll, Poll::Ready(Ok(b"hello"))));
+        reader.as_mut().consume(2);
+        let poll = reader.as_mut().poll_fill_buf(&mut cx);
+        assert!(matches!(poll, Poll::Ready(Ok(b"llo"))));
+        let reader = DynObject::<dyn AsyncBufRead>::new(&b"abc"[..]);
+        let mut reader = pin!(reader.upcast::<dyn AsyncRead>());
+        let mut buf = [0; 3];
+        let mut read_buf = ReadBuf::new(&mut buf);
+        let poll = reader.as_mut().poll_read(&mut cx, &mut read_buf);
+        assert!(matches!(poll, Poll::Ready(Ok(()))));
+        assert_eq!(read_buf.filled(), b"abc");
+    }
+}
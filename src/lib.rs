@@ -66,9 +66,12 @@ compile_error!(
 
 #[cfg(any(feature = "alloc", doc))]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 use core::{
-    hint, mem,
+    fmt,
+    marker::PhantomData,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -76,103 +79,265 @@ use core::{
 mod impls;
 #[cfg(feature = "macros")]
 mod macros;
+pub mod maybe_sync;
 pub mod object;
 pub mod storage;
+#[cfg(feature = "alloc")]
+pub mod vec;
 
 #[cfg(feature = "macros")]
-pub use macros::{dyn_object, dyn_trait, sync};
-pub use object::DynObject;
+pub use macros::{dyn_object, dyn_trait, inlined, sync};
+pub use maybe_sync::MaybeSync;
+pub use object::{DynObject, ThinDynObject};
+#[cfg(feature = "alloc")]
+pub use vec::DynVec;
+/// Expands its input only if `alloc` feature is enabled and `Arc` is available, used in
+/// [`dyn_trait`] generated code.
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_arc {
+    ($($tt:tt)*) => { $($tt)* };
+}
+#[cfg(not(all(feature = "alloc", target_has_atomic = "ptr")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_arc {
+    ($($tt:tt)*) => {};
+}
 #[cfg(all(doc, not(feature = "macros")))]
 #[doc(hidden)]
 pub fn dyn_trait() {}
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::boxed::Box;
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    pub use alloc::sync::Arc;
+    #[cfg(feature = "alloc")]
+    pub use alloc::vec::Vec;
+    use core::alloc::Layout;
+
+    /// Function with its arguments as a tuple, used to name the returned type of a method
+    /// in [`dyn_trait`](crate::dyn_trait) generated code.
+    pub trait FnReturn<Args> {
+        type Output;
+    }
+
+    macro_rules! fn_return {
+        ($($arg:ident),*) => {
+            impl<F: FnOnce($($arg),*) -> R, $($arg,)* R> FnReturn<($($arg,)*)> for F {
+                type Output = R;
+            }
+        };
+    }
+    fn_return!(A0);
+    fn_return!(A0, A1);
+    fn_return!(A0, A1, A2);
+    fn_return!(A0, A1, A2, A3);
+    fn_return!(A0, A1, A2, A3, A4);
+    fn_return!(A0, A1, A2, A3, A4, A5);
+    fn_return!(A0, A1, A2, A3, A4, A5, A6);
+    fn_return!(A0, A1, A2, A3, A4, A5, A6, A7);
+    fn_return!(A0, A1, A2, A3, A4, A5, A6, A7, A8);
+    fn_return!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9);
+    fn_return!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+    fn_return!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+
+    /// Returns the layout of the value projected from the returned value of `f`; the
+    /// projection is never called.
+    pub const fn return_layout<Args, F: FnReturn<Args>, T>(
+        _f: &F,
+        _projection: &impl FnOnce(F::Output) -> T,
+    ) -> Layout {
+        Layout::new::<T>()
+    }
+}
+
+/// Expands its input only if `alloc` feature is enabled, used in [`dyn_object`] generated code.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_alloc {
+    ($($tt:tt)*) => { $($tt)* };
+}
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_alloc {
+    ($($tt:tt)*) => {};
+}
 #[cfg(all(doc, not(feature = "macros")))]
 #[doc(hidden)]
 pub fn dyn_object() {}
 
-/// An async wrapper with an optimized synchronous execution path.
+/// A wrapper with an optimized inline execution path, bypassing the vtable of a trait object.
+///
+/// It is used in combination with trait objects, such as `DynObject<dyn Iterator<Item = T>>`,
+/// the inline variant being a concrete type implementing the same trait, such as
+/// `core::option::IntoIter<T>` or `core::slice::Iter<'_, T>`.
 ///
-/// It is used in combination with `Future` trait objects, such as
-/// `DynObject<dyn Future<Output=T>>`.
+/// It implements `Iterator`, `Future`, and `Stream` with `futures-core` feature; other traits
+/// must be implemented for it by the crate defining them.
 ///
 /// # Examples
 ///
 /// ```rust
-/// # use dyn_utils::{DynObject, MaybeSync};
+/// # use dyn_utils::{DynObject, MaybeInline};
 ///
-/// trait Callback {
-///     fn call(&self, arg: &str) -> MaybeSync<DynObject<dyn Future<Output = ()>>>;
+/// trait Values {
+///     fn values(
+///         &self,
+///     ) -> MaybeInline<DynObject<dyn Iterator<Item = u8> + '_>, core::option::IntoIter<u8>>;
 /// }
 ///
-/// struct Print;
-/// impl Callback for Print {
-///     fn call(&self, arg: &str) -> MaybeSync<DynObject<dyn Future<Output = ()>>> {
-///         println!("{arg}");
-///         MaybeSync::Sync(())
+/// struct Single(u8);
+/// impl Values for Single {
+///     fn values(
+///         &self,
+///     ) -> MaybeInline<DynObject<dyn Iterator<Item = u8> + '_>, core::option::IntoIter<u8>>
+///     {
+///         MaybeInline::Inline(Some(self.0).into_iter())
 ///     }
 /// }
 /// ```
-pub enum MaybeSync<F: Future> {
-    /// Optimized synchronous execution path.
-    Sync(F::Output),
-    /// Asynchronous wrapper
-    Async(F),
-    /// Synchronous execution path already polled
-    SyncPolled,
+#[derive(Debug, Clone)]
+pub enum MaybeInline<D, I> {
+    /// Optimized inline execution path.
+    Inline(I),
+    /// Dynamic dispatch through the trait object.
+    Dyn(D),
 }
 
-impl<F: Future> MaybeSync<F> {
-    /// # Safety
-    ///
-    /// `self` must be `Self::Sync` variant.
-    #[cfg_attr(coverage_nightly, coverage(off))] // Because of `unreachable_unchecked` branch
-    #[inline(always)]
-    unsafe fn take_sync(&mut self) -> F::Output {
-        match mem::replace(self, Self::SyncPolled) {
-            Self::Sync(res) => res,
-            // SAFETY: as per function contract
-            _ => unsafe { hint::unreachable_unchecked() },
+impl<D: Iterator, I: Iterator<Item = D::Item>> Iterator for MaybeInline<D, I> {
+    type Item = D::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Inline(iter) => iter.next(),
+            Self::Dyn(iter) => iter.next(),
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Inline(iter) => iter.size_hint(),
+            Self::Dyn(iter) => iter.size_hint(),
+        }
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self {
+            Self::Inline(iter) => iter.nth(n),
+            Self::Dyn(iter) => iter.nth(n),
         }
     }
 }
 
-impl<F: Future> Future for MaybeSync<F> {
-    type Output = F::Output;
+impl<D: Future, I: Future<Output = D::Output>> Future for MaybeInline<D, I> {
+    type Output = D::Output;
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         // SAFETY: pinned data is not moved
         match unsafe { self.get_unchecked_mut() } {
-            // SAFETY: res is `Self::Sync`
-            res @ MaybeSync::Sync(_) => Poll::Ready(unsafe { res.take_sync() }),
             // SAFETY: `fut` is pinned as `self` is
-            MaybeSync::Async(fut) => unsafe { Pin::new_unchecked(fut) }.poll(cx),
-            _ => panic!("future polled after completion"),
+            Self::Inline(fut) => unsafe { Pin::new_unchecked(fut) }.poll(cx),
+            // SAFETY: `fut` is pinned as `self` is
+            Self::Dyn(fut) => unsafe { Pin::new_unchecked(fut) }.poll(cx),
         }
     }
 }
 
-#[cfg_attr(coverage_nightly, coverage(off))] // Because of `unreachable_unchecked` branch
+#[cfg(feature = "futures-core")]
+impl<D: futures_core::Stream, I: futures_core::Stream<Item = D::Item>> futures_core::Stream
+    for MaybeInline<D, I>
+{
+    type Item = D::Item;
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // SAFETY: pinned data is not moved
+        match unsafe { self.get_unchecked_mut() } {
+            // SAFETY: `stream` is pinned as `self` is
+            Self::Inline(stream) => unsafe { Pin::new_unchecked(stream) }.poll_next(cx),
+            // SAFETY: `stream` is pinned as `self` is
+            Self::Dyn(stream) => unsafe { Pin::new_unchecked(stream) }.poll_next(cx),
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Inline(stream) => stream.size_hint(),
+            Self::Dyn(stream) => stream.size_hint(),
+        }
+    }
+}
+
+/// A zero-sized factory of `T`.
+///
+/// The factory traits generated by [`dyn_trait`] with `factory` argument are implemented for
+/// `Factory<T>`, so a type can be registered dynamically, e.g. as `Box<dyn DynTraitFactory>`.
+pub struct Factory<T: ?Sized>(PhantomData<fn() -> T>);
+
+impl<T: ?Sized> Factory<T> {
+    /// Returns the factory of `T`.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: ?Sized> Default for Factory<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> Clone for Factory<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Factory<T> {}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl<T: ?Sized> fmt::Debug for Factory<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Factory")
+            .field(&core::any::type_name::<T>())
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use core::{
-        future::{Ready, ready},
-        pin::pin,
-    };
+    use core::future::{Ready, ready};
 
     use futures::FutureExt;
 
-    use crate::MaybeSync;
+    use crate::MaybeInline;
 
     #[test]
-    fn maybe_sync() {
-        for maybe_sync in [MaybeSync::Sync(42), MaybeSync::Async(ready(42))] {
-            assert_eq!(maybe_sync.now_or_never(), Some(42));
+    fn maybe_inline() {
+        type Iter = MaybeInline<core::ops::Range<u8>, core::option::IntoIter<u8>>;
+        for (mut iter, n) in [(Iter::Inline(Some(0).into_iter()), 1), (Iter::Dyn(0..3), 3)] {
+            assert_eq!(iter.size_hint(), (n, Some(n)));
+            assert_eq!(iter.nth(n - 1), Some(n as u8 - 1));
+            assert_eq!(iter.next(), None);
         }
+        let inline = MaybeInline::<Ready<i32>, _>::Inline(ready(42));
+        assert_eq!(inline.now_or_never(), Some(42));
+        let dyn_ = MaybeInline::<_, Ready<i32>>::Dyn(ready(42));
+        assert_eq!(dyn_.now_or_never(), Some(42));
     }
 
+    #[cfg(feature = "futures-core")]
     #[test]
-    #[should_panic(expected = "future polled after completion")]
-    fn maybe_sync_polled_after_completion() {
-        let mut maybe_sync = pin!(MaybeSync::<Ready<i32>>::Sync(42));
-        assert_eq!(maybe_sync.as_mut().now_or_never(), Some(42));
-        maybe_sync.now_or_never();
+    fn maybe_inline_stream() {
+        use futures::{Stream, StreamExt, stream};
+
+        type MaybeStream = MaybeInline<stream::Iter<core::ops::Range<u8>>, stream::Once<Ready<u8>>>;
+        let streams = [
+            (MaybeStream::Inline(stream::once(ready(0))), 1),
+            (MaybeStream::Dyn(stream::iter(0..3)), 3),
+        ];
+        for (mut stream, n) in streams {
+            assert_eq!(stream.size_hint(), (n, Some(n)));
+            assert_eq!(stream.next().now_or_never(), Some(Some(0)));
+        }
     }
 }
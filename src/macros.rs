@@ -1,10 +1,26 @@
 /// Make a trait compatible with `DynObject`.
 ///
+/// Methods taking `self` by value are supported: the object is moved out of the storage,
+/// which is released before the method is called on the concrete type.
 ///
+/// If the trait has methods taking `self` by value or by mutable reference, it is only
+/// implemented for `DynObject` with a [`Storage`](crate::storage::Storage),
+/// so not with shared storages like [`storage::Arc`](crate::storage::Arc).
+///
+/// With a unique storage, `DynObject<dyn Trait>` can be converted into `Box<dyn Trait>` with
+/// [`From`] or `DynObject::into_box`.
 ///
 /// # Arguments
 ///
 /// - `bounds`: Additional bounds, e.g. `Send`, allowing to use `DynObject<dyn Trait + Send>`.
+/// - `clone`: Adds a clone function to the vtable, making `DynObject<dyn Trait>` implement
+///   `Clone`; the concrete type must implement `Clone`. Cloning panics if the storage cannot
+///   construct the clone, e.g. with [`Slot`](crate::storage::Slot).
+/// - `downcast`: Adds the [`TypeId`](core::any::TypeId) of the concrete type to the vtable,
+///   making `DynObject<dyn Trait>` downcastable with `is`/`downcast_ref`/`downcast_mut`/`downcast`
+///   methods; the concrete type must be `'static`.
+/// - `supertraits`: Supertraits, e.g. `Named + Debug`, whose vtables are embedded in the vtable,
+///   see [below](#supertraits).
 ///
 /// # Examples
 ///
@@ -29,6 +45,72 @@
 /// let callback = DynObject::<dyn Callback>::new(|arg: &str| println!("{arg}"));
 /// ```
 ///
+/// # Supertraits
+///
+/// `DynObject<dyn Trait>` implements the supertraits of `Trait` which are implemented for
+/// `DynObject<Dyn>` when `Dyn` can be upcast to them. It is the case for supertraits made
+/// compatible with `DynObject` without `bounds`, `remote`, generic parameters nor associated
+/// types, as long as they are listed in `supertraits` argument. `DynObject::upcast` can then
+/// be used to convert the object without moving the stored data.
+///
+/// ```
+/// # use dyn_utils::{dyn_object, DynObject};
+/// #[dyn_object]
+/// #[dyn_object(bounds = Send)]
+/// trait Named {
+///     fn name(&self) -> &str;
+/// }
+///
+/// #[dyn_object(supertraits = Named)]
+/// #[dyn_object(bounds = Send, supertraits = Named)]
+/// trait Handler: Named {
+///     fn handle(&self, arg: &str);
+/// }
+///
+/// struct Printer;
+/// impl Named for Printer {
+///     fn name(&self) -> &str {
+///         "printer"
+///     }
+/// }
+/// impl Handler for Printer {
+///     fn handle(&self, arg: &str) {
+///         println!("{arg}");
+///     }
+/// }
+///
+/// let handler = DynObject::<dyn Handler + Send>::new(Printer);
+/// assert_eq!(handler.name(), "printer");
+/// let named: DynObject<dyn Named + Send> = handler.upcast();
+/// assert_eq!(named.name(), "printer");
+/// ```
+///
+/// # Downgrading
+///
+/// When several `dyn_object` attributes with the same `clone` and `supertraits` arguments are
+/// stacked on a trait, and the `bounds` of one are included in the `bounds` of another, their
+/// vtables share the same layout. `DynObject::downgrade` can then be used to drop auto-trait
+/// bounds, or to shorten the object lifetime, at no cost.
+///
+/// ```
+/// # use dyn_utils::{dyn_object, DynObject};
+/// #[dyn_object]
+/// #[dyn_object(bounds = Send)]
+/// trait Callback {
+///     fn call(&self, arg: &str);
+/// }
+///
+/// impl<F: Fn(&str)> Callback for F {
+///     fn call(&self, arg: &str) {
+///         self(arg)
+///     }
+/// }
+///
+/// let callback = DynObject::<dyn Callback + Send>::new(|arg: &str| println!("{arg}"));
+/// let callback: DynObject<dyn Callback> = callback.downgrade();
+/// callback.call("Hello world!");
+/// ```
+///
 /// # Limitations
 ///
 /// When combined to [`dyn_trait`], generic parameters are not supported.
@@ -55,14 +137,134 @@ pub use dyn_utils_macros::dyn_object;
 /// Generate a dyn compatible trait from a given trait declaration.
 ///
 /// Method with a return-position impl trait, such as async method, are converted to return
-/// a `DynObject`. Other non dyn-compatible items are filtered.
+/// a `DynObject`. Impl traits nested in tuples, `Option`, `Result`, `Poll`, `Box` or `Vec`, e.g.
+/// `Result<impl Future<Output = ()>, Error>` or `Vec<impl Iterator<Item = u8>>`, are converted
+/// the same way, each one with its own storage. Only these generic types are supported: impl
+/// traits nested in any other type, e.g. `Rc<impl Iterator<Item = u8>>`, are rejected with a
+/// compilation error, as well as `Pin<Box<impl Future<Output = ()>>>`, because the pinned value
+/// cannot be moved into a `DynObject`. Other non dyn-compatible items are filtered.
 ///
 /// # Arguments
 ///
+/// - `bridge`: Implements the original trait for the trait objects of the generated trait, see
+///   [below](#bridge).
 /// - `trait`: The generated dyn-compatible trait identifier, or a string template; default to
 ///   `"Dyn{}".
+/// - `factory`: Generates a `{DynTrait}Factory` trait for methods without receiver, see
+///   [below](#factory).
+/// - `layouts`: Generates a `{DynTrait}Layouts` trait exposing the layouts of the objects
+///   returned by each implementation, see [below](#storage-layouts).
 /// - `remote`: Path to the concrete trait used in the implementation; the trait declaration must
 ///   be pasted. It allows supporting traits defined in other crates.
+/// - `send`: Shortcut for `variants(local, send)`.
+/// - `variants(...)`: Generates `Send` variants of the trait, see [below](#send-variants);
+///   `local` generates the dyn-compatible trait of the original trait, `send` the one of its
+///   `Send` variant.
+///
+/// # Bridge
+///
+/// With `bridge` argument, the original trait is implemented for `dyn DynTrait`,
+/// `dyn DynTrait + Send` and `dyn DynTrait + Send + Sync`, as well as for `Box`, references and
+/// `Arc` of them, so generic code can be used with both static and dynamic dispatch.
+/// Returned futures are wrapped in an async block, which must be `Send` if required by the
+/// original trait; trait objects which are not `Sync` (or `Send` for methods borrowing the object
+/// mutably) are then not bridged. Pointers are only bridged when they allow calling every method.
+///
+/// All the methods of the original trait must be dyn-compatible, except the ones with a default
+/// implementation or a `Self: Sized` bound, and generic arguments must be passed by value.
+///
+/// ```rust
+/// #[dyn_utils::dyn_trait(bridge)]
+/// trait Callback {
+///     fn call(&self, arg: &str) -> impl Future<Output = ()> + Send;
+/// }
+///
+/// async fn exec_callback(callback: &impl Callback) {
+///     callback.call("Hello world!").await;
+/// }
+///
+/// async fn exec_dyn_callback(callback: Box<dyn DynCallback + Send + Sync>) {
+///     exec_callback(&callback).await;
+/// }
+/// ```
+///
+/// # Factory
+///
+/// With `factory` argument, methods without receiver are made dyn-compatible in a
+/// `{DynTrait}Factory` trait, implemented for [`Factory<T>`](crate::Factory) of every `T`
+/// implementing the original trait. Returned `Self`, possibly nested like impl traits, is
+/// converted into `DynObject<dyn DynTrait>`, which requires the generated trait to be usable with
+/// `DynObject`, e.g. with [`dyn_object`](attr.dyn_object.html). Each constructor adds a generic
+/// storage parameter, as for impl traits. Methods which cannot be made dyn-compatible, e.g. with
+/// `Self` argument, are filtered.
+///
+/// ```rust
+/// #[dyn_utils::dyn_trait(factory)]
+/// #[dyn_trait(dyn_utils::dyn_object)]
+/// trait Plugin {
+///     fn new(config: &str) -> Self
+///     where
+///         Self: Sized;
+///     fn name() -> &'static str
+///     where
+///         Self: Sized;
+///     fn run(&self);
+/// }
+///
+/// fn register<P: Plugin + 'static>(registry: &mut Vec<Box<dyn DynPluginFactory>>) {
+///     registry.push(Box::new(dyn_utils::Factory::<P>::new()));
+/// }
+/// ```
+///
+/// # Send variants
+///
+/// With `send` argument, a `{Trait}Send` variant of the original trait is generated, with `Send`
+/// supertrait and `Send` bounds added to async methods and return-position impl traits, like
+/// [`trait-variant`](https://docs.rs/trait-variant) does. The original trait is implemented for
+/// every implementor of the variant, and a dyn-compatible trait is generated for both traits,
+/// the one of the variant returning `DynObject<dyn Future<Output = ...> + Send>`.
+///
+/// Default method implementations of the variant must produce `Send` futures.
+///
+/// ```rust
+/// #[dyn_utils::dyn_trait(send)] // generates `CallbackSend`, `DynCallback` and `DynCallbackSend`
+/// trait Callback {
+///     async fn call(&self, arg: &str);
+/// }
+///
+/// fn spawn_callback(callback: Box<dyn DynCallbackSend>) {
+///     std::thread::spawn(move || drop(callback.call("Hello world!")));
+/// }
+/// ```
+///
+/// # Storage layouts
+///
+/// With `layouts` argument, a `{DynTrait}Layouts` trait is implemented for every
+/// implementor of the original trait, with an associated [`Layout`](core::alloc::Layout) constant
+/// for each returned impl trait, e.g. `METHOD` or `METHOD_0` when nested, and `LAYOUTS`
+/// listing them all with the name of their method. Generic methods are not included.
+///
+/// Layouts can be checked against a [`Raw`](crate::storage::Raw) storage with
+/// [`Raw::assert_layouts`](crate::storage::Raw::assert_layouts), e.g. in a test, reporting every
+/// object which doesn't fit at once, instead of the build-time assertions of `Raw::new`.
+///
+/// ```rust
+/// # use dyn_utils::storage::Raw;
+/// #[dyn_utils::dyn_trait(layouts)] // generates `DynCallback` and `DynCallbackLayouts`
+/// trait Callback {
+///     #[dyn_trait(storage = Raw<64>)]
+///     async fn call(&self, arg: &str);
+/// }
+///
+/// struct Print;
+/// impl Callback for Print {
+///     async fn call(&self, arg: &str) {
+///         println!("{arg}");
+///     }
+/// }
+///
+/// Raw::<64>::assert_layouts(&[("Print", Print::LAYOUTS)]);
+/// ```
 ///
 /// # Trait attributes
 ///
@@ -70,6 +272,36 @@ pub use dyn_utils_macros::dyn_object;
 /// generated dyn-compatible trait. It can be used to apply [`dyn_object`](attr.dyn_object.html)
 /// to the generated trait.
 ///
+/// # Generic methods
+///
+/// Generic type parameters and argument-position impl trait are erased into trait objects, as
+/// long as they are only used as the type of a single argument:
+///
+/// - arguments passed by value are converted to `DynObject<dyn Trait>`, except `Fn`/`FnMut`
+///   which are converted to `&dyn Fn`/`&mut dyn FnMut`;
+/// - arguments passed by reference are converted to `&dyn Trait`/`&mut dyn Trait` if the
+///   parameter is `?Sized`, to `&DynObject<dyn Trait>`/`&mut DynObject<dyn Trait>` otherwise.
+///
+/// The trait must then be dyn-compatible, and usable with `DynObject` when converted to it,
+/// e.g. with [`dyn_object`](attr.dyn_object.html). Methods whose generic parameters cannot be
+/// erased are filtered.
+///
+/// ```rust
+/// # use dyn_utils::DynObject;
+/// #[dyn_utils::dyn_object]
+/// trait Visitor {
+///     fn visit(&mut self, n: usize);
+/// }
+///
+/// #[dyn_utils::dyn_trait]
+/// trait Visitable {
+///     // generates `fn accept(&self, visitor: &mut dyn Visitor)`
+///     fn accept<V: Visitor + ?Sized>(&self, visitor: &mut V);
+///     // generates `fn accept_owned(&self, visitor: DynObject<dyn Visitor + '_>)`
+///     fn accept_owned(&self, visitor: impl Visitor);
+/// }
+/// ```
+///
 /// # Method attributes
 ///
 /// Methods with are return-position impl trait, such as async methods, can be decorated with
@@ -78,9 +310,16 @@ pub use dyn_utils_macros::dyn_object;
 /// - `maybe_sync`: (must be applied to method returning `Future`) Makes the generated method return
 ///   `MaybeSync<...>`, providing an optimized execution path when the concrete method is
 ///   synchronous and decorated with [`sync`](attr.sync.html).
+/// - `maybe_inline`/`maybe_inline = Type`: Makes the generated method return
+///   `MaybeInline<..., Type>`, providing an optimized execution path, bypassing the vtable, when
+///   the concrete method returns `Type` and is decorated with [`inlined`](attr.inlined.html).
+///   `Type` defaults to `core::option::IntoIter<Item>` for methods returning `Iterator`.
+///   [`MaybeInline`](crate::MaybeInline) only implements `Iterator`, `Future` and `Stream`,
+///   so other impl traits require it to implement their trait.
 /// - `storage`: Defines the default storage in the returned `DynObject`. Each method adds a
-///   generic storage parameter, whose default value is `dyn_utils::DefaultStorage` when not
-///   specified with the argument.
+///   generic storage parameter, one per impl trait when they are nested in the returned type,
+///   whose default value is `dyn_utils::DefaultStorage` when not specified with the argument.
+///   The parameter is bounded by `Send`/`Sync` when the impl trait is.
 ///
 /// # Examples
 ///
@@ -94,6 +333,30 @@ pub use dyn_utils_macros::dyn_object;
 /// }
 /// ```
 pub use dyn_utils_macros::dyn_trait;
+/// Mark a method returning impl trait as returning a concrete inline type.
+///
+/// The trait declaration must have been decorated with [`dyn_trait`],
+/// and the trait method declaration with [`maybe_inline`](dyn_trait#method-attributes);
+/// the method must return the inline type of the declaration.
+///
+/// # Examples
+///
+/// ```rust
+/// #[dyn_utils::dyn_trait]
+/// trait Values {
+///     #[dyn_trait(maybe_inline = core::slice::Iter<'_, u8>)]
+///     fn values(&self) -> impl Iterator<Item = &u8>;
+/// }
+///
+/// struct Bytes(Vec<u8>);
+/// impl Values for Bytes {
+///     #[dyn_utils::inlined]
+///     fn values(&self) -> core::slice::Iter<'_, u8> {
+///         self.0.iter()
+///     }
+/// }
+/// ```
+pub use dyn_utils_macros::inlined;
 /// Mark an async method as internally synchronous.
 ///
 /// The trait declaration must have been decorated with [`dyn_trait`],
@@ -0,0 +1,312 @@
+//! [`MaybeSync`] implementation.
+use core::{
+    fmt, hint, mem,
+    pin::{Pin, pin},
+    task::{Context, Poll, Waker, ready},
+};
+
+/// An async wrapper with an optimized synchronous execution path.
+///
+/// It is used in combination with `Future` trait objects, such as
+/// `DynObject<dyn Future<Output=T>>`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use dyn_utils::{DynObject, MaybeSync};
+///
+/// trait Callback {
+///     fn call(&self, arg: &str) -> MaybeSync<DynObject<dyn Future<Output = ()>>>;
+/// }
+///
+/// struct Print;
+/// impl Callback for Print {
+///     fn call(&self, arg: &str) -> MaybeSync<DynObject<dyn Future<Output = ()>>> {
+///         println!("{arg}");
+///         MaybeSync::Sync(())
+///     }
+/// }
+/// ```
+pub enum MaybeSync<F: Future> {
+    /// Optimized synchronous execution path.
+    Sync(F::Output),
+    /// Asynchronous wrapper
+    Async(F),
+    /// Synchronous execution path already polled
+    SyncPolled,
+}
+
+impl<F: Future> MaybeSync<F> {
+    /// Returns `true` if the result is available synchronously.
+    pub fn is_sync(&self) -> bool {
+        matches!(self, Self::Sync(_))
+    }
+
+    /// Returns the result if it is available synchronously, or `self` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dyn_utils::MaybeSync;
+    /// let maybe_sync = MaybeSync::<core::future::Ready<i32>>::Sync(42);
+    /// assert_eq!(maybe_sync.try_into_sync().ok(), Some(42));
+    /// ```
+    pub fn try_into_sync(self) -> Result<F::Output, Self> {
+        match self {
+            Self::Sync(res) => Ok(res),
+            this => Err(this),
+        }
+    }
+
+    /// Returns the result if it is available synchronously, or if the asynchronous wrapper
+    /// completes when polled once, without executor.
+    ///
+    /// It allows consuming methods decorated with `maybe_sync` in synchronous contexts.
+    /// It doesn't block: if the asynchronous wrapper is pending, it is dropped, cancelling
+    /// the operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dyn_utils::{MaybeSync, maybe_sync::PollOnceError};
+    /// let maybe_sync = MaybeSync::Async(async { 42 });
+    /// assert_eq!(maybe_sync.poll_once(), Ok(42));
+    /// let maybe_sync = MaybeSync::Async(core::future::pending::<i32>());
+    /// assert_eq!(maybe_sync.poll_once(), Err(PollOnceError::Pending));
+    /// ```
+    pub fn poll_once(self) -> Result<F::Output, PollOnceError> {
+        match self {
+            Self::Sync(res) => Ok(res),
+            Self::Async(fut) => match pin!(fut).poll(&mut Context::from_waker(Waker::noop())) {
+                Poll::Ready(res) => Ok(res),
+                Poll::Pending => Err(PollOnceError::Pending),
+            },
+            Self::SyncPolled => Err(PollOnceError::Polled),
+        }
+    }
+
+    /// Maps the result with the given function, preserving the synchronous execution path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dyn_utils::MaybeSync;
+    /// let maybe_sync = MaybeSync::<core::future::Ready<i32>>::Sync(21).map(|n| n * 2);
+    /// assert_eq!(maybe_sync.try_into_sync().ok(), Some(42));
+    /// ```
+    pub fn map<U, G: FnOnce(F::Output) -> U>(self, f: G) -> MaybeSync<Map<F, G>> {
+        match self {
+            Self::Sync(res) => MaybeSync::Sync(f(res)),
+            Self::Async(fut) => MaybeSync::Async(Map { fut, f: Some(f) }),
+            Self::SyncPolled => MaybeSync::SyncPolled,
+        }
+    }
+
+    /// Chains the result with the given function, preserving the synchronous execution path
+    /// when both are synchronous.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use dyn_utils::MaybeSync;
+    /// type Ready = core::future::Ready<i32>;
+    /// let maybe_sync = MaybeSync::<Ready>::Sync(21).and_then(|n| MaybeSync::<Ready>::Sync(n * 2));
+    /// assert_eq!(maybe_sync.try_into_sync().ok(), Some(42));
+    /// ```
+    pub fn and_then<G: Future, H: FnOnce(F::Output) -> MaybeSync<G>>(
+        self,
+        f: H,
+    ) -> MaybeSync<AndThen<F, G, H>> {
+        match self {
+            Self::Sync(res) => match f(res) {
+                MaybeSync::Sync(res) => MaybeSync::Sync(res),
+                MaybeSync::Async(fut) => MaybeSync::Async(AndThen(AndThenState::Second(fut))),
+                MaybeSync::SyncPolled => MaybeSync::SyncPolled,
+            },
+            Self::Async(fut) => MaybeSync::Async(AndThen(AndThenState::First(fut, Some(f)))),
+            Self::SyncPolled => MaybeSync::SyncPolled,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `self` must be `Self::Sync` variant.
+    #[cfg_attr(coverage_nightly, coverage(off))] // Because of `unreachable_unchecked` branch
+    #[inline(always)]
+    unsafe fn take_sync(&mut self) -> F::Output {
+        match mem::replace(self, Self::SyncPolled) {
+            Self::Sync(res) => res,
+            // SAFETY: as per function contract
+            _ => unsafe { hint::unreachable_unchecked() },
+        }
+    }
+}
+
+impl<F: Future> Future for MaybeSync<F> {
+    type Output = F::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: pinned data is not moved
+        match unsafe { self.get_unchecked_mut() } {
+            // SAFETY: res is `Self::Sync`
+            res @ MaybeSync::Sync(_) => Poll::Ready(unsafe { res.take_sync() }),
+            // SAFETY: `fut` is pinned as `self` is
+            MaybeSync::Async(fut) => unsafe { Pin::new_unchecked(fut) }.poll(cx),
+            _ => panic!("future polled after completion"),
+        }
+    }
+}
+
+/// Error returned by [`MaybeSync::poll_once`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollOnceError {
+    /// The asynchronous wrapper was pending, and has been dropped.
+    Pending,
+    /// The synchronous result has already been polled.
+    Polled,
+}
+
+impl fmt::Display for PollOnceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => write!(f, "future is pending"),
+            Self::Polled => write!(f, "future polled after completion"),
+        }
+    }
+}
+
+impl core::error::Error for PollOnceError {}
+
+/// Future returned by [`MaybeSync::map`].
+#[derive(Debug)]
+pub struct Map<F, G> {
+    fut: F,
+    f: Option<G>,
+}
+
+impl<U, F: Future, G: FnOnce(F::Output) -> U> Future for Map<F, G> {
+    type Output = U;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: pinned data is not moved, only `f` which is not pinned
+        let this = unsafe { self.get_unchecked_mut() };
+        // SAFETY: `fut` is pinned as `self` is
+        let res = ready!(unsafe { Pin::new_unchecked(&mut this.fut) }.poll(cx));
+        let f = this.f.take().expect("future polled after completion");
+        Poll::Ready(f(res))
+    }
+}
+
+/// Future returned by [`MaybeSync::and_then`].
+#[derive(Debug)]
+pub struct AndThen<F, G, H>(AndThenState<F, G, H>);
+
+#[derive(Debug)]
+enum AndThenState<F, G, H> {
+    First(F, Option<H>),
+    Second(G),
+}
+
+impl<F: Future, G: Future, H: FnOnce(F::Output) -> MaybeSync<G>> Future for AndThen<F, G, H> {
+    type Output = G::Output;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: pinned data is not moved, only dropped in place when the state changes
+        let this = unsafe { self.get_unchecked_mut() };
+        if let AndThenState::First(fut, f) = &mut this.0 {
+            // SAFETY: `fut` is pinned as `self` is
+            let res = ready!(unsafe { Pin::new_unchecked(fut) }.poll(cx));
+            let f = f.take().expect("future polled after completion");
+            match f(res) {
+                MaybeSync::Sync(res) => return Poll::Ready(res),
+                MaybeSync::Async(fut) => this.0 = AndThenState::Second(fut),
+                MaybeSync::SyncPolled => panic!("future polled after completion"),
+            }
+        }
+        match &mut this.0 {
+            // SAFETY: `fut` is pinned as `self` is
+            AndThenState::Second(fut) => unsafe { Pin::new_unchecked(fut) }.poll(cx),
+            AndThenState::First(..) => unreachable!(),
+        }
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))] // Because of `unreachable_unchecked` branch
+#[cfg(test)]
+mod tests {
+    use core::{
+        future::{Ready, pending, ready},
+        pin::pin,
+    };
+
+    use futures::FutureExt;
+
+    use crate::{MaybeSync, maybe_sync::PollOnceError};
+
+    #[test]
+    fn maybe_sync() {
+        for maybe_sync in [MaybeSync::Sync(42), MaybeSync::Async(ready(42))] {
+            assert_eq!(maybe_sync.now_or_never(), Some(42));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "future polled after completion")]
+    fn maybe_sync_polled_after_completion() {
+        let mut maybe_sync = pin!(MaybeSync::<Ready<i32>>::Sync(42));
+        assert_eq!(maybe_sync.as_mut().now_or_never(), Some(42));
+        maybe_sync.now_or_never();
+    }
+
+    #[test]
+    fn inspection() {
+        let maybe_sync = MaybeSync::<Ready<i32>>::Sync(42);
+        assert!(maybe_sync.is_sync());
+        assert_eq!(maybe_sync.try_into_sync().ok(), Some(42));
+        let maybe_sync = MaybeSync::Async(ready(42));
+        assert!(!maybe_sync.is_sync());
+        let maybe_sync = maybe_sync.try_into_sync().unwrap_err();
+        assert_eq!(maybe_sync.poll_once(), Ok(42));
+        let pending = MaybeSync::Async(pending::<i32>()).poll_once();
+        assert_eq!(pending, Err(PollOnceError::Pending));
+        let polled = MaybeSync::<Ready<i32>>::SyncPolled.poll_once();
+        assert_eq!(polled, Err(PollOnceError::Polled));
+    }
+
+    #[test]
+    fn combinators() {
+        let sync = || MaybeSync::<Ready<i32>>::Sync(21);
+        let async_ = || MaybeSync::Async(ready(21));
+        let double = |n| n * 2;
+        assert_eq!(sync().map(double).try_into_sync().ok(), Some(42));
+        assert_eq!(async_().map(double).now_or_never(), Some(42));
+        assert!(
+            MaybeSync::<Ready<i32>>::SyncPolled
+                .map(double)
+                .try_into_sync()
+                .is_err()
+        );
+        assert_eq!(
+            sync()
+                .and_then(|n| sync().map(move |m| n + m))
+                .try_into_sync()
+                .ok(),
+            Some(42)
+        );
+        assert_eq!(
+            sync()
+                .and_then(|n| async_().map(move |m| n + m))
+                .now_or_never(),
+            Some(42)
+        );
+        assert_eq!(
+            async_()
+                .and_then(|n| sync().map(move |m| n + m))
+                .now_or_never(),
+            Some(42)
+        );
+        assert_eq!(
+            async_()
+                .and_then(|n| async_().map(move |m| n + m))
+                .now_or_never(),
+            Some(42)
+        );
+    }
+}
@@ -1,12 +1,22 @@
 //! [`DynObject`] implementation.
-use core::{alloc::Layout, any::Any, fmt, marker::PhantomData, mem, pin::Pin, ptr::NonNull};
+use core::{
+    alloc::Layout,
+    any::{Any, TypeId},
+    fmt,
+    marker::PhantomData,
+    mem,
+    pin::Pin,
+    ptr::{self, NonNull},
+};
 
 use crate::{
     impls::any_impl,
-    storage::{DefaultStorage, Storage},
+    storage::{
+        Align, Alignment, DefaultStorage, SharedStorage, Slot, SlotBuffer, Storage, ThinStorage,
+    },
 };
 
-/// A trait object whose data is stored in a generic [`Storage`].
+/// A trait object whose data is stored in a generic [`SharedStorage`].
 ///
 /// [`dyn_object`] proc-macro can be used to make a trait compatible with `DynObject`.
 ///
@@ -20,22 +30,55 @@ use crate::{
 /// # });
 /// ```
 ///
+/// Closures can be stored as `dyn Fn`/`dyn FnMut`/`dyn FnOnce` (up to 6 arguments), and called
+/// with the inherent `call`/`call_mut`/`call_once` methods.
+///
+/// ```rust
+/// # use dyn_utils::{DynObject, storage::Raw};
+/// let n = 40;
+/// let callback = DynObject::<dyn Fn(usize) -> usize, Raw<8>>::new(move |m| n + m);
+/// assert_eq!(callback.call(2), 42);
+/// ```
+///
+/// Closures taking a single reference argument, e.g. `dyn Fn(&str)`, are higher-ranked over its
+/// lifetime; they are called with [`Call`]/[`CallMut`]/[`CallOnce`] traits instead. Other
+/// higher-ranked closures are not supported.
+///
+/// ```rust
+/// # use dyn_utils::{DynObject, object::Call};
+/// let callback = DynObject::<dyn Fn(&str) -> usize>::new(|s: &str| s.len());
+/// assert_eq!(callback.call("hello"), 5);
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// let callback: Box<dyn Fn(&str) -> usize> = callback.into();
+/// assert_eq!(callback("hello"), 5);
+/// # }
+/// ```
+///
 /// [`dyn_object`]: crate::dyn_object
-pub struct DynObject<Dyn: DynTrait + ?Sized, S: Storage = DefaultStorage> {
+pub struct DynObject<Dyn: DynTrait + ?Sized, S: SharedStorage = DefaultStorage> {
     storage: S,
     vtable: &'static Dyn::Vtable,
     _phantom: PhantomData<Dyn>,
 }
 
-// SAFETY: DynObject is just a wrapper around `Dyn`
-unsafe impl<Dyn: Send + DynTrait + ?Sized, S: Storage> Send for DynObject<Dyn, S> {}
+// SAFETY: DynObject is just a wrapper around `Dyn` and its storage
+unsafe impl<Dyn: Send + DynTrait + ?Sized, S: Storage + Send> Send for DynObject<Dyn, S> {}
+
+// SAFETY: DynObject is just a wrapper around `Dyn` and its storage
+unsafe impl<Dyn: Sync + DynTrait + ?Sized, S: Storage + Sync> Sync for DynObject<Dyn, S> {}
 
-// SAFETY: DynObject is just a wrapper around `Dyn`
-unsafe impl<Dyn: Sync + DynTrait + ?Sized, S: Storage> Sync for DynObject<Dyn, S> {}
+// SAFETY: DynObject is just a wrapper around `Dyn` shared like in `alloc::sync::Arc`
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+unsafe impl<Dyn: Send + Sync + DynTrait + ?Sized> Send for DynObject<Dyn, crate::storage::Arc> {}
 
-impl<Dyn: Unpin + DynTrait + ?Sized, S: Storage> Unpin for DynObject<Dyn, S> {}
+// SAFETY: DynObject is just a wrapper around `Dyn` shared like in `alloc::sync::Arc`
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+unsafe impl<Dyn: Send + Sync + DynTrait + ?Sized> Sync for DynObject<Dyn, crate::storage::Arc> {}
 
-impl<S: Storage, Dyn: DynTrait + ?Sized> DynObject<Dyn, S> {
+impl<Dyn: Unpin + DynTrait + ?Sized, S: SharedStorage> Unpin for DynObject<Dyn, S> {}
+
+impl<S: SharedStorage, Dyn: DynTrait + ?Sized> DynObject<Dyn, S> {
     /// Constructs a new `DynObject` from an object implementing the trait
     pub fn new<T>(object: T) -> Self
     where
@@ -48,6 +91,31 @@ impl<S: Storage, Dyn: DynTrait + ?Sized> DynObject<Dyn, S> {
         }
     }
 
+    /// Tries to construct a new `DynObject` from an object implementing the trait.
+    ///
+    /// Returns the object if it cannot be stored, see [`Storage::try_new`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::any::Any;
+    /// # use dyn_utils::{DynObject, storage::Raw};
+    /// let small = DynObject::<dyn Any, Raw<8>>::try_new(42u64);
+    /// assert_eq!(small.ok().and_then(|obj| obj.downcast().ok()), Some(42u64));
+    /// let large = DynObject::<dyn Any, Raw<8>>::try_new([42u64; 2]);
+    /// assert_eq!(large.err(), Some([42u64; 2]));
+    /// ```
+    pub fn try_new<T>(object: T) -> Result<Self, T>
+    where
+        Dyn: Vtable<T>,
+    {
+        Ok(Self {
+            storage: S::try_new(object)?,
+            vtable: Dyn::vtable::<S>(),
+            _phantom: PhantomData,
+        })
+    }
+
     /// Construct a new `DynObject` from a boxed object implementing the trait
     #[cfg(feature = "alloc")]
     pub fn from_box<T>(boxed: alloc::boxed::Box<T>) -> Self
@@ -62,6 +130,117 @@ impl<S: Storage, Dyn: DynTrait + ?Sized> DynObject<Dyn, S> {
         }
     }
 
+    /// Construct a new `DynObject` from an object implementing the trait, boxed with
+    /// an allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use allocator_api2::{alloc::Global, boxed::Box};
+    /// # use dyn_utils::{DynObject, storage::BoxIn};
+    /// let alloc = Global;
+    /// let future: DynObject<dyn Future<Output = usize>, BoxIn<&Global>> =
+    ///     DynObject::from_box_in(Box::new_in(async { 42 }, &alloc));
+    /// # futures::executor::block_on(async move {
+    /// assert_eq!(future.await, 42);
+    /// # });
+    /// ```
+    #[cfg(feature = "allocator-api2")]
+    pub fn from_box_in<T, A: allocator_api2::alloc::Allocator>(
+        boxed: allocator_api2::boxed::Box<T, A>,
+    ) -> Self
+    where
+        S: crate::storage::FromBoxIn<A>,
+        Dyn: Vtable<T>,
+    {
+        Self {
+            storage: S::from_box_in(boxed),
+            vtable: Dyn::vtable::<S>(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Upcasts the object to one of its supertraits' object, reusing the same storage.
+    ///
+    /// See [`dyn_object`](crate::dyn_object#supertraits) proc-macro.
+    pub fn upcast<Super: DynTrait + ?Sized>(self) -> DynObject<Super, S>
+    where
+        Dyn: Upcast<Super>,
+    {
+        let this = mem::ManuallyDrop::new(self);
+        DynObject {
+            // SAFETY: `this` is not dropped, so the storage is not duplicated
+            storage: unsafe { ptr::read(&this.storage) },
+            vtable: Dyn::upcast_vtable(this.vtable),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Converts the object to a trait object with less auto traits and/or a shorter lifetime,
+    /// e.g. `dyn Trait + Send + 'static` to `dyn Trait + 'a`, reusing the same storage and vtable.
+    ///
+    /// See [`dyn_object`](crate::dyn_object#downgrading) proc-macro.
+    pub fn downgrade<Target: DynTrait + ?Sized>(self) -> DynObject<Target, S>
+    where
+        Dyn: Downgrade<Target>,
+    {
+        let this = mem::ManuallyDrop::new(self);
+        DynObject {
+            // SAFETY: `this` is not dropped, so the storage is not duplicated
+            storage: unsafe { ptr::read(&this.storage) },
+            // SAFETY: `Target::Vtable` is a prefix of `Dyn::Vtable` as per trait contract
+            vtable: unsafe { NonNull::from(this.vtable).cast().as_ref() },
+            _phantom: PhantomData,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `vtable` must be the vtable of the type stored, for a storage with the same layout
+    /// and accessors as `S`.
+    #[cfg(feature = "alloc")]
+    pub(crate) unsafe fn from_raw_parts(storage: S, vtable: &'static Dyn::Vtable) -> Self {
+        Self {
+            storage,
+            vtable,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Moves the object into a [`Box`](alloc::boxed::Box), reusing the allocation if
+    /// the object is already stored in a [`Box`](crate::storage::Box).
+    ///
+    /// Traits made compatible with `DynObject` by [`dyn_object`](crate::dyn_object) proc-macro
+    /// also implement the conversion with [`From`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::pin::Pin;
+    /// # use dyn_utils::DynObject;
+    /// let future = DynObject::<dyn Future<Output = usize>>::new(async { 42 });
+    /// let future: Pin<Box<dyn Future<Output = usize>>> = future.into_box().into();
+    /// # futures::executor::block_on(async move {
+    /// assert_eq!(future.await, 42);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_box(self) -> alloc::boxed::Box<Dyn>
+    where
+        Dyn: DynUnsize,
+        S: Storage,
+    {
+        let unsize = Dyn::unsize_fn(self.vtable);
+        let layout = Dyn::layout(self.vtable);
+        let mut this = mem::ManuallyDrop::new(self);
+        // SAFETY: `this` is not dropped, so the storage is no longer used after the call,
+        // and `layout` is the one of the stored object
+        let ptr = unsafe { this.storage.move_into_box(layout) };
+        // SAFETY: the object has been moved into an allocation made with the global allocator
+        // and its layout, and `unsize` returns a pointer to it with the trait object metadata
+        unsafe { alloc::boxed::Box::from_raw(unsize(ptr).as_ptr()) }
+    }
+
     #[doc(hidden)]
     pub fn vtable(&self) -> &'static Dyn::Vtable {
         self.vtable
@@ -87,6 +266,7 @@ impl<S: Storage, Dyn: DynTrait + ?Sized> DynObject<Dyn, S> {
     pub fn insert<T>(this: &mut Option<Self>, object: T) -> &mut T
     where
         Dyn: Vtable<T>,
+        S: Storage,
     {
         let storage = this.insert(DynObject::new(object));
         // SAFETY: storage has been initialized with `T`
@@ -97,28 +277,92 @@ impl<S: Storage, Dyn: DynTrait + ?Sized> DynObject<Dyn, S> {
     pub fn insert_pinned<T>(this: Pin<&mut Option<Self>>, object: T) -> Pin<&mut T>
     where
         Dyn: Vtable<T>,
+        S: Storage,
     {
         // SAFETY: the returned reference cannot is structurally pinned
         unsafe { this.map_unchecked_mut(|opt| Self::insert(opt, object)) }
     }
 }
 
-impl<Dyn: DynTrait + ?Sized, S: Storage> Drop for DynObject<Dyn, S> {
-    fn drop(&mut self) {
-        if let Some(drop_inner) = Dyn::drop_in_place_fn(self.vtable) {
-            // SAFETY: the storage data is no longer accessed after the call,
-            // and is matched by the vtable as per function contract.
-            unsafe { drop_inner(self.storage_mut().ptr_mut()) };
+impl<'a, Dyn: DynTrait + ?Sized, const SIZE: usize, const ALIGN: usize>
+    DynObject<Dyn, Slot<'a, SIZE, ALIGN>>
+where
+    Align<ALIGN>: Alignment,
+{
+    /// Constructs a new `DynObject` from an object implementing the trait, storing it in
+    /// a caller-provided buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::pin::pin;
+    /// # use dyn_utils::{DynObject, storage::{Slot, SlotBuffer}};
+    /// let mut buffer = pin!(SlotBuffer::<64>::new());
+    /// let future: DynObject<dyn Future<Output = usize>, Slot<64>> =
+    ///     DynObject::new_in(async { 42 }, buffer.as_mut());
+    /// # futures::executor::block_on(async move {
+    /// assert_eq!(future.await, 42);
+    /// # });
+    /// ```
+    pub fn new_in<'b, T: 'b>(object: T, buffer: Pin<&'a mut SlotBuffer<'b, SIZE, ALIGN>>) -> Self
+    where
+        Dyn: Vtable<T>,
+    {
+        Self {
+            storage: Slot::new(buffer, object),
+            vtable: Dyn::vtable::<Slot<'a, SIZE, ALIGN>>(),
+            _phantom: PhantomData,
         }
+    }
+}
+
+impl<Dyn: DynTrait + ?Sized, S: SharedStorage> Drop for DynObject<Dyn, S> {
+    fn drop(&mut self) {
+        let drop_inner = Dyn::drop_in_place_fn(self.vtable);
         let layout = Dyn::layout(self.vtable);
-        // SAFETY: the storage data is no longer accessed after the call,
+        // SAFETY: the storage is no longer accessed after the call,
         // and is matched by the vtable as per function contract.
-        unsafe { self.storage_mut().drop_in_place(layout) };
+        unsafe { self.storage_mut().drop_object(drop_inner, layout) };
     }
 }
 
+impl<Dyn: DynClone + ?Sized, S: Storage> Clone for DynObject<Dyn, S> {
+    fn clone(&self) -> Self {
+        let clone = Dyn::clone_fn::<S>(self.vtable);
+        Self {
+            // SAFETY: the vtable has been returned by `Vtable::vtable::<S>`
+            storage: unsafe { clone(&self.storage) },
+            vtable: self.vtable,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+macro_rules! shared_clone {
+    ($(#[$attr:meta])* $storage:ty) => {
+        $(#[$attr])*
+        impl<Dyn: DynTrait + ?Sized> Clone for DynObject<Dyn, $storage> {
+            fn clone(&self) -> Self {
+                Self {
+                    storage: self.storage.clone(),
+                    vtable: self.vtable,
+                    _phantom: PhantomData,
+                }
+            }
+        }
+    };
+}
+shared_clone!(
+    #[cfg(feature = "alloc")]
+    crate::storage::Rc
+);
+shared_clone!(
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    crate::storage::Arc
+);
+
 #[cfg_attr(coverage_nightly, coverage(off))]
-impl<Dyn: DynTrait<Vtable: fmt::Debug> + ?Sized, S: Storage + fmt::Debug> fmt::Debug
+impl<Dyn: DynTrait<Vtable: fmt::Debug> + ?Sized, S: SharedStorage + fmt::Debug> fmt::Debug
     for DynObject<Dyn, S>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -128,11 +372,241 @@ impl<Dyn: DynTrait<Vtable: fmt::Debug> + ?Sized, S: Storage + fmt::Debug> fmt::D
             .finish()
     }
 }
-// Putting this in impls module make these methods appears before others,
+
+/// A trait object whose data is stored in a generic [`ThinStorage`], with its vtable.
+///
+/// Contrary to [`DynObject`], the vtable is stored in a header placed before the data, so
+/// the object has the size of its storage, e.g. a single pointer with
+/// [`ThinBox`](crate::storage::ThinBox), and can be converted to and from a raw pointer.
+///
+/// Traits made compatible with `DynObject` by [`dyn_object`] proc-macro are also implemented
+/// by `ThinDynObject`.
+///
+/// [`dyn_object`]: crate::dyn_object
+pub struct ThinDynObject<Dyn: DynTrait + ?Sized, S: ThinStorage> {
+    storage: S,
+    _phantom: PhantomData<Dyn>,
+}
+
+// SAFETY: ThinDynObject is just a wrapper around `Dyn` and its storage
+unsafe impl<Dyn: Send + DynTrait + ?Sized, S: ThinStorage + Send> Send for ThinDynObject<Dyn, S> {}
+
+// SAFETY: ThinDynObject is just a wrapper around `Dyn` and its storage
+unsafe impl<Dyn: Sync + DynTrait + ?Sized, S: ThinStorage + Sync> Sync for ThinDynObject<Dyn, S> {}
+
+impl<Dyn: Unpin + DynTrait + ?Sized, S: ThinStorage> Unpin for ThinDynObject<Dyn, S> {}
+
+impl<Dyn: DynTrait + ?Sized, S: ThinStorage> ThinDynObject<Dyn, S> {
+    /// Constructs a new `ThinDynObject` from an object implementing the trait
+    pub fn new<T>(object: T) -> Self
+    where
+        Dyn: Vtable<T>,
+    {
+        // SAFETY: the vtable matches the storage
+        unsafe { Self::from_storage(S::new(object), Dyn::vtable::<S>()) }
+    }
+
+    /// Tries to construct a new `ThinDynObject` from an object implementing the trait.
+    ///
+    /// Returns the object if it cannot be stored, see [`Storage::try_new`].
+    pub fn try_new<T>(object: T) -> Result<Self, T>
+    where
+        Dyn: Vtable<T>,
+    {
+        // SAFETY: the vtable matches the storage
+        Ok(unsafe { Self::from_storage(S::try_new(object)?, Dyn::vtable::<S>()) })
+    }
+
+    /// # Safety
+    ///
+    /// `vtable` must have been returned by `Vtable::vtable::<S>` for the type stored.
+    unsafe fn from_storage(storage: S, vtable: &'static Dyn::Vtable) -> Self {
+        let this = Self {
+            storage,
+            _phantom: PhantomData,
+        };
+        // SAFETY: the header is valid as per `ThinStorage` contract
+        unsafe { this.header().write(vtable) };
+        this
+    }
+
+    /// Consumes the object, returning a pointer to its data, with its vtable placed before.
+    ///
+    /// The object can be reconstructed with [`ThinDynObject::from_raw`].
+    pub fn into_raw(self) -> NonNull<()> {
+        let this = mem::ManuallyDrop::new(self);
+        // SAFETY: `this` is not dropped, so the storage is not duplicated
+        unsafe { ptr::read(&this.storage) }.into_raw()
+    }
+
+    /// Constructs an object from a pointer returned by [`ThinDynObject::into_raw`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by [`ThinDynObject::into_raw`] with the same storage,
+    /// and a trait object which is the same as `Dyn`, or which can be downgraded to it.
+    pub unsafe fn from_raw(ptr: NonNull<()>) -> Self {
+        Self {
+            // SAFETY: same precondition
+            storage: unsafe { S::from_raw(ptr) },
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Upcasts the object to one of its supertraits' object, reusing the same storage.
+    ///
+    /// See [`dyn_object`](crate::dyn_object#supertraits) proc-macro.
+    pub fn upcast<Super: DynTrait + ?Sized>(self) -> ThinDynObject<Super, S>
+    where
+        Dyn: Upcast<Super>,
+    {
+        let vtable = Dyn::upcast_vtable(self.vtable());
+        let this = mem::ManuallyDrop::new(self);
+        // SAFETY: `this` is not dropped, so the storage is not duplicated,
+        // and the upcast vtable matches the same storage
+        unsafe { ThinDynObject::from_storage(ptr::read(&this.storage), vtable) }
+    }
+
+    /// Converts the object to a trait object with less auto traits and/or a shorter lifetime,
+    /// e.g. `dyn Trait + Send + 'static` to `dyn Trait + 'a`, reusing the same storage and vtable.
+    ///
+    /// See [`dyn_object`](crate::dyn_object#downgrading) proc-macro.
+    pub fn downgrade<Target: DynTrait + ?Sized>(self) -> ThinDynObject<Target, S>
+    where
+        Dyn: Downgrade<Target>,
+    {
+        let this = mem::ManuallyDrop::new(self);
+        ThinDynObject {
+            // SAFETY: `this` is not dropped, so the storage is not duplicated;
+            // `Target::Vtable` is a prefix of `Dyn::Vtable` as per trait contract,
+            // so the vtable in the header can be reused
+            storage: unsafe { ptr::read(&this.storage) },
+            _phantom: PhantomData,
+        }
+    }
+
+    fn header(&self) -> NonNull<&'static Dyn::Vtable> {
+        // SAFETY: the header is placed just before the data as per `ThinStorage` contract
+        unsafe { self.storage.ptr().cast().sub(1) }
+    }
+
+    #[doc(hidden)]
+    pub fn vtable(&self) -> &'static Dyn::Vtable {
+        // SAFETY: the header has been initialized at construction
+        unsafe { self.header().read() }
+    }
+
+    #[doc(hidden)]
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    #[doc(hidden)]
+    pub fn storage_mut(&mut self) -> &mut S {
+        &mut self.storage
+    }
+
+    #[doc(hidden)]
+    pub fn storage_pinned_mut(self: Pin<&mut Self>) -> Pin<&mut S> {
+        // SAFETY: `self.storage` is structurally pinned
+        unsafe { self.map_unchecked_mut(|this| &mut this.storage) }
+    }
+}
+
+impl<Dyn: DynTrait + ?Sized, S: ThinStorage> Drop for ThinDynObject<Dyn, S> {
+    fn drop(&mut self) {
+        let vtable = self.vtable();
+        let drop_inner = Dyn::drop_in_place_fn(vtable);
+        let layout = Dyn::layout(vtable);
+        // SAFETY: the storage is no longer accessed after the call,
+        // and is matched by the vtable as per function contract.
+        unsafe { self.storage_mut().drop_object(drop_inner, layout) };
+    }
+}
+
+impl<Dyn: DynClone + ?Sized, S: ThinStorage> Clone for ThinDynObject<Dyn, S> {
+    fn clone(&self) -> Self {
+        let clone = Dyn::clone_fn::<S>(self.vtable());
+        // SAFETY: the vtable has been returned by `Vtable::vtable::<S>`,
+        // and it matches the cloned storage
+        unsafe { Self::from_storage(clone(&self.storage), self.vtable()) }
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl<Dyn: DynTrait<Vtable: fmt::Debug> + ?Sized, S: ThinStorage + fmt::Debug> fmt::Debug
+    for ThinDynObject<Dyn, S>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThinDynObject")
+            .field("inner", &self.storage)
+            .field("vtable", &self.vtable())
+            .finish()
+    }
+}
+
+macro_rules! downcast_impl {
+    ($object:ident: $storage:ident) => {
+        impl<Dyn: DynDowncast + ?Sized, S: $storage> $object<Dyn, S> {
+            /// Returns the [`TypeId`] of the underlying concrete type.
+            pub fn type_id(&self) -> TypeId {
+                Dyn::type_id(self.vtable())
+            }
+
+            /// Returns `true` if the inner type is the same as `T`.
+            pub fn is<T: Any>(&self) -> bool {
+                self.type_id() == TypeId::of::<T>()
+            }
+
+            /// Returns some reference to the inner value if it is of type `T`,
+            /// or `None` if it isn’t.
+            pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+                // SAFETY: `is` ensures that the storage has been initialized with `T`
+                self.is::<T>().then(|| unsafe { self.storage().as_ref() })
+            }
+
+            /// Returns some mutable reference to the inner value if it is of type `T`,
+            /// or `None` if it isn’t.
+            pub fn downcast_mut<T: Any>(&mut self) -> Option<&mut T>
+            where
+                S: Storage,
+            {
+                self.is::<T>()
+                    // SAFETY: `is` ensures that the storage has been initialized with `T`
+                    .then(|| unsafe { Storage::as_mut(self.storage_mut()) })
+            }
+
+            /// Attempts to downcast the object to a concrete type.
+            // TODO understand why it prevents 100% coverage
+            #[cfg_attr(coverage_nightly, coverage(off))]
+            pub fn downcast<T: Any>(self) -> Result<T, Self>
+            where
+                S: Storage,
+            {
+                if self.is::<T>() {
+                    let mut this = mem::ManuallyDrop::new(self);
+                    let storage = this.storage_mut();
+                    // SAFETY: `is` ensures that the storage has been initialized with `T`
+                    let obj = unsafe { Storage::ptr_mut(storage).cast().read() };
+                    // SAFETY: the storage is no longer used after,
+                    // and `is` ensures that the storage has been initialized with `T`
+                    unsafe { Storage::drop_in_place(storage, Layout::new::<T>()) };
+                    Ok(obj)
+                } else {
+                    Err(self)
+                }
+            }
+        }
+    };
+}
+// Putting this before would make these methods appear before others,
 // so it has to be explicitly put after other methods
+downcast_impl!(DynObject: SharedStorage);
+downcast_impl!(ThinDynObject: ThinStorage);
+
 any_impl!(dyn Any);
-any_impl!(dyn Any + Send);
-any_impl!(dyn Any + Send + Sync);
+any_impl!(dyn Any + Send => dyn Any);
+any_impl!(dyn Any + Send + Sync => dyn Any, dyn Any + Send);
 
 /// A trait object with its associated vtable.
 pub trait DynTrait {
@@ -144,6 +618,67 @@ pub trait DynTrait {
     fn layout(vtable: &Self::Vtable) -> Layout;
 }
 
+/// A trait object whose vtable can clone the stored object.
+///
+/// # Safety
+///
+/// `DynClone::clone_fn::<S>` must return a function storing a clone of the object in a new
+/// storage, when the vtable has been returned by `Vtable::vtable::<S>`.
+pub unsafe trait DynClone: DynTrait {
+    /// Returns the clone function of the trait object as stored in vtable.
+    fn clone_fn<S: SharedStorage>(vtable: &Self::Vtable) -> unsafe fn(&S) -> S;
+}
+
+/// A trait object whose vtable can convert a pointer to the stored object into
+/// a pointer to the trait object, e.g. to move it into a `Box<dyn Trait>`.
+///
+/// # Safety
+///
+/// `DynUnsize::unsize_fn` must return a function converting a pointer to the concrete type of
+/// the vtable into a trait object pointer with the same address.
+pub unsafe trait DynUnsize: DynTrait {
+    /// Returns the unsize function of the trait object as stored in vtable.
+    fn unsize_fn(vtable: &Self::Vtable) -> fn(NonNull<()>) -> NonNull<Self>;
+}
+
+/// A trait object whose vtable stores the [`TypeId`] of the stored object, allowing it to be
+/// downcast to its concrete type.
+///
+/// # Safety
+///
+/// `DynDowncast::type_id` must return the `TypeId` of the concrete type of the vtable.
+pub unsafe trait DynDowncast: DynTrait {
+    /// Returns the `TypeId` of the trait object concrete type as stored in vtable.
+    fn type_id(vtable: &Self::Vtable) -> TypeId;
+}
+
+/// A trait object which can be upcast to `Super` trait object.
+///
+/// # Safety
+///
+/// `Upcast::upcast_vtable` must return the vtable of `Super` for the same concrete type and
+/// storage as the given vtable.
+pub unsafe trait Upcast<Super: DynTrait + ?Sized>: DynTrait {
+    /// Returns the vtable of `Super` trait object.
+    fn upcast_vtable(vtable: &'static Self::Vtable) -> &'static Super::Vtable;
+}
+
+// SAFETY: the vtable is returned as is
+unsafe impl<Dyn: DynTrait + ?Sized> Upcast<Dyn> for Dyn {
+    fn upcast_vtable(vtable: &'static Self::Vtable) -> &'static Dyn::Vtable {
+        vtable
+    }
+}
+
+/// A trait object which can be converted to `Target` trait object with less auto traits
+/// and/or a shorter lifetime.
+///
+/// # Safety
+///
+/// `Self` must implement all the traits of `Target`, and `Target::Vtable` must be
+/// a `#[repr(C)]` prefix of `Self::Vtable` with the same fields semantic.
+pub unsafe trait Downgrade<Target: DynTrait + ?Sized>: DynTrait {}
+
 /// A vtable constructor.
 ///
 /// # Safety
@@ -153,7 +688,7 @@ pub trait DynTrait {
 ///   `<Self as crate::object::Vtable<T>>::DROP_IN_PLACE_FN`
 pub unsafe trait Vtable<T>: DynTrait {
     /// Returns the vtable for a given `T` stored in `S`.
-    fn vtable<S: Storage>() -> &'static Self::Vtable;
+    fn vtable<S: SharedStorage>() -> &'static Self::Vtable;
     /// The function has the same safety contract that [`core::ptr::drop_in_place`].
     const DROP_IN_PLACE_FN: Option<unsafe fn(NonNull<()>)> = if mem::needs_drop::<T>() {
         // SAFETY: as per function contract
@@ -163,6 +698,37 @@ pub unsafe trait Vtable<T>: DynTrait {
     };
 }
 
+/// An object storing a higher-ranked `dyn FnOnce(&T)`/`dyn FnOnce(&mut T)` closure.
+///
+/// Other closures are called with inherent methods, but these ones would conflict with
+/// the inherent methods of `dyn FnOnce(T)`.
+pub trait CallOnce<Arg> {
+    /// The returned type of the closure.
+    type Output;
+    /// Calls the underlying closure, consuming the object.
+    fn call_once(self, arg: Arg) -> Self::Output;
+}
+
+/// An object storing a higher-ranked `dyn FnMut(&T)`/`dyn FnMut(&mut T)` closure.
+///
+/// See [`CallOnce`].
+pub trait CallMut<Arg> {
+    /// The returned type of the closure.
+    type Output;
+    /// Calls the underlying closure.
+    fn call_mut(&mut self, arg: Arg) -> Self::Output;
+}
+
+/// An object storing a higher-ranked `dyn Fn(&T)`/`dyn Fn(&mut T)` closure.
+///
+/// See [`CallOnce`].
+pub trait Call<Arg> {
+    /// The returned type of the closure.
+    type Output;
+    /// Calls the underlying closure.
+    fn call(&self, arg: Arg) -> Self::Output;
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{impls::any_test, object::Any};
@@ -170,4 +736,14 @@ mod tests {
     any_test!(dyn_any, dyn Any);
     any_test!(dyn_any_send, dyn Any + Send);
     any_test!(dyn_any_send_sync, dyn Any + Send + Sync);
+
+    #[test]
+    fn any_downgrade() {
+        let any = crate::DynObject::<dyn Any + Send + Sync>::new(42usize);
+        let any = any.downgrade::<dyn Any + Send>();
+        assert_eq!(
+            any.downgrade::<dyn Any>().downcast::<usize>().ok(),
+            Some(42)
+        );
+    }
 }
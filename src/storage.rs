@@ -2,16 +2,21 @@
 
 #[cfg(any(feature = "alloc", doc))]
 use alloc::boxed::Box as StdBox;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use core::sync::atomic::{self, AtomicUsize, Ordering};
 use core::{
     alloc::Layout,
     cell::UnsafeCell,
+    fmt,
     hint::unreachable_unchecked,
     marker::{PhantomData, PhantomPinned},
-    mem::MaybeUninit,
+    mem::{self, MaybeUninit},
     pin::Pin,
     ptr::NonNull,
 };
 
+#[cfg(feature = "allocator-api2")]
+use allocator_api2::alloc::{Allocator, Global};
 pub use elain::{Align, Alignment};
 
 /// Default storage for [`DynObject`](crate::DynObject), and used in [`dyn_trait`](crate::dyn_trait) macro.
@@ -19,13 +24,38 @@ pub type DefaultStorage = RawOrBox<{ 128 * size_of::<usize>() }>;
 
 /// A storage that can be used to store dynamic type-erased objects.
 ///
+/// Stored data is owned exclusively by the storage; see [`SharedStorage`] for storages
+/// sharing their data, like [`Rc`].
+///
+/// Storages of this crate are `Send` and `Sync` whatever data they store, as its thread-safety
+/// is checked by [`DynObject`](crate::DynObject), which requires both the trait object and
+/// the storage to be `Send`/`Sync`. A storage holding a raw pointer to its data must then
+/// implement `Send`/`Sync` with `unsafe impl` for its objects to be `Send`/`Sync`.
+///
 /// # Safety
 ///
 /// `ptr`/`ptr_mut`/`as_ref`/`as_mut`/`as_pinned_mut` must return a pointer/reference
-/// to stored data.
+/// to stored data. Stored data must not be shared with another storage.
 pub unsafe trait Storage: Sized {
     /// Constructs a new storage storing `T`.
     fn new<T>(data: T) -> Self;
+    /// Tries to construct a new storage storing `T`.
+    ///
+    /// Returns the data if it cannot be stored, e.g. if it doesn't fit in place or if
+    /// the allocation fails.
+    fn try_new<T>(data: T) -> Result<Self, T> {
+        Ok(Self::new(data))
+    }
+    /// Objects which can be stored with [`Storage::new`], i.e. without failing at build time.
+    const CAPACITY: Capacity = Capacity::ANY;
+    /// Constructs a new storage storing `T`, without build time assertion.
+    ///
+    /// # Safety
+    ///
+    /// [`Storage::CAPACITY`] must be able to store `T`.
+    unsafe fn new_unchecked<T>(data: T) -> Self {
+        Self::new(data)
+    }
     /// Returns a const pointer to stored data.
     fn ptr(&self) -> NonNull<()>;
     /// Returns a mutable pointer to stored data.
@@ -34,7 +64,8 @@ pub unsafe trait Storage: Sized {
     ///
     /// # Safety
     ///
-    /// Storage must have been constructed with `T`
+    /// Storage must have been constructed with `T`. `T` must be `Send` if the storage has been
+    /// sent to another thread, and `Sync` if it is shared between threads.
     unsafe fn as_ref<T>(&self) -> &T {
         // SAFETY: `Self::ptr` returns a const pointer to stored data
         unsafe { self.ptr().cast().as_ref() }
@@ -43,7 +74,8 @@ pub unsafe trait Storage: Sized {
     ///
     /// # Safety
     ///
-    /// Storage must have been constructed with `T`
+    /// Storage must have been constructed with `T`. `T` must be `Send` if the storage has been
+    /// sent to another thread.
     unsafe fn as_mut<T>(&mut self) -> &mut T {
         // SAFETY: `Self::ptr` returns a mutable pointer to stored data
         unsafe { self.ptr_mut().cast().as_mut() }
@@ -52,7 +84,8 @@ pub unsafe trait Storage: Sized {
     ///
     /// # Safety
     ///
-    /// Storage must have been constructed with from `T`
+    /// Storage must have been constructed with `T`. `T` must be `Send` if the storage has been
+    /// sent to another thread.
     unsafe fn as_pinned_mut<T>(self: Pin<&mut Self>) -> Pin<&mut T> {
         // SAFETY: data is not moved, and `Self::as_mut` as the same precondition
         unsafe { self.map_unchecked_mut(|this| this.as_mut()) }
@@ -66,6 +99,142 @@ pub unsafe trait Storage: Sized {
     /// `drop_in_place` must be called once, and the storage must not be used
     /// after. `layout` must be the layout of the data stored.
     unsafe fn drop_in_place(&mut self, layout: Layout);
+    /// Moves stored data into an allocation made with the global allocator and the layout
+    /// of the data, then drops the storage in place, returning a pointer to the data.
+    ///
+    /// # Safety
+    ///
+    /// `move_into_box` must be called once, and the storage must not be used after.
+    /// `layout` must be the layout of the data stored.
+    #[cfg(feature = "alloc")]
+    unsafe fn move_into_box(&mut self, layout: Layout) -> NonNull<()> {
+        let ptr = if layout.size() == 0 {
+            NonNull::new(core::ptr::without_provenance_mut(layout.align())).unwrap()
+        } else {
+            // SAFETY: the layout has a non-zero size
+            let ptr = unsafe { alloc::alloc::alloc(layout) };
+            NonNull::new(ptr).unwrap_or_else(|| alloc::alloc::handle_alloc_error(layout))
+        };
+        // SAFETY: both pointers are valid for `layout`, and the stored data is moved out,
+        // as the storage is dropped in place without dropping its data
+        unsafe { ptr.copy_from_nonoverlapping(self.ptr_mut().cast(), layout.size()) };
+        // SAFETY: same function contract
+        unsafe { self.drop_in_place(layout) };
+        ptr.cast()
+    }
+}
+
+/// A storage whose data may be shared with other storages, like [`Rc`], only giving shared
+/// access to it.
+///
+/// It is implemented by every [`Storage`]. `DynObject` with a shared storage only implement
+/// methods taking `&self`, as mutable accessors are only provided by [`Storage`].
+///
+/// # Safety
+///
+/// `ptr`/`as_ref` must return a pointer/reference to stored data.
+pub unsafe trait SharedStorage: Sized {
+    /// Constructs a new storage storing `T`.
+    fn new<T>(data: T) -> Self;
+    /// Tries to construct a new storage storing `T`, see [`Storage::try_new`].
+    fn try_new<T>(data: T) -> Result<Self, T> {
+        Ok(Self::new(data))
+    }
+    /// Objects which can be stored with [`SharedStorage::new`], see [`Storage::CAPACITY`].
+    const CAPACITY: Capacity = Capacity::ANY;
+    /// Constructs a new storage storing `T`, without build time assertion.
+    ///
+    /// # Safety
+    ///
+    /// [`SharedStorage::CAPACITY`] must be able to store `T`.
+    unsafe fn new_unchecked<T>(data: T) -> Self {
+        Self::new(data)
+    }
+    /// Returns a const pointer to stored data.
+    fn ptr(&self) -> NonNull<()>;
+    /// Returns a mutable pointer to stored data.
+    ///
+    /// Data may be shared, so it must only be mutated through the pointer if the storage is
+    /// a [`Storage`].
+    fn ptr_mut(&mut self) -> NonNull<()>;
+    /// Returns a reference to stored data.
+    ///
+    /// # Safety
+    ///
+    /// Storage must have been constructed with `T`. `T` must be `Send` if the storage has been
+    /// sent to another thread, and `Sync` if it is shared between threads.
+    unsafe fn as_ref<T>(&self) -> &T {
+        // SAFETY: `Self::ptr` returns a const pointer to stored data
+        unsafe { self.ptr().cast().as_ref() }
+    }
+    /// Drops the stored data with `drop_in_place` function, if any, then drops the storage
+    /// in place.
+    ///
+    /// Shared storages only drop their data when they are the last owner.
+    ///
+    /// # Safety
+    ///
+    /// `drop_object` must be called once, and the storage must not be used
+    /// after. `drop_in_place` and `layout` must be the drop function and the layout
+    /// of the data stored.
+    unsafe fn drop_object(&mut self, drop_in_place: Option<unsafe fn(NonNull<()>)>, layout: Layout);
+}
+
+// SAFETY: `Storage` has the same requirements, and its data is not shared
+unsafe impl<S: Storage> SharedStorage for S {
+    fn new<T>(data: T) -> Self {
+        <S as Storage>::new(data)
+    }
+    fn try_new<T>(data: T) -> Result<Self, T> {
+        <S as Storage>::try_new(data)
+    }
+    const CAPACITY: Capacity = <S as Storage>::CAPACITY;
+    unsafe fn new_unchecked<T>(data: T) -> Self {
+        // SAFETY: same precondition
+        unsafe { <S as Storage>::new_unchecked(data) }
+    }
+    fn ptr(&self) -> NonNull<()> {
+        <S as Storage>::ptr(self)
+    }
+    fn ptr_mut(&mut self) -> NonNull<()> {
+        <S as Storage>::ptr_mut(self)
+    }
+    unsafe fn as_ref<T>(&self) -> &T {
+        // SAFETY: same precondition
+        unsafe { <S as Storage>::as_ref(self) }
+    }
+    unsafe fn drop_object(
+        &mut self,
+        drop_in_place: Option<unsafe fn(NonNull<()>)>,
+        layout: Layout,
+    ) {
+        if let Some(drop_in_place) = drop_in_place {
+            // SAFETY: the storage data is no longer accessed after the call,
+            // and is matched by the function as per function contract.
+            unsafe { drop_in_place(<S as Storage>::ptr_mut(self)) };
+        }
+        // SAFETY: same function contract
+        unsafe { <S as Storage>::drop_in_place(self, layout) };
+    }
+}
+
+/// A storage which can back a [`ThinDynObject`](crate::ThinDynObject), converted to and from
+/// a pointer to its stored data.
+///
+/// # Safety
+///
+/// Stored data must be preceded by a header with the size and alignment of a pointer,
+/// which is not accessed by the storage after its construction. `into_raw` must return the same pointer as `ptr`,
+/// and `from_raw` must be its inverse.
+pub unsafe trait ThinStorage: Storage {
+    /// Consumes the storage, returning a pointer to stored data.
+    fn into_raw(self) -> NonNull<()>;
+    /// Constructs a storage from a pointer to stored data.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by [`ThinStorage::into_raw`].
+    unsafe fn from_raw(ptr: NonNull<()>) -> Self;
 }
 
 /// A storage that can be constructed from boxed data.
@@ -77,11 +246,65 @@ pub trait FromBox: Storage {
     fn from_box<T>(boxed: StdBox<T>) -> Self;
 }
 
+/// The objects a storage can store, given by their maximum size for each alignment.
+///
+/// Contrary to a function, it can be evaluated at build time in generic code, e.g. by
+/// [`Fallback`] to select its storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capacity([Option<usize>; usize::BITS as usize]);
+
+impl Capacity {
+    /// Capacity of a storage which can store any object.
+    pub const ANY: Self = Self([Some(usize::MAX); usize::BITS as usize]);
+    /// Capacity of a storage which cannot store any object.
+    pub const NONE: Self = Self([None; usize::BITS as usize]);
+
+    /// Capacity of a storage which can store objects up to the given size and alignment.
+    pub const fn new(size: usize, align: usize) -> Self {
+        let mut capacity = Self::NONE;
+        let mut i = 0;
+        while i < capacity.0.len() && 1 << i <= align {
+            capacity.0[i] = Some(size);
+            i += 1;
+        }
+        capacity
+    }
+
+    /// Returns the union of both capacities.
+    pub const fn or(self, other: Self) -> Self {
+        let mut capacity = self;
+        let mut i = 0;
+        while i < capacity.0.len() {
+            capacity.0[i] = match (self.0[i], other.0[i]) {
+                (Some(a), Some(b)) if a >= b => Some(a),
+                (Some(a), None) => Some(a),
+                (_, b) => b,
+            };
+            i += 1;
+        }
+        capacity
+    }
+
+    /// Returns `true` if an object with the given layout can be stored.
+    pub const fn can_store_layout(&self, layout: Layout) -> bool {
+        match self.0[layout.align().trailing_zeros() as usize] {
+            Some(size) => layout.size() <= size,
+            None => false,
+        }
+    }
+
+    /// Returns `true` if `T` can be stored.
+    pub const fn can_store<T>(&self) -> bool {
+        self.can_store_layout(Layout::new::<T>())
+    }
+}
+
 /// A raw storage, where data is stored in place.
 ///
 /// Data size and alignment must fit, e.g. be lesser or equal to the generic parameters.
 /// This condition is enforced by a constant assertion, which triggers at build time
-/// — **it is not triggered by `cargo check`**.
+/// — **it is not triggered by `cargo check`**. [`Storage::try_new`] checks it at runtime
+/// instead.
 #[derive(Debug)]
 #[repr(C)]
 pub struct Raw<const SIZE: usize, const ALIGN: usize = { align_of::<usize>() }>
@@ -90,17 +313,64 @@ where
 {
     data: UnsafeCell<MaybeUninit<[u8; SIZE]>>,
     _align: Align<ALIGN>,
-    _not_send_sync: PhantomData<*mut ()>,
     _pinned: PhantomPinned,
 }
 
+// SAFETY: `Raw` only holds the data, whose thread-safety is checked by `DynObject`
+unsafe impl<const SIZE: usize, const ALIGN: usize> Send for Raw<SIZE, ALIGN> where
+    Align<ALIGN>: Alignment
+{
+}
+
+// SAFETY: `Raw` only holds the data, whose thread-safety is checked by `DynObject`
+unsafe impl<const SIZE: usize, const ALIGN: usize> Sync for Raw<SIZE, ALIGN> where
+    Align<ALIGN>: Alignment
+{
+}
+
 impl<const SIZE: usize, const ALIGN: usize> Raw<SIZE, ALIGN>
 where
     Align<ALIGN>: Alignment,
 {
     /// Returns `true` if `T` can be stored in the storage.
     pub const fn can_store<T>() -> bool {
-        size_of::<T>() <= SIZE && align_of::<T>() <= ALIGN
+        Self::can_store_layout(Layout::new::<T>())
+    }
+
+    /// Returns `true` if an object with the given layout can be stored in the storage.
+    pub const fn can_store_layout(layout: Layout) -> bool {
+        layout.size() <= SIZE && layout.align() <= ALIGN
+    }
+
+    /// Checks that the objects returned by a set of implementors fit into the storage.
+    ///
+    /// Implementors are given with their name and the layouts of their returned objects,
+    /// e.g. the `LAYOUTS` constant generated by [`dyn_trait`](crate::dyn_trait) with
+    /// `layouts` argument. The returned report lists all the objects which don't fit.
+    pub fn check_layouts<'a>(
+        implementors: &'a [(&'a str, &'a [(&'a str, Layout)])],
+    ) -> Result<(), LayoutReport<'a>> {
+        let report = LayoutReport {
+            implementors,
+            storage: (SIZE, ALIGN),
+        };
+        match report.misfits().next() {
+            Some(_) => Err(report),
+            None => Ok(()),
+        }
+    }
+
+    /// Asserts that the objects returned by a set of implementors fit into the storage,
+    /// see [`Raw::check_layouts`].
+    ///
+    /// # Panics
+    ///
+    /// Panics with the report of all the objects which don't fit.
+    #[track_caller]
+    pub fn assert_layouts(implementors: &[(&str, &[(&str, Layout)])]) {
+        if let Err(report) = Self::check_layouts(implementors) {
+            panic!("{report}");
+        }
     }
 
     /// Constructs a new `Raw` storage, with compile-time assertion that `T` can be stored.
@@ -130,7 +400,6 @@ where
         let mut raw = Self {
             data: UnsafeCell::new(MaybeUninit::uninit()),
             _align: Align::NEW,
-            _not_send_sync: PhantomData,
             _pinned: PhantomPinned,
         };
         // SAFETY: function contract guarantees that `raw.data` size and alignment
@@ -148,6 +417,18 @@ where
     fn new<T>(data: T) -> Self {
         Self::new(data)
     }
+    fn try_new<T>(data: T) -> Result<Self, T> {
+        if !Self::can_store::<T>() {
+            return Err(data);
+        }
+        // SAFETY: size and alignment are checked above
+        Ok(unsafe { Self::new_unchecked(data) })
+    }
+    const CAPACITY: Capacity = Capacity::new(SIZE, ALIGN);
+    unsafe fn new_unchecked<T>(data: T) -> Self {
+        // SAFETY: `Self::CAPACITY` checks size and alignment, as per function contract
+        unsafe { Self::new_unchecked(data) }
+    }
     fn ptr(&self) -> NonNull<()> {
         NonNull::new(self.data.get()).unwrap().cast()
     }
@@ -157,11 +438,53 @@ where
     unsafe fn drop_in_place(&mut self, _layout: Layout) {}
 }
 
+/// Report of the objects which don't fit into a [`Raw`] storage, returned by
+/// [`Raw::check_layouts`].
+#[derive(Debug, Clone, Copy)]
+pub struct LayoutReport<'a> {
+    implementors: &'a [(&'a str, &'a [(&'a str, Layout)])],
+    storage: (usize, usize),
+}
+
+impl<'a> LayoutReport<'a> {
+    /// Returns the objects which don't fit, with the name of their implementor and method.
+    pub fn misfits(&self) -> impl Iterator<Item = (&'a str, &'a str, Layout)> + use<'a> {
+        let (size, align) = self.storage;
+        (self.implementors.iter())
+            .flat_map(|(implementor, layouts)| layouts.iter().map(move |l| (*implementor, l)))
+            .filter(move |(_, (_, l))| l.size() > size || l.align() > align)
+            .map(|(implementor, (method, layout))| (implementor, *method, *layout))
+    }
+}
+
+impl fmt::Display for LayoutReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (size, align) = self.storage;
+        write!(f, "objects don't fit into Raw<{size}, {align}> storage:")?;
+        for (implementor, method, layout) in self.misfits() {
+            let (size, align) = (layout.size(), layout.align());
+            write!(
+                f,
+                "\n- {implementor}::{method} (size={size}, align={align})"
+            )?;
+        }
+        Ok(())
+    }
+}
+
 /// A type-erased [`Box`](StdBox).
 #[cfg(any(feature = "alloc", doc))]
 #[derive(Debug)]
 pub struct Box(NonNull<()>);
 
+// SAFETY: `Box` owns the data, like `alloc::boxed::Box`
+#[cfg(feature = "alloc")]
+unsafe impl Send for Box {}
+
+// SAFETY: `Box` owns the data, like `alloc::boxed::Box`
+#[cfg(feature = "alloc")]
+unsafe impl Sync for Box {}
+
 #[cfg(feature = "alloc")]
 impl FromBox for Box {
     fn from_box<T>(data: StdBox<T>) -> Self {
@@ -175,6 +498,594 @@ unsafe impl Storage for Box {
     fn new<T>(data: T) -> Self {
         Self::from_box(StdBox::new(data))
     }
+    fn try_new<T>(data: T) -> Result<Self, T> {
+        let layout = Layout::new::<T>();
+        if layout.size() == 0 {
+            return Ok(<Self as Storage>::new(data));
+        }
+        // SAFETY: the layout has a non-zero size
+        let Some(ptr) = NonNull::new(unsafe { alloc::alloc::alloc(layout) }) else {
+            return Err(data);
+        };
+        // SAFETY: the allocation has the layout of `T`
+        unsafe { ptr.cast::<T>().write(data) };
+        Ok(Self(ptr.cast()))
+    }
+    fn ptr(&self) -> NonNull<()> {
+        self.0
+    }
+    fn ptr_mut(&mut self) -> NonNull<()> {
+        self.0
+    }
+    unsafe fn drop_in_place(&mut self, layout: Layout) {
+        if layout.size() != 0 {
+            // SAFETY: storage has been initialized with `Box<T>`,
+            // and `layout` must be `Layout::new::<T>()` as per function contract
+            unsafe { alloc::alloc::dealloc(self.0.as_ptr().cast(), layout) };
+        }
+    }
+    unsafe fn move_into_box(&mut self, _layout: Layout) -> NonNull<()> {
+        self.0
+    }
+}
+
+/// An allocator which can be used in [`BoxIn`] and [`RawOrBoxIn`] storages.
+#[cfg(feature = "allocator-api2")]
+pub trait StorageAllocator: Allocator {
+    /// Constructor of the allocator used in [`Storage::new`].
+    ///
+    /// If `None`, [`Storage::new`] fails at build time, [`Storage::try_new`] always fails,
+    /// and the storage must be constructed
+    /// from an allocated box, e.g. with [`DynObject::from_box_in`](crate::DynObject::from_box_in).
+    const NEW: Option<fn() -> Self>;
+}
+
+#[cfg(feature = "allocator-api2")]
+impl StorageAllocator for Global {
+    const NEW: Option<fn() -> Self> = Some(|| Global);
+}
+
+#[cfg(feature = "allocator-api2")]
+impl<A: Allocator + ?Sized> StorageAllocator for &A {
+    const NEW: Option<fn() -> Self> = None;
+}
+
+/// A storage that can be constructed from data boxed with an allocator.
+#[cfg(feature = "allocator-api2")]
+pub trait FromBoxIn<A: Allocator>: Storage {
+    /// Constructs a new storage storing `T`.
+    ///
+    /// Data may be moved out the box if it fits in the storage.
+    fn from_box_in<T>(boxed: allocator_api2::boxed::Box<T, A>) -> Self;
+}
+
+/// A type-erased [`Box`](allocator_api2::boxed::Box) with a generic allocator.
+#[cfg(feature = "allocator-api2")]
+#[derive(Debug)]
+pub struct BoxIn<A: StorageAllocator = Global>(NonNull<()>, mem::ManuallyDrop<A>);
+
+// SAFETY: `BoxIn` owns the data and the allocator, like `allocator_api2::boxed::Box`
+#[cfg(feature = "allocator-api2")]
+unsafe impl<A: StorageAllocator + Send> Send for BoxIn<A> {}
+
+// SAFETY: `BoxIn` owns the data and the allocator, like `allocator_api2::boxed::Box`
+#[cfg(feature = "allocator-api2")]
+unsafe impl<A: StorageAllocator + Sync> Sync for BoxIn<A> {}
+
+#[cfg(feature = "allocator-api2")]
+impl<A: StorageAllocator> FromBoxIn<A> for BoxIn<A> {
+    fn from_box_in<T>(boxed: allocator_api2::boxed::Box<T, A>) -> Self {
+        let (ptr, alloc) = allocator_api2::boxed::Box::into_raw_with_allocator(boxed);
+        Self(
+            NonNull::new(ptr).unwrap().cast(),
+            mem::ManuallyDrop::new(alloc),
+        )
+    }
+}
+
+// SAFETY: `ptr`/`ptr_mut` return a pointer to the stored data.
+#[cfg(feature = "allocator-api2")]
+unsafe impl<A: StorageAllocator> Storage for BoxIn<A> {
+    fn new<T>(data: T) -> Self {
+        let new_alloc = const {
+            match A::NEW {
+                Some(new_alloc) => new_alloc,
+                None => panic!("allocator cannot be constructed, use `DynObject::from_box_in`"),
+            }
+        };
+        Self::from_box_in(allocator_api2::boxed::Box::new_in(data, new_alloc()))
+    }
+    fn try_new<T>(data: T) -> Result<Self, T> {
+        let Some(new_alloc) = A::NEW else {
+            return Err(data);
+        };
+        let alloc = new_alloc();
+        let ptr = if size_of::<T>() == 0 {
+            NonNull::<T>::dangling()
+        } else {
+            match alloc.allocate(Layout::new::<T>()) {
+                Ok(ptr) => ptr.cast(),
+                Err(_) => return Err(data),
+            }
+        };
+        // SAFETY: the pointer is either dangling for a zero-sized `T`,
+        // or allocated with the layout of `T`
+        unsafe { ptr.write(data) };
+        Ok(Self(ptr.cast(), mem::ManuallyDrop::new(alloc)))
+    }
+    const CAPACITY: Capacity = match A::NEW {
+        Some(_) => Capacity::ANY,
+        None => Capacity::NONE,
+    };
+    unsafe fn new_unchecked<T>(data: T) -> Self {
+        let Some(new_alloc) = A::NEW else {
+            // SAFETY: `Self::CAPACITY` can store `T` as per function contract
+            unsafe { unreachable_unchecked() }
+        };
+        Self::from_box_in(allocator_api2::boxed::Box::new_in(data, new_alloc()))
+    }
+    fn ptr(&self) -> NonNull<()> {
+        self.0
+    }
+    fn ptr_mut(&mut self) -> NonNull<()> {
+        self.0
+    }
+    unsafe fn drop_in_place(&mut self, layout: Layout) {
+        // SAFETY: the allocator is not used after, as the storage must not be used
+        // as per function contract
+        let alloc = unsafe { mem::ManuallyDrop::take(&mut self.1) };
+        if layout.size() != 0 {
+            // SAFETY: storage has been initialized with `Box<T, A>`,
+            // and `layout` must be `Layout::new::<T>()` as per function contract
+            unsafe { alloc.deallocate(self.0.cast(), layout) };
+        }
+    }
+}
+
+/// A reference counter of a shared storage.
+#[cfg(feature = "alloc")]
+trait RefCount {
+    const ONE: Self;
+    fn increment(&self);
+    /// Returns `true` if the last reference has been released.
+    fn decrement(&self) -> bool;
+}
+
+#[cfg(feature = "alloc")]
+impl RefCount for core::cell::Cell<usize> {
+    const ONE: Self = core::cell::Cell::new(1);
+    fn increment(&self) {
+        self.set(self.get().checked_add(1).expect("reference count overflow"));
+    }
+    fn decrement(&self) -> bool {
+        self.set(self.get() - 1);
+        self.get() == 0
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl RefCount for AtomicUsize {
+    const ONE: Self = AtomicUsize::new(1);
+    fn increment(&self) {
+        // Same limit as `alloc::sync::Arc`, but panicking instead of aborting
+        assert!(
+            self.fetch_add(1, Ordering::Relaxed) <= isize::MAX as usize,
+            "reference count overflow"
+        );
+    }
+    fn decrement(&self) -> bool {
+        if self.fetch_sub(1, Ordering::Release) != 1 {
+            return false;
+        }
+        atomic::fence(Ordering::Acquire);
+        true
+    }
+}
+
+/// An allocation, with a header placed just before the data.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+struct Prefixed<H>(NonNull<()>, PhantomData<H>);
+
+#[cfg(feature = "alloc")]
+impl<H> Prefixed<H> {
+    /// Returns the layout of the allocation and the offset of the data.
+    ///
+    /// The offset is a multiple of the header alignment, and is greater or equal to
+    /// its size, so the header can always be placed just before the data.
+    fn layout(layout: Layout) -> (Layout, usize) {
+        Layout::new::<H>().extend(layout).unwrap()
+    }
+
+    fn new<T>(header: H, data: T) -> Self {
+        match Self::try_new(header, data) {
+            Ok(prefixed) => prefixed,
+            Err(_) => alloc::alloc::handle_alloc_error(Self::layout(Layout::new::<T>()).0),
+        }
+    }
+
+    fn try_new<T>(header: H, data: T) -> Result<Self, T> {
+        const { assert!(size_of::<H>() != 0) };
+        let (layout, offset) = Self::layout(Layout::new::<T>());
+        // SAFETY: the layout contains the header, so it has a non-zero size
+        let Some(ptr) = NonNull::new(unsafe { alloc::alloc::alloc(layout) }) else {
+            return Err(data);
+        };
+        // SAFETY: the offset is in bounds of the allocation
+        let data_ptr = unsafe { ptr.add(offset) };
+        // SAFETY: the allocation has the layout of both the header and `T`, see `Self::layout`
+        unsafe { data_ptr.cast::<H>().sub(1).write(header) };
+        // SAFETY: same as above
+        unsafe { data_ptr.cast::<T>().write(data) };
+        Ok(Self(data_ptr.cast(), PhantomData))
+    }
+
+    fn header(&self) -> &H {
+        // SAFETY: the header is placed just before the data, see `Self::layout`
+        unsafe { self.0.cast::<H>().sub(1).as_ref() }
+    }
+
+    /// # Safety
+    ///
+    /// Data must have been dropped, and `layout` must be its layout.
+    unsafe fn dealloc(&mut self, layout: Layout) {
+        let (layout, offset) = Self::layout(layout);
+        // SAFETY: the allocation has been made with the same layout, the data being
+        // placed at `offset`
+        unsafe { alloc::alloc::dealloc(self.0.cast::<u8>().sub(offset).as_ptr(), layout) };
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: RefCount> Clone for Prefixed<C> {
+    fn clone(&self) -> Self {
+        self.header().increment();
+        Self(self.0, PhantomData)
+    }
+}
+
+macro_rules! shared_storage {
+    ($(#[$attr:meta])* $name:ident($counter:ty)) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone)]
+        pub struct $name(Prefixed<$counter>);
+
+        $(#[$attr])*
+        // SAFETY: `ptr`/`ptr_mut` return a pointer to the stored data,
+        // and the data is only dropped by the last owner
+        unsafe impl SharedStorage for $name {
+            fn new<T>(data: T) -> Self {
+                Self(Prefixed::new(<$counter>::ONE, data))
+            }
+            fn try_new<T>(data: T) -> Result<Self, T> {
+                Prefixed::try_new(<$counter>::ONE, data).map(Self)
+            }
+            fn ptr(&self) -> NonNull<()> {
+                self.0.0
+            }
+            fn ptr_mut(&mut self) -> NonNull<()> {
+                self.0.0
+            }
+            unsafe fn drop_object(
+                &mut self,
+                drop_in_place: Option<unsafe fn(NonNull<()>)>,
+                layout: Layout,
+            ) {
+                if self.0.header().decrement() {
+                    if let Some(drop_in_place) = drop_in_place {
+                        // SAFETY: this is the last owner, and the function matches the data
+                        // as per function contract
+                        unsafe { drop_in_place(self.0.0) };
+                    }
+                    // SAFETY: data has been dropped above
+                    unsafe { self.0.dealloc(layout) };
+                }
+            }
+        }
+    };
+}
+
+shared_storage!(
+    /// A reference-counted storage, like [`Rc`](alloc::rc::Rc).
+    ///
+    /// Data is shared between clones of the `DynObject`, so only methods taking `&self` are
+    /// available.
+    #[cfg(feature = "alloc")]
+    Rc(core::cell::Cell<usize>)
+);
+
+shared_storage!(
+    /// A thread-safe reference-counted storage, like [`Arc`](alloc::sync::Arc).
+    ///
+    /// Data is shared between clones of the `DynObject`, so only methods taking `&self` are
+    /// available.
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    Arc(AtomicUsize)
+);
+
+/// A type-erased [`Box`](StdBox) with a header placed before the data, used by
+/// [`ThinDynObject`](crate::ThinDynObject) to store its vtable.
+///
+/// # Examples
+///
+/// ```rust
+/// # use dyn_utils::{ThinDynObject, storage::ThinBox};
+/// let future: ThinDynObject<dyn Future<Output = usize>, ThinBox> =
+///     ThinDynObject::new(async { 42 });
+/// assert_eq!(size_of_val(&future), size_of::<usize>());
+/// # futures::executor::block_on(async move {
+/// assert_eq!(future.await, 42);
+/// # });
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct ThinBox(Prefixed<Option<NonNull<()>>>);
+
+// SAFETY: `ThinBox` owns the data, like `alloc::boxed::Box`
+#[cfg(feature = "alloc")]
+unsafe impl Send for ThinBox {}
+
+// SAFETY: `ThinBox` owns the data, like `alloc::boxed::Box`
+#[cfg(feature = "alloc")]
+unsafe impl Sync for ThinBox {}
+
+// SAFETY: `ptr`/`ptr_mut` return a pointer to the stored data.
+#[cfg(feature = "alloc")]
+unsafe impl Storage for ThinBox {
+    fn new<T>(data: T) -> Self {
+        Self(Prefixed::new(None, data))
+    }
+    fn try_new<T>(data: T) -> Result<Self, T> {
+        Prefixed::try_new(None, data).map(Self)
+    }
+    fn ptr(&self) -> NonNull<()> {
+        self.0.0
+    }
+    fn ptr_mut(&mut self) -> NonNull<()> {
+        self.0.0
+    }
+    unsafe fn drop_in_place(&mut self, layout: Layout) {
+        // SAFETY: same precondition
+        unsafe { self.0.dealloc(layout) };
+    }
+}
+
+// SAFETY: data is preceded by a pointer header, which is not accessed by the storage
+#[cfg(feature = "alloc")]
+unsafe impl ThinStorage for ThinBox {
+    fn into_raw(self) -> NonNull<()> {
+        self.0.0
+    }
+    unsafe fn from_raw(ptr: NonNull<()>) -> Self {
+        Self(Prefixed(ptr, PhantomData))
+    }
+}
+
+/// A pool of recycled memory blocks, used by [`Pooled`] storage.
+///
+/// Blocks are allocated with the global allocator, by power-of-two size classes, and are kept
+/// in the pool when released, to be handed out again for an object of the same size class.
+/// Objects with an alignment greater than 16 are not pooled.
+///
+/// The pool is not thread-safe, so it is expected to be used as a thread-local, see
+/// [`PoolHandle`]. A block can be released in another pool than the one it was taken from.
+#[cfg(feature = "alloc")]
+pub struct Pool {
+    free_lists: [core::cell::Cell<Option<NonNull<FreeBlock>>>; usize::BITS as usize],
+}
+
+#[cfg(feature = "alloc")]
+struct FreeBlock(Option<NonNull<FreeBlock>>);
+
+// SAFETY: the pool owns its blocks exclusively
+#[cfg(feature = "alloc")]
+unsafe impl Send for Pool {}
+
+#[cfg(feature = "alloc")]
+impl Pool {
+    const MAX_ALIGN: usize = 16;
+
+    /// Constructs a new, empty pool.
+    pub const fn new() -> Self {
+        Self {
+            free_lists: [const { core::cell::Cell::new(None) }; usize::BITS as usize],
+        }
+    }
+
+    /// Releases all the blocks kept in the pool.
+    pub fn clear(&self) {
+        for (class, free_list) in self.free_lists.iter().enumerate() {
+            while let Some(block) = free_list.get() {
+                // SAFETY: blocks in free lists are valid `FreeBlock`
+                free_list.set(unsafe { block.read() }.0);
+                // SAFETY: blocks in free lists have been allocated with their class layout
+                unsafe { alloc::alloc::dealloc(block.as_ptr().cast(), Self::class_layout(class)) };
+            }
+        }
+    }
+
+    /// Returns the size class of the layout, or `None` if it cannot be pooled.
+    fn size_class(layout: Layout) -> Option<usize> {
+        if layout.align() > Self::MAX_ALIGN {
+            return None;
+        }
+        let size = layout.size().max(size_of::<FreeBlock>());
+        let class = size.checked_next_power_of_two()?.trailing_zeros() as usize;
+        Layout::from_size_align(1 << class, 1).ok()?;
+        Some(class)
+    }
+
+    fn class_layout(class: usize) -> Layout {
+        let size = 1 << class;
+        // SAFETY: `size` is a valid layout size as checked in `Self::size_class`,
+        // and the alignment is a power of two lower than `size`
+        unsafe { Layout::from_size_align_unchecked(size, size.min(Self::MAX_ALIGN)) }
+    }
+
+    /// Returns a block fitting the layout, or a null pointer if the allocation fails.
+    ///
+    /// # Safety
+    ///
+    /// `layout` size must be non-zero.
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let free_list = Self::size_class(layout).map(|class| &self.free_lists[class]);
+        match free_list.and_then(|free_list| Some((free_list, free_list.get()?))) {
+            Some((free_list, block)) => {
+                // SAFETY: blocks in free lists are valid `FreeBlock`
+                free_list.set(unsafe { block.read() }.0);
+                block.as_ptr().cast()
+            }
+            // SAFETY: same precondition
+            None => unsafe { Self::alloc_global(layout) },
+        }
+    }
+
+    /// Allocates a block without taking it from a pool, or returns a null pointer if
+    /// the allocation fails.
+    ///
+    /// # Safety
+    ///
+    /// `layout` size must be non-zero.
+    unsafe fn alloc_global(layout: Layout) -> *mut u8 {
+        let layout = Self::size_class(layout).map_or(layout, Self::class_layout);
+        // SAFETY: same precondition
+        unsafe { alloc::alloc::alloc(layout) }
+    }
+
+    /// Puts a block back into the pool.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by [`Pool::alloc`] (of any pool) or [`Pool::alloc_global`]
+    /// with the same layout.
+    unsafe fn dealloc(&self, ptr: NonNull<u8>, layout: Layout) {
+        let Some(class) = Self::size_class(layout) else {
+            // SAFETY: the block has been allocated with `layout` as per function contract
+            return unsafe { alloc::alloc::dealloc(ptr.as_ptr(), layout) };
+        };
+        let block = ptr.cast::<FreeBlock>();
+        // SAFETY: the block fits a `FreeBlock`, as its class size and alignment are at least
+        // the ones of a pointer
+        unsafe { block.write(FreeBlock(self.free_lists[class].get())) };
+        self.free_lists[class].set(Some(block));
+    }
+
+    /// Releases a block without putting it into a pool.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by [`Pool::alloc`] (of any pool) or [`Pool::alloc_global`]
+    /// with the same layout.
+    unsafe fn dealloc_global(ptr: NonNull<u8>, layout: Layout) {
+        let layout = Self::size_class(layout).map_or(layout, Self::class_layout);
+        // SAFETY: the block has been allocated with its class layout, or with `layout`
+        // if it is not pooled
+        unsafe { alloc::alloc::dealloc(ptr.as_ptr(), layout) };
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Default for Pool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for Pool {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(feature = "alloc")]
+impl core::fmt::Debug for Pool {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pool").finish_non_exhaustive()
+    }
+}
+
+/// A static handle to a [`Pool`], typically a thread-local.
+///
+/// As objects can be sent across threads, the handle may be used from any thread.
+#[cfg(feature = "alloc")]
+pub trait PoolHandle {
+    /// Calls `f` with the pool, or returns `None` if it is not available, e.g. if
+    /// the thread-local has been destroyed. In that case, the global allocator is used
+    /// directly.
+    fn with<R>(f: impl FnOnce(&Pool) -> R) -> Option<R>;
+}
+
+/// A type-erased box whose allocation is recycled through a [`Pool`].
+///
+/// Combined with [`Fallback`], e.g. `Fallback<Raw<SIZE>, Pooled<P>>`, it allows returning
+/// objects too large to be stored in place without hitting the global allocator
+/// in the steady state.
+///
+/// # Examples
+///
+/// ```rust
+/// # use dyn_utils::{DynObject, storage::{Pool, PoolHandle, Pooled}};
+/// struct LocalPool;
+/// impl PoolHandle for LocalPool {
+///     fn with<R>(f: impl FnOnce(&Pool) -> R) -> Option<R> {
+///         std::thread_local! { static POOL: Pool = const { Pool::new() }; }
+///         POOL.try_with(f).ok()
+///     }
+/// }
+///
+/// for _ in 0..2 {
+///     // the second future reuses the allocation of the first one
+///     let future: DynObject<dyn Future<Output = usize>, Pooled<LocalPool>> =
+///         DynObject::new(async { 42 });
+/// # futures::executor::block_on(async move {
+///     assert_eq!(future.await, 42);
+/// # });
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct Pooled<P: PoolHandle>(NonNull<()>, PhantomData<P>);
+
+// SAFETY: `Pooled` owns the data, and the block can be deallocated in any thread's pool,
+// as `PoolHandle` is only used statically
+#[cfg(feature = "alloc")]
+unsafe impl<P: PoolHandle> Send for Pooled<P> {}
+
+// SAFETY: `Pooled` owns the data, and the pool is not accessed through a shared reference
+#[cfg(feature = "alloc")]
+unsafe impl<P: PoolHandle> Sync for Pooled<P> {}
+
+#[cfg(feature = "alloc")]
+impl<P: PoolHandle> Pooled<P> {
+    fn alloc(layout: Layout) -> *mut u8 {
+        // SAFETY: `Storage` methods only call it with a non-zero layout size
+        let alloc = |pool: &Pool| unsafe { pool.alloc(layout) };
+        // SAFETY: same as above
+        P::with(alloc).unwrap_or_else(|| unsafe { Pool::alloc_global(layout) })
+    }
+}
+
+// SAFETY: `ptr`/`ptr_mut` return a pointer to the stored data.
+#[cfg(feature = "alloc")]
+unsafe impl<P: PoolHandle> Storage for Pooled<P> {
+    fn new<T>(data: T) -> Self {
+        <Self as Storage>::try_new(data)
+            .unwrap_or_else(|_| alloc::alloc::handle_alloc_error(Layout::new::<T>()))
+    }
+    fn try_new<T>(data: T) -> Result<Self, T> {
+        let layout = Layout::new::<T>();
+        if layout.size() == 0 {
+            return Ok(Self(NonNull::<T>::dangling().cast(), PhantomData));
+        }
+        let Some(ptr) = NonNull::new(Self::alloc(layout)) else {
+            return Err(data);
+        };
+        // SAFETY: the block fits the layout of `T`
+        unsafe { ptr.cast::<T>().write(data) };
+        Ok(Self(ptr.cast(), PhantomData))
+    }
     fn ptr(&self) -> NonNull<()> {
         self.0
     }
@@ -183,125 +1094,383 @@ unsafe impl Storage for Box {
     }
     unsafe fn drop_in_place(&mut self, layout: Layout) {
         if layout.size() != 0 {
-            // SAFETY: storage has been initialized with `Box<T>`,
-            // and `layout` must be `Layout::new::<T>()` as per function contract
-            unsafe { alloc::alloc::dealloc(self.0.as_ptr().cast(), layout) };
+            let ptr = self.0.cast();
+            // SAFETY: the block has been allocated with `layout`, as per function contract
+            if P::with(|pool| unsafe { pool.dealloc(ptr, layout) }).is_none() {
+                // SAFETY: same as above
+                unsafe { Pool::dealloc_global(ptr, layout) };
+            }
         }
     }
 }
 
 #[derive(Debug)]
-enum RawOrBoxInner<const SIZE: usize, const ALIGN: usize = { align_of::<usize>() }>
-where
-    Align<ALIGN>: Alignment,
-{
-    Raw(Raw<SIZE, ALIGN>),
-    #[cfg(feature = "alloc")]
-    Box(Box),
+enum FallbackInner<A, B> {
+    First(A),
+    Second(B),
 }
 
-/// A [`Raw`] storage with `Box` backup if the object doesn't fit in.
+/// A storage combinator using `A` storage if the object can be stored in it, see
+/// [`Storage::CAPACITY`], and `B` storage otherwise.
 ///
-/// When `alloc` feature is not enabled, it behaves like [`Raw`].
+/// The storage used is decided at compile time, so combinators can be chained, e.g.
+/// `Fallback<Raw<32>, Fallback<Raw<256, 16>, Box>>`.
 #[derive(Debug)]
-pub struct RawOrBox<const SIZE: usize, const ALIGN: usize = { align_of::<usize>() }>(
-    RawOrBoxInner<SIZE, ALIGN>,
-)
-where
-    Align<ALIGN>: Alignment;
+pub struct Fallback<A, B>(FallbackInner<A, B>);
 
 #[cfg_attr(coverage_nightly, coverage(off))]
-impl<const SIZE: usize, const ALIGN: usize> RawOrBox<SIZE, ALIGN>
+impl<const SIZE: usize, const ALIGN: usize, B: Storage> Fallback<Raw<SIZE, ALIGN>, B>
 where
     Align<ALIGN>: Alignment,
 {
-    /// Constructs a [`Raw`] variant of `RawOrBox`.
+    /// Constructs a [`Raw`] variant of `Fallback`.
     pub const fn new_raw<T>(data: T) -> Self {
-        Self(RawOrBoxInner::Raw(Raw::new(data)))
+        Self(FallbackInner::First(Raw::new(data)))
     }
 }
 
 #[cfg_attr(coverage_nightly, coverage(off))]
 #[cfg(feature = "alloc")]
-impl<const SIZE: usize, const ALIGN: usize> FromBox for RawOrBox<SIZE, ALIGN>
-where
-    Align<ALIGN>: Alignment,
-{
+impl<A: Storage, B: FromBox> FromBox for Fallback<A, B> {
     fn from_box<T>(data: StdBox<T>) -> Self {
-        if Raw::<SIZE, ALIGN>::can_store::<T>() {
-            Self::new(*data)
+        if A::CAPACITY.can_store::<T>() {
+            // SAFETY: `A::CAPACITY` is checked above
+            Self(FallbackInner::First(unsafe { A::new_unchecked(*data) }))
+        } else {
+            Self(FallbackInner::Second(B::from_box(data)))
+        }
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(feature = "allocator-api2")]
+impl<Alloc: Allocator, A: Storage, B: FromBoxIn<Alloc>> FromBoxIn<Alloc> for Fallback<A, B> {
+    fn from_box_in<T>(data: allocator_api2::boxed::Box<T, Alloc>) -> Self {
+        if A::CAPACITY.can_store::<T>() {
+            let data = allocator_api2::boxed::Box::into_inner(data);
+            // SAFETY: `A::CAPACITY` is checked above
+            Self(FallbackInner::First(unsafe { A::new_unchecked(data) }))
         } else {
-            Self(RawOrBoxInner::Box(Box::from_box(data)))
+            Self(FallbackInner::Second(B::from_box_in(data)))
         }
     }
 }
 
-// SAFETY: The impl delegates to `Raw`/`Box` which implements `Storage`
+// SAFETY: The impl delegates to `A`/`B` which implements `Storage`
 // This enum is generic and the variant is chosen according constant predicate,
 // so it's not possible to cover all variant for a specific monomorphization.
 // https://github.com/taiki-e/cargo-llvm-cov/issues/394
 #[cfg_attr(coverage_nightly, coverage(off))]
-unsafe impl<const SIZE: usize, const ALIGN: usize> Storage for RawOrBox<SIZE, ALIGN>
-where
-    Align<ALIGN>: Alignment,
-{
+unsafe impl<A: Storage, B: Storage> Storage for Fallback<A, B> {
     fn new<T>(data: T) -> Self {
-        #[cfg(feature = "alloc")]
-        if Raw::<SIZE, ALIGN>::can_store::<T>() {
-            // SAFETY: size and alignment are checked above
-            Self(RawOrBoxInner::Raw(unsafe { Raw::new_unchecked(data) }))
+        const {
+            assert!(
+                <Self as Storage>::CAPACITY.can_store::<T>(),
+                "object doesn't fit into any `Fallback` storage"
+            );
+        }
+        // SAFETY: assertion above ensures function contract
+        unsafe { <Self as Storage>::new_unchecked(data) }
+    }
+    fn try_new<T>(data: T) -> Result<Self, T> {
+        if A::CAPACITY.can_store::<T>() {
+            A::try_new(data).map(|s| Self(FallbackInner::First(s)))
         } else {
-            Self(RawOrBoxInner::Box(Box::new(data)))
+            B::try_new(data).map(|s| Self(FallbackInner::Second(s)))
         }
-        #[cfg(not(feature = "alloc"))]
-        {
-            Self(RawOrBoxInner::Raw(Raw::new(data)))
+    }
+    const CAPACITY: Capacity = A::CAPACITY.or(B::CAPACITY);
+    unsafe fn new_unchecked<T>(data: T) -> Self {
+        if A::CAPACITY.can_store::<T>() {
+            // SAFETY: `A::CAPACITY` is checked above
+            Self(FallbackInner::First(unsafe { A::new_unchecked(data) }))
+        } else {
+            // SAFETY: `B::CAPACITY` can store `T` as per function contract
+            Self(FallbackInner::Second(unsafe { B::new_unchecked(data) }))
         }
     }
     fn ptr(&self) -> NonNull<()> {
         match &self.0 {
-            RawOrBoxInner::Raw(s) => s.ptr(),
-            #[cfg(feature = "alloc")]
-            RawOrBoxInner::Box(s) => s.ptr(),
+            FallbackInner::First(s) => s.ptr(),
+            FallbackInner::Second(s) => s.ptr(),
         }
     }
     fn ptr_mut(&mut self) -> NonNull<()> {
         match &mut self.0 {
-            RawOrBoxInner::Raw(s) => s.ptr_mut(),
-            #[cfg(feature = "alloc")]
-            RawOrBoxInner::Box(s) => s.ptr_mut(),
+            FallbackInner::First(s) => s.ptr_mut(),
+            FallbackInner::Second(s) => s.ptr_mut(),
         }
     }
     unsafe fn as_ref<T>(&self) -> &T {
         match &self.0 {
             // SAFETY: same precondition
-            RawOrBoxInner::Raw(s) if Raw::<SIZE, ALIGN>::can_store::<T>() => unsafe { s.as_ref() },
-            #[cfg(feature = "alloc")]
+            FallbackInner::First(s) if A::CAPACITY.can_store::<T>() => unsafe { s.as_ref() },
             // SAFETY: same precondition
-            RawOrBoxInner::Box(s) if !Raw::<SIZE, ALIGN>::can_store::<T>() => unsafe { s.as_ref() },
-            // SAFETY: storage will always be Raw if it can store `T`
+            FallbackInner::Second(s) if !A::CAPACITY.can_store::<T>() => unsafe { s.as_ref() },
+            // SAFETY: storage will always be `A` if it can store `T`
             _ => unsafe { unreachable_unchecked() },
         }
     }
     unsafe fn as_mut<T>(&mut self) -> &mut T {
         match &mut self.0 {
             // SAFETY: same precondition
-            RawOrBoxInner::Raw(s) if Raw::<SIZE, ALIGN>::can_store::<T>() => unsafe { s.as_mut() },
-            #[cfg(feature = "alloc")]
+            FallbackInner::First(s) if A::CAPACITY.can_store::<T>() => unsafe { s.as_mut() },
             // SAFETY: same precondition
-            RawOrBoxInner::Box(s) if !Raw::<SIZE, ALIGN>::can_store::<T>() => unsafe { s.as_mut() },
-            // SAFETY: storage will always be Raw if it can store `T`
+            FallbackInner::Second(s) if !A::CAPACITY.can_store::<T>() => unsafe { s.as_mut() },
+            // SAFETY: storage will always be `A` if it can store `T`
             _ => unsafe { unreachable_unchecked() },
         }
     }
     unsafe fn drop_in_place(&mut self, layout: Layout) {
         match &mut self.0 {
             // SAFETY: same precondition
-            RawOrBoxInner::Raw(s) => unsafe { s.drop_in_place(layout) },
-            #[cfg(feature = "alloc")]
+            FallbackInner::First(s) => unsafe { s.drop_in_place(layout) },
             // SAFETY: same precondition
-            RawOrBoxInner::Box(s) => unsafe { s.drop_in_place(layout) },
+            FallbackInner::Second(s) => unsafe { s.drop_in_place(layout) },
+        }
+    }
+    #[cfg(feature = "alloc")]
+    unsafe fn move_into_box(&mut self, layout: Layout) -> NonNull<()> {
+        match &mut self.0 {
+            // SAFETY: same function contract
+            FallbackInner::First(s) => unsafe { s.move_into_box(layout) },
+            // SAFETY: same function contract
+            FallbackInner::Second(s) => unsafe { s.move_into_box(layout) },
+        }
+    }
+}
+
+/// A [`Raw`] storage with `Box` backup if the object doesn't fit in.
+///
+/// When `alloc` feature is not enabled, it behaves like [`Raw`].
+#[cfg(feature = "alloc")]
+pub type RawOrBox<const SIZE: usize, const ALIGN: usize = { align_of::<usize>() }> =
+    Fallback<Raw<SIZE, ALIGN>, Box>;
+
+/// A [`Raw`] storage with `Box` backup if the object doesn't fit in.
+///
+/// When `alloc` feature is not enabled, it behaves like [`Raw`].
+#[cfg(not(feature = "alloc"))]
+pub type RawOrBox<const SIZE: usize, const ALIGN: usize = { align_of::<usize>() }> =
+    Fallback<Raw<SIZE, ALIGN>, Raw<SIZE, ALIGN>>;
+
+/// A [`Raw`] storage with [`BoxIn`] backup if the object doesn't fit in.
+///
+/// If the allocator cannot be constructed, see [`StorageAllocator::NEW`], the storage must be
+/// constructed from an allocated box.
+#[cfg(feature = "allocator-api2")]
+pub type RawOrBoxIn<const SIZE: usize, const ALIGN: usize = { align_of::<usize>() }, A = Global> =
+    Fallback<Raw<SIZE, ALIGN>, BoxIn<A>>;
+
+/// A caller-provided buffer backing a [`Slot`] storage, e.g. in a static arena.
+///
+/// The buffer must be pinned, because an object whose `DynObject` has been leaked is not
+/// dropped with it, but when the buffer is reused or dropped. Lifetime `'b` is the one of the
+/// objects stored in the buffer, which must then outlive it.
+#[derive(Debug)]
+pub struct SlotBuffer<'b, const SIZE: usize, const ALIGN: usize = { align_of::<usize>() }>
+where
+    Align<ALIGN>: Alignment,
+{
+    data: SlotData<SIZE, ALIGN>,
+    _phantom: PhantomData<&'b ()>,
+}
+
+impl<const SIZE: usize, const ALIGN: usize> SlotBuffer<'_, SIZE, ALIGN>
+where
+    Align<ALIGN>: Alignment,
+{
+    /// Constructs a new empty `SlotBuffer`.
+    pub const fn new() -> Self {
+        Self {
+            data: SlotData {
+                data: UnsafeCell::new(MaybeUninit::uninit()),
+                _align: Align::NEW,
+                drop_in_place: None,
+                _pinned: PhantomPinned,
+            },
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns `true` if `T` can be stored in the buffer.
+    pub const fn can_store<T>() -> bool {
+        size_of::<T>() <= SIZE && align_of::<T>() <= ALIGN
+    }
+
+    fn data(self: Pin<&mut Self>) -> Pin<&mut SlotData<SIZE, ALIGN>> {
+        // SAFETY: `data` is structurally pinned
+        unsafe { self.map_unchecked_mut(|buffer| &mut buffer.data) }
+    }
+}
+
+impl<const SIZE: usize, const ALIGN: usize> Default for SlotBuffer<'_, SIZE, ALIGN>
+where
+    Align<ALIGN>: Alignment,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SIZE: usize, const ALIGN: usize> Drop for SlotBuffer<'_, SIZE, ALIGN>
+where
+    Align<ALIGN>: Alignment,
+{
+    fn drop(&mut self) {
+        // SAFETY: `drop` is called on pinned data, see `Drop` documentation
+        unsafe { Pin::new_unchecked(self) }.data().clear();
+    }
+}
+
+/// Data of a [`SlotBuffer`], without the lifetime of the stored object.
+#[derive(Debug)]
+#[repr(C)]
+struct SlotData<const SIZE: usize, const ALIGN: usize>
+where
+    Align<ALIGN>: Alignment,
+{
+    data: UnsafeCell<MaybeUninit<[u8; SIZE]>>,
+    _align: Align<ALIGN>,
+    drop_in_place: Option<unsafe fn(NonNull<()>)>,
+    _pinned: PhantomPinned,
+}
+
+impl<const SIZE: usize, const ALIGN: usize> SlotData<SIZE, ALIGN>
+where
+    Align<ALIGN>: Alignment,
+{
+    fn ptr(&self) -> NonNull<()> {
+        NonNull::new(self.data.get()).unwrap().cast()
+    }
+
+    /// Drops the stored object if it has not been released by its storage.
+    fn clear(self: Pin<&mut Self>) {
+        // SAFETY: data is not moved
+        let this = unsafe { self.get_unchecked_mut() };
+        if let Some(drop_in_place) = this.drop_in_place.take() {
+            // SAFETY: `drop_in_place` is only set when an object has been written into the
+            // buffer, and it is reset when the object is dropped or moved out by the storage;
+            // the object outlives the buffer, see `SlotBuffer` lifetime
+            unsafe { drop_in_place(this.ptr()) }
+        }
+    }
+}
+
+/// A storage borrowing a caller-provided [`SlotBuffer`], where data is stored in place.
+///
+/// Contrary to other storages, it cannot be constructed with [`Storage::new`], which fails
+/// at build time, nor with [`Storage::try_new`], which always fails;
+/// [`DynObject::new_in`](crate::DynObject::new_in) must be used instead.
+/// As a consequence, cloning an object stored in it panics.
+///
+/// Data size and alignment must fit, e.g. be lesser or equal to the generic parameters.
+/// This condition is enforced by a constant assertion, which triggers at build time
+/// — **it is not triggered by `cargo check`**.
+#[derive(Debug)]
+pub struct Slot<'a, const SIZE: usize, const ALIGN: usize = { align_of::<usize>() }>(
+    Pin<&'a mut SlotData<SIZE, ALIGN>>,
+)
+where
+    Align<ALIGN>: Alignment;
+
+// SAFETY: `Slot` only borrows the data, whose thread-safety is checked by `DynObject`
+unsafe impl<const SIZE: usize, const ALIGN: usize> Send for Slot<'_, SIZE, ALIGN> where
+    Align<ALIGN>: Alignment
+{
+}
+
+// SAFETY: `Slot` only borrows the data, whose thread-safety is checked by `DynObject`
+unsafe impl<const SIZE: usize, const ALIGN: usize> Sync for Slot<'_, SIZE, ALIGN> where
+    Align<ALIGN>: Alignment
+{
+}
+
+impl<'a, const SIZE: usize, const ALIGN: usize> Slot<'a, SIZE, ALIGN>
+where
+    Align<ALIGN>: Alignment,
+{
+    /// Constructs a new `Slot` storage, with compile-time assertion that `T` can be stored.
+    ///
+    /// An object still stored in the buffer, because its `DynObject` has been leaked,
+    /// is dropped first.
+    pub(crate) fn new<'b, T: 'b>(
+        buffer: Pin<&'a mut SlotBuffer<'b, SIZE, ALIGN>>,
+        data: T,
+    ) -> Self {
+        #[cfg(feature = "const_panic")]
+        const {
+            let (size, align) = (size_of::<T>(), align_of::<T>());
+            #[rustfmt::skip]
+            const_panic::concat_assert!(
+                SlotBuffer::<SIZE, ALIGN>::can_store::<T>(),
+                "object (size=", size, ", align=", align, ")",
+                " doesn't fit into Slot<", SIZE, ", ", ALIGN, "> storage"
+            );
+        }
+        #[cfg(not(feature = "const_panic"))]
+        const {
+            assert!(SlotBuffer::<SIZE, ALIGN>::can_store::<T>());
         }
+        let mut buffer = buffer.data();
+        buffer.as_mut().clear();
+        // SAFETY: data is not moved
+        let slot = unsafe { buffer.as_mut().get_unchecked_mut() };
+        // SAFETY: assertion above ensures that `slot.data` size and alignment matches
+        // `data` ones; alignment is obtained through `_align` field and `repr(C)`
+        unsafe { slot.ptr().cast::<T>().write(data) };
+        slot.drop_in_place = const {
+            if mem::needs_drop::<T>() {
+                // SAFETY: the pointer has been written with `T` above
+                Some(|ptr| unsafe { ptr.cast::<T>().drop_in_place() })
+            } else {
+                None
+            }
+        };
+        Self(buffer)
+    }
+
+    /// Releases the buffer, as the stored object has been dropped or moved out.
+    fn release(&mut self) {
+        // SAFETY: data is not moved
+        unsafe { self.0.as_mut().get_unchecked_mut() }.drop_in_place = None;
+    }
+}
+
+impl<const SIZE: usize, const ALIGN: usize> Drop for Slot<'_, SIZE, ALIGN>
+where
+    Align<ALIGN>: Alignment,
+{
+    fn drop(&mut self) {
+        // The storage is only dropped after its object, even when the drop panics
+        self.release();
+    }
+}
+
+// SAFETY: `ptr`/`ptr_mut` return a pointer to the stored data.
+unsafe impl<const SIZE: usize, const ALIGN: usize> Storage for Slot<'_, SIZE, ALIGN>
+where
+    Align<ALIGN>: Alignment,
+{
+    fn new<T>(_data: T) -> Self {
+        const { panic!("`Slot` storage must be constructed with `DynObject::new_in`") }
+    }
+    fn try_new<T>(data: T) -> Result<Self, T> {
+        Err(data)
+    }
+    const CAPACITY: Capacity = Capacity::NONE;
+    unsafe fn new_unchecked<T>(_data: T) -> Self {
+        // SAFETY: `Self::CAPACITY` cannot store anything, so this is never called
+        unsafe { unreachable_unchecked() }
+    }
+    fn ptr(&self) -> NonNull<()> {
+        self.0.ptr()
+    }
+    fn ptr_mut(&mut self) -> NonNull<()> {
+        self.0.ptr()
+    }
+    unsafe fn drop_in_place(&mut self, _layout: Layout) {
+        self.release();
     }
 }
 
@@ -309,11 +1478,11 @@ where
 #[cfg(test)]
 #[allow(clippy::undocumented_unsafe_blocks)]
 mod tests {
-    use core::{any::Any, cell::RefCell, mem};
+    use core::{any::Any, cell::RefCell, mem, pin::pin};
 
     use elain::{Align, Alignment};
 
-    use crate::{DynObject, storage::Storage};
+    use crate::{DynObject, storage::SharedStorage};
 
     trait Test {}
     const _: () = {
@@ -334,7 +1503,7 @@ mod tests {
             }
         }
         unsafe impl<'__lt, __Dyn: Test + '__lt> crate::object::Vtable<__Dyn> for dyn Test + '__lt {
-            fn vtable<__Storage: crate::storage::Storage>() -> &'static Self::Vtable {
+            fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
                 &const {
                     __Vtable {
                         __drop_in_place: <Self as crate::object::Vtable<__Dyn>>::DROP_IN_PLACE_FN,
@@ -347,6 +1516,7 @@ mod tests {
     impl Test for () {}
     impl<const N: usize> Test for [u8; N] {}
     impl Test for u64 {}
+    impl<const N: usize> Test for Align<N> where Align<N>: Alignment {}
     type TestObject<'__dyn, S> = DynObject<dyn Test + '__dyn, S>;
 
     #[test]
@@ -384,11 +1554,47 @@ mod tests {
                 array
             );
         }
-        check_variant::<4>(|s| matches!(s.0, super::RawOrBoxInner::Raw(_)));
-        check_variant::<64>(|s| matches!(s.0, super::RawOrBoxInner::Box(_)));
+        check_variant::<4>(|s| matches!(s.0, super::FallbackInner::First(_)));
+        check_variant::<64>(|s| matches!(s.0, super::FallbackInner::Second(_)));
 
         let storage = TestObject::<super::RawOrBox<8, 1>>::new(0u64);
-        assert!(matches!(storage.storage().0, super::RawOrBoxInner::Box(_)));
+        assert!(matches!(
+            storage.storage().0,
+            super::FallbackInner::Second(_)
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fallback() {
+        use super::{Box, Fallback, FallbackInner, Raw};
+        type Chained = Fallback<Raw<4>, Fallback<Raw<16, 16>, Box>>;
+        fn check_variant<T: Test + 'static>(data: T, variant: impl Fn(&Chained) -> bool) {
+            assert!(variant(TestObject::<Chained>::new(data).storage()));
+        }
+        check_variant([0u8; 4], |s| matches!(s.0, FallbackInner::First(_)));
+        check_variant(
+            Align::<16>::NEW,
+            |s| matches!(&s.0, FallbackInner::Second(s) if matches!(s.0, FallbackInner::First(_))),
+        );
+        check_variant(
+            [0u8; 32],
+            |s| matches!(&s.0, FallbackInner::Second(s) if matches!(s.0, FallbackInner::Second(_))),
+        );
+        assert!(!<Fallback<Raw<4>, Raw<8>> as SharedStorage>::CAPACITY.can_store::<[u8; 16]>());
+        // objects fitting the first storage only don't require the second one to fit them
+        type BySize = Fallback<Raw<32>, Raw<16>>;
+        let object = TestObject::<BySize>::new([0u8; 24]);
+        assert!(matches!(object.storage().0, FallbackInner::First(_)));
+        type ByAlign = Fallback<Raw<16, 16>, Raw<64, 8>>;
+        let object = TestObject::<ByAlign>::new(Align::<16>::NEW);
+        assert!(matches!(object.storage().0, FallbackInner::First(_)));
+        let object = TestObject::<ByAlign>::new([0u8; 48]);
+        assert!(matches!(object.storage().0, FallbackInner::Second(_)));
+        assert_eq!(
+            TestObject::<Fallback<Raw<4>, Raw<8>>>::try_new([0u8; 16]).err(),
+            Some([0u8; 16])
+        );
     }
 
     struct SetDropped<'a>(&'a mut bool);
@@ -401,7 +1607,7 @@ mod tests {
 
     #[test]
     fn storage_drop() {
-        fn check_drop<S: Storage>() {
+        fn check_drop<S: SharedStorage>() {
             let mut dropped = false;
             let storage = TestObject::<S>::new(SetDropped(&mut dropped));
             assert!(!*unsafe { storage.storage().ptr().cast::<SetDropped>().as_ref() }.0);
@@ -415,11 +1621,303 @@ mod tests {
         check_drop::<super::RawOrBox<{ size_of::<SetDropped>() }>>();
         #[cfg(feature = "alloc")]
         check_drop::<super::RawOrBox<0>>();
+        #[cfg(feature = "alloc")]
+        check_drop::<super::Rc>();
+        #[cfg(feature = "alloc")]
+        check_drop::<super::Arc>();
+        #[cfg(feature = "allocator-api2")]
+        check_drop::<super::BoxIn>();
+        #[cfg(feature = "allocator-api2")]
+        check_drop::<super::RawOrBoxIn<{ size_of::<SetDropped>() }>>();
+        #[cfg(feature = "allocator-api2")]
+        check_drop::<super::RawOrBoxIn<0>>();
+        #[cfg(feature = "alloc")]
+        check_drop::<super::Pooled<LocalPool>>();
+        #[cfg(feature = "alloc")]
+        check_drop::<super::Pooled<NoPool>>();
+    }
+
+    #[test]
+    fn storage_try_new() {
+        fn check_try_new<S: SharedStorage>(fits: bool) {
+            let mut dropped = false;
+            match TestObject::<S>::try_new(SetDropped(&mut dropped)) {
+                Ok(storage) => {
+                    assert!(fits);
+                    drop(storage);
+                }
+                Err(data) => {
+                    assert!(!fits);
+                    drop(data);
+                }
+            }
+            assert!(dropped);
+        }
+        check_try_new::<super::Raw<{ size_of::<SetDropped>() }>>(true);
+        check_try_new::<super::Raw<0>>(false);
+        check_try_new::<super::Raw<{ size_of::<SetDropped>() }, 1>>(false);
+        check_try_new::<super::RawOrBox<{ size_of::<SetDropped>() }>>(true);
+        #[cfg(feature = "alloc")]
+        check_try_new::<super::RawOrBox<0>>(true);
+        #[cfg(not(feature = "alloc"))]
+        check_try_new::<super::RawOrBox<0>>(false);
+        #[cfg(feature = "alloc")]
+        check_try_new::<super::Box>(true);
+        #[cfg(feature = "alloc")]
+        check_try_new::<super::Rc>(true);
+        #[cfg(feature = "alloc")]
+        check_try_new::<super::Arc>(true);
+        #[cfg(feature = "allocator-api2")]
+        check_try_new::<super::BoxIn>(true);
+        #[cfg(feature = "allocator-api2")]
+        check_try_new::<super::BoxIn<&allocator_api2::alloc::Global>>(false);
+        #[cfg(feature = "allocator-api2")]
+        check_try_new::<super::RawOrBoxIn<0>>(true);
+        check_try_new::<super::Slot<{ size_of::<SetDropped>() }>>(false);
+        #[cfg(feature = "alloc")]
+        check_try_new::<super::Pooled<LocalPool>>(true);
+        #[cfg(feature = "alloc")]
+        drop(TestObject::<super::Box>::try_new(()).ok().unwrap());
+        #[cfg(feature = "alloc")]
+        drop(
+            TestObject::<super::Pooled<LocalPool>>::try_new(())
+                .ok()
+                .unwrap(),
+        );
+        #[cfg(feature = "allocator-api2")]
+        drop(TestObject::<super::BoxIn>::try_new(()).ok().unwrap());
+    }
+
+    #[cfg(feature = "alloc")]
+    struct LocalPool;
+    #[cfg(feature = "alloc")]
+    impl super::PoolHandle for LocalPool {
+        fn with<R>(f: impl FnOnce(&super::Pool) -> R) -> Option<R> {
+            extern crate std;
+            std::thread_local! { static POOL: super::Pool = const { super::Pool::new() }; }
+            POOL.try_with(f).ok()
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    struct NoPool;
+    #[cfg(feature = "alloc")]
+    impl super::PoolHandle for NoPool {
+        fn with<R>(_f: impl FnOnce(&super::Pool) -> R) -> Option<R> {
+            None
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn pool() {
+        type Pooled = super::Pooled<LocalPool>;
+        #[repr(align(32))]
+        struct Aligned;
+        impl Test for Aligned {}
+
+        let first = TestObject::<Pooled>::new([0u8; 100]);
+        let first_ptr = first.storage().ptr();
+        drop(first);
+        let second = TestObject::<Pooled>::new([0u8; 128]);
+        assert_eq!(second.storage().ptr(), first_ptr);
+        let third = TestObject::<Pooled>::new([0u8; 100]);
+        assert_ne!(third.storage().ptr(), first_ptr);
+        drop((second, third));
+        let aligned = TestObject::<Pooled>::new(Aligned);
+        assert!(aligned.storage().ptr().cast::<Aligned>().is_aligned());
+        drop(aligned);
+        let global = TestObject::<super::Pooled<NoPool>>::new([0u8; 100]);
+        drop(global);
+        // a block taken from a pool can be put back into another pool
+        let first = TestObject::<Pooled>::new([0u8; 100]);
+        let pool = super::Pool::new();
+        <LocalPool as super::PoolHandle>::with(super::Pool::clear);
+        let mut first = mem::ManuallyDrop::new(first);
+        let ptr = first.storage_mut().ptr_mut().cast();
+        let layout = core::alloc::Layout::new::<[u8; 100]>();
+        unsafe { pool.dealloc(ptr, layout) };
+        assert_eq!(unsafe { pool.alloc(layout) }, ptr.as_ptr());
+        unsafe { pool.dealloc(ptr, layout) };
+    }
+
+    #[cfg(feature = "allocator-api2")]
+    #[test]
+    fn box_in_try_new_alloc_error() {
+        use core::{alloc::Layout, ptr::NonNull};
+
+        use allocator_api2::alloc::{AllocError, Allocator};
+
+        struct Failing;
+        unsafe impl Allocator for Failing {
+            fn allocate(&self, _layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+                Err(AllocError)
+            }
+            unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {
+                unreachable!()
+            }
+        }
+        impl super::StorageAllocator for Failing {
+            const NEW: Option<fn() -> Self> = Some(|| Failing);
+        }
+
+        assert_eq!(
+            TestObject::<super::BoxIn<Failing>>::try_new(42u64).err(),
+            Some(42)
+        );
+        assert_eq!(
+            TestObject::<super::RawOrBoxIn<0, 8, Failing>>::try_new(42u64).err(),
+            Some(42)
+        );
+        assert!(TestObject::<super::RawOrBoxIn<8, 8, Failing>>::try_new(42u64).is_ok());
+    }
+
+    #[cfg(feature = "allocator-api2")]
+    #[test]
+    fn box_in() {
+        use core::{alloc::Layout, cell::Cell, ptr::NonNull};
+
+        use allocator_api2::{
+            alloc::{AllocError, Allocator, Global},
+            boxed::Box,
+        };
+
+        #[derive(Default)]
+        struct Counting(Cell<usize>);
+        unsafe impl Allocator for Counting {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+                self.0.set(self.0.get() + 1);
+                Global.allocate(layout)
+            }
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                self.0.set(self.0.get() - 1);
+                unsafe { Global.deallocate(ptr, layout) };
+            }
+        }
+
+        let alloc = Counting::default();
+        let mut dropped = false;
+        let storage = TestObject::<super::BoxIn<&Counting>>::from_box_in(Box::new_in(
+            SetDropped(&mut dropped),
+            &alloc,
+        ));
+        assert_eq!(alloc.0.get(), 1);
+        drop(storage);
+        assert!(dropped);
+        assert_eq!(alloc.0.get(), 0);
+
+        let storage = TestObject::<super::RawOrBoxIn<8, 8, &Counting>>::from_box_in(Box::new_in(
+            0u64, &alloc,
+        ));
+        assert!(matches!(
+            storage.storage().0,
+            super::FallbackInner::First(_)
+        ));
+        assert_eq!(alloc.0.get(), 0);
+        let storage = TestObject::<super::RawOrBoxIn<0, 8, &Counting>>::from_box_in(Box::new_in(
+            0u64, &alloc,
+        ));
+        assert!(matches!(
+            storage.storage().0,
+            super::FallbackInner::Second(_)
+        ));
+        assert_eq!(alloc.0.get(), 1);
+        drop(storage);
+        assert_eq!(alloc.0.get(), 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn shared() {
+        use core::sync::atomic::{AtomicBool, Ordering};
+
+        fn check<S: SharedStorage>()
+        where
+            TestObject<'static, S>: Clone,
+        {
+            static DROPPED: AtomicBool = AtomicBool::new(false);
+            struct SetDropped;
+            impl Test for SetDropped {}
+            impl Drop for SetDropped {
+                fn drop(&mut self) {
+                    assert!(!DROPPED.swap(true, Ordering::Relaxed));
+                }
+            }
+            DROPPED.store(false, Ordering::Relaxed);
+            let storage = TestObject::<S>::new(SetDropped);
+            let storage2 = storage.clone();
+            assert_eq!(storage.storage().ptr(), storage2.storage().ptr());
+            drop(storage);
+            assert!(!DROPPED.load(Ordering::Relaxed));
+            drop(storage2);
+            assert!(DROPPED.load(Ordering::Relaxed));
+
+            let storage = TestObject::<S>::new(Align::<64>::NEW);
+            assert!(storage.storage().ptr().cast::<Align<64>>().is_aligned());
+            drop(storage.clone());
+            let storage = TestObject::<S>::new([0u8; 3]);
+            assert!(unsafe { storage.storage().as_ref::<[u8; 3]>() } == &[0; 3]);
+        }
+        check::<super::Rc>();
+        check::<super::Arc>();
+    }
+
+    #[test]
+    fn slot() {
+        let mut dropped = false;
+        {
+            let mut buffer = pin!(super::SlotBuffer::<{ size_of::<SetDropped>() }>::new());
+            let ptr = buffer.data.ptr();
+            let storage = TestObject::new_in(SetDropped(&mut dropped), buffer.as_mut());
+            assert_eq!(storage.storage().ptr(), ptr);
+            drop(storage);
+        }
+        assert!(dropped);
+
+        let (mut dropped1, mut dropped2, mut dropped3) = (false, false, false);
+        {
+            let mut buffer = pin!(super::SlotBuffer::<{ size_of::<SetDropped>() }>::new());
+            mem::forget(TestObject::new_in(
+                SetDropped(&mut dropped1),
+                buffer.as_mut(),
+            ));
+            drop(TestObject::new_in(
+                SetDropped(&mut dropped2),
+                buffer.as_mut(),
+            ));
+            mem::forget(TestObject::new_in(
+                SetDropped(&mut dropped3),
+                buffer.as_mut(),
+            ));
+        }
+        assert!(dropped1 && dropped2 && dropped3);
+    }
+
+    #[test]
+    fn slot_leak() {
+        struct ReadOnDrop<'a>(&'a [u8; 4], &'a mut bool);
+        impl Test for ReadOnDrop<'_> {}
+        impl Drop for ReadOnDrop<'_> {
+            fn drop(&mut self) {
+                *self.1 = self.0 == b"data";
+            }
+        }
+        let mut dropped = false;
+        {
+            // the borrowed local must be declared before the buffer, so it is dropped after
+            let local = *b"data";
+            let mut buffer = pin!(super::SlotBuffer::<{ size_of::<ReadOnDrop>() }>::new());
+            mem::forget(TestObject::new_in(
+                ReadOnDrop(&local, &mut dropped),
+                buffer.as_mut(),
+            ));
+        }
+        assert!(dropped);
     }
 
     #[test]
     fn storage_dst() {
-        fn check_dst<S: Storage>() {
+        fn check_dst<S: SharedStorage>() {
             drop(TestObject::<S>::new(()));
         }
         check_dst::<super::Raw<{ size_of::<SetDropped>() }, { align_of::<SetDropped>() }>>();
@@ -429,11 +1927,17 @@ mod tests {
         check_dst::<super::RawOrBox<{ size_of::<SetDropped>() }>>();
         #[cfg(feature = "alloc")]
         check_dst::<super::RawOrBox<0>>();
+        #[cfg(feature = "alloc")]
+        check_dst::<super::Rc>();
+        #[cfg(feature = "alloc")]
+        check_dst::<super::Arc>();
+        #[cfg(feature = "allocator-api2")]
+        check_dst::<super::BoxIn>();
     }
 
     #[test]
     fn storage_interior_mutability() {
-        fn check<S: Storage + core::fmt::Debug>() {
+        fn check<S: super::Storage + core::fmt::Debug>() {
             let obj = DynObject::<dyn Any, S>::new(RefCell::new(false));
             *obj.downcast_ref::<RefCell<bool>>().unwrap().borrow_mut() = true;
             assert!(obj.downcast::<RefCell<bool>>().unwrap().into_inner());
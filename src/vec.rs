@@ -0,0 +1,506 @@
+//! [`DynVec`] implementation.
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error, realloc},
+    vec::Vec,
+};
+use core::{
+    alloc::Layout,
+    fmt,
+    hint::unreachable_unchecked,
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem,
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+    slice,
+};
+
+use crate::{
+    DynObject,
+    object::{DynTrait, Vtable},
+    storage::{Capacity, Storage},
+};
+
+/// A growable collection of trait objects, whose data are packed back-to-back in a single
+/// allocated buffer.
+///
+/// Contrary to a `Vec` of [`DynObject`], objects of different sizes don't waste space,
+/// and large objects are not allocated individually. Elements are accessed through
+/// [`ElementRef`]/[`ElementMut`] handles, which dereference to a `DynObject` and thus
+/// implement the trait.
+///
+/// Elements are moved when the buffer grows, so they cannot be pinned unless they are `Unpin`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use dyn_utils::DynVec;
+/// let mut iterators = DynVec::<dyn Iterator<Item = usize>>::new();
+/// iterators.push(0..2);
+/// iterators.push([42].into_iter());
+/// let items = iterators
+///     .iter_mut()
+///     .flat_map(|mut it| it.next())
+///     .collect::<Vec<_>>();
+/// assert_eq!(items, [0, 42]);
+/// ```
+pub struct DynVec<Dyn: DynTrait + ?Sized> {
+    buffer: NonNull<u8>,
+    layout: Layout,
+    len_bytes: usize,
+    elements: Vec<(usize, &'static Dyn::Vtable)>,
+    _phantom: PhantomData<Dyn>,
+}
+
+// SAFETY: DynVec owns its elements like a `Vec<Box<Dyn>>`
+unsafe impl<Dyn: Send + DynTrait + ?Sized> Send for DynVec<Dyn> {}
+
+// SAFETY: DynVec owns its elements like a `Vec<Box<Dyn>>`
+unsafe impl<Dyn: Sync + DynTrait + ?Sized> Sync for DynVec<Dyn> {}
+
+impl<Dyn: DynTrait + ?Sized> DynVec<Dyn> {
+    /// Constructs a new, empty `DynVec`, without allocating.
+    pub const fn new() -> Self {
+        Self {
+            buffer: NonNull::dangling(),
+            layout: Layout::new::<()>(),
+            len_bytes: 0,
+            elements: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Constructs a new, empty `DynVec`, with room for at least `elements` elements, and a
+    /// buffer of at least `bytes` bytes.
+    pub fn with_capacity(elements: usize, bytes: usize) -> Self {
+        let mut vec = Self::new();
+        vec.elements.reserve(elements);
+        vec.reserve(bytes, 1);
+        vec
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns `true` if there is no element.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the size in bytes of the buffer.
+    pub fn capacity(&self) -> usize {
+        self.layout.size()
+    }
+
+    /// Appends an object implementing the trait at the end of the collection.
+    pub fn push<T>(&mut self, object: T)
+    where
+        Dyn: Vtable<T>,
+    {
+        let layout = Layout::new::<T>();
+        let offset = self.len_bytes.next_multiple_of(layout.align());
+        let end = (offset.checked_add(layout.size())).expect("capacity overflow");
+        self.reserve(end, layout.align());
+        self.elements
+            .push((offset, Dyn::vtable::<Element<'static>>()));
+        // SAFETY: the buffer has been reserved for `T` at `offset`, which is aligned for `T`
+        // as the buffer is aligned with the largest alignment
+        unsafe { self.buffer.add(offset).cast::<T>().write(object) };
+        self.len_bytes = end;
+    }
+
+    /// Returns a handle to the element at `index`, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<ElementRef<'_, Dyn>> {
+        let &(offset, vtable) = self.elements.get(index)?;
+        // SAFETY: the element is initialized, and borrowed immutably with `self`
+        Some(unsafe { ElementRef::new(self.buffer, offset, vtable) })
+    }
+
+    /// Returns a mutable handle to the element at `index`, or `None` if out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<ElementMut<'_, Dyn>> {
+        let &(offset, vtable) = self.elements.get(index)?;
+        // SAFETY: the element is initialized, and borrowed mutably with `self`
+        Some(unsafe { ElementMut::new(self.buffer, offset, vtable) })
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> Iter<'_, Dyn> {
+        Iter {
+            buffer: self.buffer,
+            elements: self.elements.iter(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the elements, allowing their modification.
+    pub fn iter_mut(&mut self) -> IterMut<'_, Dyn> {
+        IterMut {
+            buffer: self.buffer,
+            elements: self.elements.iter(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Drops the elements after the first `len` ones, keeping the buffer allocated.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+        let tail = self.elements.split_off(len);
+        self.len_bytes = tail[0].0;
+        for (offset, vtable) in tail {
+            if let Some(drop_in_place) = Dyn::drop_in_place_fn(vtable) {
+                // SAFETY: the element is initialized, and is no longer accessed after the call
+                // as it has been removed from `self.elements`
+                unsafe { drop_in_place(self.buffer.add(offset).cast()) };
+            }
+        }
+    }
+
+    /// Drops all the elements, keeping the buffer allocated.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Ensures that the buffer has at least `size` bytes, and is aligned to `align`.
+    fn reserve(&mut self, size: usize, align: usize) {
+        if size <= self.layout.size() && align <= self.layout.align() {
+            return;
+        }
+        let align = align.max(self.layout.align());
+        let size = if size > self.layout.size() {
+            size.max(2 * self.layout.size())
+        } else {
+            self.layout.size()
+        };
+        let layout = Layout::from_size_align(size, align).expect("capacity overflow");
+        let buffer = if layout.size() == 0 {
+            NonNull::new(ptr::without_provenance_mut(align)).unwrap()
+        } else if self.layout.size() == 0 {
+            // SAFETY: layout size is non-zero
+            NonNull::new(unsafe { alloc(layout) }).unwrap_or_else(|| handle_alloc_error(layout))
+        } else if layout.align() == self.layout.align() {
+            // SAFETY: the buffer has been allocated with `self.layout`,
+            // and layout size is non-zero and doesn't overflow
+            let ptr = unsafe { realloc(self.buffer.as_ptr(), self.layout, layout.size()) };
+            NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
+        } else {
+            // SAFETY: layout size is non-zero
+            let ptr = unsafe { alloc(layout) };
+            let ptr = NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout));
+            // SAFETY: the new buffer is at least as large as the old one, and
+            // elements remain aligned as the new buffer alignment is larger
+            unsafe { ptr.copy_from_nonoverlapping(self.buffer, self.len_bytes) };
+            // SAFETY: the buffer has been allocated with `self.layout`
+            unsafe { dealloc(self.buffer.as_ptr(), self.layout) };
+            ptr
+        };
+        self.buffer = buffer;
+        self.layout = layout;
+    }
+}
+
+impl<Dyn: DynTrait + ?Sized> Default for DynVec<Dyn> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Dyn: DynTrait + ?Sized> Drop for DynVec<Dyn> {
+    fn drop(&mut self) {
+        self.clear();
+        if self.layout.size() != 0 {
+            // SAFETY: the buffer has been allocated with `self.layout`
+            unsafe { dealloc(self.buffer.as_ptr(), self.layout) };
+        }
+    }
+}
+
+impl<'a, Dyn: DynTrait + ?Sized> IntoIterator for &'a DynVec<Dyn> {
+    type Item = ElementRef<'a, Dyn>;
+    type IntoIter = Iter<'a, Dyn>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, Dyn: DynTrait + ?Sized> IntoIterator for &'a mut DynVec<Dyn> {
+    type Item = ElementMut<'a, Dyn>;
+    type IntoIter = IterMut<'a, Dyn>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<Dyn: DynTrait + ?Sized, T> Extend<T> for DynVec<Dyn>
+where
+    Dyn: Vtable<T>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|object| self.push(object));
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl<Dyn: DynTrait<Vtable: fmt::Debug> + ?Sized> fmt::Debug for DynVec<Dyn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// The storage of [`DynVec`] elements, borrowing the data in the buffer.
+///
+/// It is only used through [`ElementRef`]/[`ElementMut`] handles, and cannot be constructed
+/// with [`Storage::new`], which fails at build time, nor with [`Storage::try_new`], which always
+/// fails. As a consequence, cloning an element object panics.
+#[derive(Debug)]
+pub struct Element<'a>(NonNull<()>, PhantomData<&'a mut ()>);
+
+// SAFETY: `Element` only borrows the data, whose thread-safety is checked by `DynObject`
+unsafe impl Send for Element<'_> {}
+
+// SAFETY: `Element` only borrows the data, whose thread-safety is checked by `DynObject`
+unsafe impl Sync for Element<'_> {}
+
+// SAFETY: `ptr`/`ptr_mut` return a pointer to the stored data, which is borrowed mutably
+// through `ElementMut`, and immutably through `ElementRef`
+unsafe impl Storage for Element<'_> {
+    fn new<T>(_data: T) -> Self {
+        const { panic!("`Element` storage cannot be constructed outside of `DynVec`") }
+    }
+    fn try_new<T>(data: T) -> Result<Self, T> {
+        Err(data)
+    }
+    const CAPACITY: Capacity = Capacity::NONE;
+    unsafe fn new_unchecked<T>(_data: T) -> Self {
+        // SAFETY: `Self::CAPACITY` cannot store anything, so this is never called
+        unsafe { unreachable_unchecked() }
+    }
+    fn ptr(&self) -> NonNull<()> {
+        self.0
+    }
+    fn ptr_mut(&mut self) -> NonNull<()> {
+        self.0
+    }
+    unsafe fn drop_in_place(&mut self, _layout: Layout) {}
+}
+
+/// A handle to an element of a [`DynVec`].
+pub struct ElementRef<'a, Dyn: DynTrait + ?Sized> {
+    object: mem::ManuallyDrop<DynObject<Dyn, Element<'a>>>,
+    _phantom: PhantomData<&'a Dyn>,
+}
+
+impl<'a, Dyn: DynTrait + ?Sized> ElementRef<'a, Dyn> {
+    /// # Safety
+    ///
+    /// The element at `offset` must be initialized with a type matching `vtable`,
+    /// and must be borrowed immutably for `'a`.
+    unsafe fn new(buffer: NonNull<u8>, offset: usize, vtable: &'static Dyn::Vtable) -> Self {
+        // SAFETY: same precondition
+        let storage = Element(unsafe { buffer.add(offset) }.cast(), PhantomData);
+        Self {
+            // SAFETY: `Element` layout and accessors don't depend on its lifetime
+            object: mem::ManuallyDrop::new(unsafe { DynObject::from_raw_parts(storage, vtable) }),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, Dyn: DynTrait + ?Sized> Deref for ElementRef<'a, Dyn> {
+    type Target = DynObject<Dyn, Element<'a>>;
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl<Dyn: DynTrait<Vtable: fmt::Debug> + ?Sized> fmt::Debug for ElementRef<'_, Dyn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.object, f)
+    }
+}
+
+/// A mutable handle to an element of a [`DynVec`].
+pub struct ElementMut<'a, Dyn: DynTrait + ?Sized> {
+    object: mem::ManuallyDrop<DynObject<Dyn, Element<'a>>>,
+    _phantom: PhantomData<&'a mut Dyn>,
+}
+
+impl<'a, Dyn: DynTrait + ?Sized> ElementMut<'a, Dyn> {
+    /// # Safety
+    ///
+    /// The element at `offset` must be initialized with a type matching `vtable`,
+    /// and must be borrowed mutably for `'a`.
+    unsafe fn new(buffer: NonNull<u8>, offset: usize, vtable: &'static Dyn::Vtable) -> Self {
+        // SAFETY: same precondition
+        let storage = Element(unsafe { buffer.add(offset) }.cast(), PhantomData);
+        Self {
+            // SAFETY: `Element` layout and accessors don't depend on its lifetime
+            object: mem::ManuallyDrop::new(unsafe { DynObject::from_raw_parts(storage, vtable) }),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, Dyn: DynTrait + ?Sized> Deref for ElementMut<'a, Dyn> {
+    type Target = DynObject<Dyn, Element<'a>>;
+    fn deref(&self) -> &Self::Target {
+        &self.object
+    }
+}
+
+impl<Dyn: DynTrait + ?Sized> DerefMut for ElementMut<'_, Dyn> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.object
+    }
+}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+impl<Dyn: DynTrait<Vtable: fmt::Debug> + ?Sized> fmt::Debug for ElementMut<'_, Dyn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.object, f)
+    }
+}
+
+/// An iterator over the elements of a [`DynVec`].
+///
+/// This struct is created by [`DynVec::iter`].
+pub struct Iter<'a, Dyn: DynTrait + ?Sized> {
+    buffer: NonNull<u8>,
+    elements: slice::Iter<'a, (usize, &'static Dyn::Vtable)>,
+    _phantom: PhantomData<&'a Dyn>,
+}
+
+impl<'a, Dyn: DynTrait + ?Sized> Iterator for Iter<'a, Dyn> {
+    type Item = ElementRef<'a, Dyn>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let &(offset, vtable) = self.elements.next()?;
+        // SAFETY: the element is initialized, and borrowed immutably with the vector
+        Some(unsafe { ElementRef::new(self.buffer, offset, vtable) })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
+
+impl<Dyn: DynTrait + ?Sized> DoubleEndedIterator for Iter<'_, Dyn> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let &(offset, vtable) = self.elements.next_back()?;
+        // SAFETY: the element is initialized, and borrowed immutably with the vector
+        Some(unsafe { ElementRef::new(self.buffer, offset, vtable) })
+    }
+}
+
+impl<Dyn: DynTrait + ?Sized> ExactSizeIterator for Iter<'_, Dyn> {}
+
+impl<Dyn: DynTrait + ?Sized> FusedIterator for Iter<'_, Dyn> {}
+
+/// A mutable iterator over the elements of a [`DynVec`].
+///
+/// This struct is created by [`DynVec::iter_mut`].
+pub struct IterMut<'a, Dyn: DynTrait + ?Sized> {
+    buffer: NonNull<u8>,
+    elements: slice::Iter<'a, (usize, &'static Dyn::Vtable)>,
+    _phantom: PhantomData<&'a mut Dyn>,
+}
+
+impl<'a, Dyn: DynTrait + ?Sized> Iterator for IterMut<'a, Dyn> {
+    type Item = ElementMut<'a, Dyn>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let &(offset, vtable) = self.elements.next()?;
+        // SAFETY: the element is initialized, borrowed mutably with the vector,
+        // and yielded only once
+        Some(unsafe { ElementMut::new(self.buffer, offset, vtable) })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
+
+impl<Dyn: DynTrait + ?Sized> DoubleEndedIterator for IterMut<'_, Dyn> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let &(offset, vtable) = self.elements.next_back()?;
+        // SAFETY: the element is initialized, borrowed mutably with the vector,
+        // and yielded only once
+        Some(unsafe { ElementMut::new(self.buffer, offset, vtable) })
+    }
+}
+
+impl<Dyn: DynTrait + ?Sized> ExactSizeIterator for IterMut<'_, Dyn> {}
+
+impl<Dyn: DynTrait + ?Sized> FusedIterator for IterMut<'_, Dyn> {}
+
+#[cfg_attr(coverage_nightly, coverage(off))]
+#[cfg(test)]
+mod tests {
+    use alloc::{rc::Rc, string::String, vec::Vec};
+    use core::any::Any;
+
+    use crate::DynVec;
+
+    #[test]
+    fn dyn_vec() {
+        let mut vec = DynVec::<dyn Any>::new();
+        assert!(vec.is_empty());
+        vec.push(());
+        assert_eq!(vec.capacity(), 0);
+        vec.push(0u8);
+        vec.push(42u64);
+        vec.push(String::from("a"));
+        vec.push([0u128; 4]);
+        assert_eq!(vec.len(), 5);
+        assert!(vec.get(0).unwrap().is::<()>());
+        assert_eq!(vec.get(2).unwrap().downcast_ref::<u64>(), Some(&42));
+        *vec.get_mut(1).unwrap().downcast_mut::<u8>().unwrap() += 1;
+        vec.get_mut(3)
+            .unwrap()
+            .downcast_mut::<String>()
+            .unwrap()
+            .push('b');
+        assert_eq!(vec.iter().nth(1).unwrap().downcast_ref::<u8>(), Some(&1));
+        let s = vec.iter_mut().next_back().unwrap();
+        assert_eq!(s.downcast_ref::<[u128; 4]>(), Some(&[0; 4]));
+        assert_eq!(
+            vec.get(3).unwrap().downcast_ref(),
+            Some(&String::from("ab"))
+        );
+        assert!(vec.get(5).is_none());
+        assert_eq!(vec.iter().len(), 5);
+    }
+
+    #[test]
+    fn dyn_vec_drop() {
+        let rc = Rc::new(());
+        let mut vec = DynVec::<dyn Any>::with_capacity(8, 0);
+        vec.extend((0..4).map(|_| Some(rc.clone())));
+        let capacity = vec.capacity();
+        vec.truncate(5);
+        vec.truncate(3);
+        assert_eq!((vec.len(), Rc::strong_count(&rc)), (3, 4));
+        vec.clear();
+        assert_eq!((vec.len(), Rc::strong_count(&rc)), (0, 1));
+        assert_eq!(vec.capacity(), capacity);
+        vec.push(rc.clone());
+        drop(vec);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn dyn_vec_fn() {
+        let mut vec = DynVec::<dyn FnMut() -> usize>::default();
+        let mut count = 0;
+        vec.push(|| 0);
+        vec.push(move || {
+            count += 1;
+            count
+        });
+        for _ in 0..2 {
+            let results = (&mut vec).into_iter().map(|mut f| f.call_mut());
+            assert_eq!(results.collect::<Vec<_>>().len(), 2);
+        }
+        assert_eq!(vec.get_mut(1).unwrap().call_mut(), 3);
+    }
+}
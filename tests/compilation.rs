@@ -5,4 +5,13 @@ fn compilation() {
     t.compile_fail("tests/compilation/errors.rs");
     // check expansion of impls to keep them up to date with macro modifications
     macrotest::expand("tests/compilation/impls.rs");
+    macrotest::expand("tests/compilation/error.rs");
+    #[cfg(feature = "std")]
+    macrotest::expand("tests/compilation/io.rs");
+    #[cfg(feature = "futures-core")]
+    macrotest::expand("tests/compilation/stream.rs");
+    #[cfg(feature = "futures-io")]
+    macrotest::expand("tests/compilation/async_io.rs");
+    #[cfg(feature = "tokio")]
+    macrotest::expand("tests/compilation/tokio_io.rs");
 }
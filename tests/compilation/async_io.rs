@@ -0,0 +1,47 @@
+#[dyn_utils::dyn_object(crate = crate, remote = futures_io::AsyncRead)]
+#[dyn_utils::dyn_object(crate = crate, remote = futures_io::AsyncRead, bounds = Send)]
+trait AsyncRead {
+    fn poll_read(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        buf: &mut [u8],
+    ) -> core::task::Poll<futures_io::Result<usize>>;
+    fn poll_read_vectored(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        bufs: &mut [futures_io::IoSliceMut<'_>],
+    ) -> core::task::Poll<futures_io::Result<usize>>;
+}
+
+#[dyn_utils::dyn_object(crate = crate, remote = futures_io::AsyncWrite)]
+#[dyn_utils::dyn_object(crate = crate, remote = futures_io::AsyncWrite, bounds = Send)]
+trait AsyncWrite {
+    fn poll_write(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        buf: &[u8],
+    ) -> core::task::Poll<futures_io::Result<usize>>;
+    fn poll_write_vectored(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        bufs: &[futures_io::IoSlice<'_>],
+    ) -> core::task::Poll<futures_io::Result<usize>>;
+    fn poll_flush(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<futures_io::Result<()>>;
+    fn poll_close(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<futures_io::Result<()>>;
+}
+
+#[dyn_utils::dyn_object(crate = crate, remote = futures_io::AsyncBufRead, supertraits = futures_io::AsyncRead)]
+#[dyn_utils::dyn_object(crate = crate, remote = futures_io::AsyncBufRead, bounds = Send, supertraits = futures_io::AsyncRead)]
+trait AsyncBufRead: futures_io::AsyncRead {
+    fn poll_fill_buf<'a>(
+        self: core::pin::Pin<&'a mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<futures_io::Result<&'a [u8]>>;
+    fn consume(self: core::pin::Pin<&mut Self>, amt: usize);
+}
@@ -0,0 +1,1487 @@
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fmt: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::debug_struct_field4_finish(
+                f,
+                "__Vtable",
+                "__drop_in_place",
+                &self.__drop_in_place,
+                "__layout",
+                &self.__layout,
+                "__unsize",
+                &self.__unsize,
+                "fmt",
+                &&self.fmt,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::fmt::Debug + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<'__lt, __Dyn: core::fmt::Debug + '__lt> crate::object::Vtable<__Dyn>
+    for dyn core::fmt::Debug + '__lt {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    fmt: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &__Storage,
+                                &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result,
+                            unsafe fn(),
+                        >(|__self, f| ::core::mem::transmute(
+                            __Dyn::fmt(__self.as_ref(), ::core::mem::transmute(f)),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn core::fmt::Debug + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn core::fmt::Debug + '__lt, __Storage>,
+    > for crate::__private::Box<dyn core::fmt::Debug + '__lt> {
+        fn from(
+            object: crate::DynObject<dyn core::fmt::Debug + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::fmt::Debug + '__target>
+    for dyn core::fmt::Debug + '__lt {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::fmt::Debug + '__target>
+    for dyn core::fmt::Debug + '__lt + Send {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::fmt::Debug + '__target>
+    for dyn core::fmt::Debug + '__lt + Send + Sync {}
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Debug
+    for crate::DynObject<dyn core::fmt::Debug + '__lt, __Storage> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Debug
+    for crate::ThinDynObject<dyn core::fmt::Debug + '__lt, __Storage> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fmt: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::debug_struct_field4_finish(
+                f,
+                "__Vtable",
+                "__drop_in_place",
+                &self.__drop_in_place,
+                "__layout",
+                &self.__layout,
+                "__unsize",
+                &self.__unsize,
+                "fmt",
+                &&self.fmt,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::fmt::Debug + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: core::fmt::Debug + '__lt + Send,
+    > crate::object::Vtable<__Dyn> for dyn core::fmt::Debug + '__lt + Send {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    fmt: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &__Storage,
+                                &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result,
+                            unsafe fn(),
+                        >(|__self, f| ::core::mem::transmute(
+                            __Dyn::fmt(__self.as_ref(), ::core::mem::transmute(f)),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn core::fmt::Debug + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn core::fmt::Debug + '__lt + Send, __Storage>,
+    > for crate::__private::Box<dyn core::fmt::Debug + '__lt + Send> {
+        fn from(
+            object: crate::DynObject<dyn core::fmt::Debug + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::fmt::Debug + '__target + Send>
+    for dyn core::fmt::Debug + '__lt + Send {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::fmt::Debug + '__target + Send>
+    for dyn core::fmt::Debug + '__lt + Send + Sync {}
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Debug
+    for crate::DynObject<dyn core::fmt::Debug + '__lt + Send, __Storage> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Debug
+    for crate::ThinDynObject<dyn core::fmt::Debug + '__lt + Send, __Storage> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fmt: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::debug_struct_field4_finish(
+                f,
+                "__Vtable",
+                "__drop_in_place",
+                &self.__drop_in_place,
+                "__layout",
+                &self.__layout,
+                "__unsize",
+                &self.__unsize,
+                "fmt",
+                &&self.fmt,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait
+    for dyn core::fmt::Debug + '__lt + Send + Sync {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: core::fmt::Debug + '__lt + Send + Sync,
+    > crate::object::Vtable<__Dyn> for dyn core::fmt::Debug + '__lt + Send + Sync {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    fmt: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &__Storage,
+                                &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result,
+                            unsafe fn(),
+                        >(|__self, f| ::core::mem::transmute(
+                            __Dyn::fmt(__self.as_ref(), ::core::mem::transmute(f)),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn core::fmt::Debug + '__lt + Send + Sync {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn core::fmt::Debug + '__lt + Send + Sync, __Storage>,
+    > for crate::__private::Box<dyn core::fmt::Debug + '__lt + Send + Sync> {
+        fn from(
+            object: crate::DynObject<
+                dyn core::fmt::Debug + '__lt + Send + Sync,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::fmt::Debug + '__target + Send + Sync>
+    for dyn core::fmt::Debug + '__lt + Send + Sync {}
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Debug
+    for crate::DynObject<dyn core::fmt::Debug + '__lt + Send + Sync, __Storage> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Debug
+    for crate::ThinDynObject<dyn core::fmt::Debug + '__lt + Send + Sync, __Storage> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fmt: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::debug_struct_field4_finish(
+                f,
+                "__Vtable",
+                "__drop_in_place",
+                &self.__drop_in_place,
+                "__layout",
+                &self.__layout,
+                "__unsize",
+                &self.__unsize,
+                "fmt",
+                &&self.fmt,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::fmt::Display + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: core::fmt::Display + '__lt,
+    > crate::object::Vtable<__Dyn> for dyn core::fmt::Display + '__lt {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    fmt: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &__Storage,
+                                &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result,
+                            unsafe fn(),
+                        >(|__self, f| ::core::mem::transmute(
+                            __Dyn::fmt(__self.as_ref(), ::core::mem::transmute(f)),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn core::fmt::Display + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn core::fmt::Display + '__lt, __Storage>,
+    > for crate::__private::Box<dyn core::fmt::Display + '__lt> {
+        fn from(
+            object: crate::DynObject<dyn core::fmt::Display + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::fmt::Display + '__target>
+    for dyn core::fmt::Display + '__lt {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::fmt::Display + '__target>
+    for dyn core::fmt::Display + '__lt + Send {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::fmt::Display + '__target>
+    for dyn core::fmt::Display + '__lt + Send + Sync {}
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Display
+    for crate::DynObject<dyn core::fmt::Display + '__lt, __Storage> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Display
+    for crate::ThinDynObject<dyn core::fmt::Display + '__lt, __Storage> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fmt: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::debug_struct_field4_finish(
+                f,
+                "__Vtable",
+                "__drop_in_place",
+                &self.__drop_in_place,
+                "__layout",
+                &self.__layout,
+                "__unsize",
+                &self.__unsize,
+                "fmt",
+                &&self.fmt,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::fmt::Display + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: core::fmt::Display + '__lt + Send,
+    > crate::object::Vtable<__Dyn> for dyn core::fmt::Display + '__lt + Send {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    fmt: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &__Storage,
+                                &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result,
+                            unsafe fn(),
+                        >(|__self, f| ::core::mem::transmute(
+                            __Dyn::fmt(__self.as_ref(), ::core::mem::transmute(f)),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn core::fmt::Display + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn core::fmt::Display + '__lt + Send, __Storage>,
+    > for crate::__private::Box<dyn core::fmt::Display + '__lt + Send> {
+        fn from(
+            object: crate::DynObject<
+                dyn core::fmt::Display + '__lt + Send,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::fmt::Display + '__target + Send>
+    for dyn core::fmt::Display + '__lt + Send {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::fmt::Display + '__target + Send>
+    for dyn core::fmt::Display + '__lt + Send + Sync {}
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Display
+    for crate::DynObject<dyn core::fmt::Display + '__lt + Send, __Storage> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Display
+    for crate::ThinDynObject<dyn core::fmt::Display + '__lt + Send, __Storage> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fmt: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::debug_struct_field4_finish(
+                f,
+                "__Vtable",
+                "__drop_in_place",
+                &self.__drop_in_place,
+                "__layout",
+                &self.__layout,
+                "__unsize",
+                &self.__unsize,
+                "fmt",
+                &&self.fmt,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait
+    for dyn core::fmt::Display + '__lt + Send + Sync {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: core::fmt::Display + '__lt + Send + Sync,
+    > crate::object::Vtable<__Dyn> for dyn core::fmt::Display + '__lt + Send + Sync {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    fmt: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &__Storage,
+                                &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result,
+                            unsafe fn(),
+                        >(|__self, f| ::core::mem::transmute(
+                            __Dyn::fmt(__self.as_ref(), ::core::mem::transmute(f)),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn core::fmt::Display + '__lt + Send + Sync {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn core::fmt::Display + '__lt + Send + Sync, __Storage>,
+    > for crate::__private::Box<dyn core::fmt::Display + '__lt + Send + Sync> {
+        fn from(
+            object: crate::DynObject<
+                dyn core::fmt::Display + '__lt + Send + Sync,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::fmt::Display + '__target + Send + Sync>
+    for dyn core::fmt::Display + '__lt + Send + Sync {}
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::fmt::Display
+    for crate::DynObject<dyn core::fmt::Display + '__lt + Send + Sync, __Storage> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::fmt::Display
+    for crate::ThinDynObject<
+        dyn core::fmt::Display + '__lt + Send + Sync,
+        __Storage,
+    > {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage, &mut core::fmt::Formatter<'_>) -> core::fmt::Result,
+                >(self.vtable().fmt)(self.storage(), f)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        source: unsafe fn(),
+        __upcast0: unsafe fn(),
+        __upcast1: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "source",
+                "__upcast0",
+                "__upcast1",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.source,
+                &self.__upcast0,
+                &&self.__upcast1,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::error::Error + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: core::error::Error + '__lt,
+    > crate::object::Vtable<__Dyn> for dyn core::error::Error + '__lt {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    source: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &__Storage,
+                            ) -> Option<&(dyn core::error::Error + 'static)>,
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::source(__self.as_ref()),
+                        ))
+                    },
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Debug + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    __upcast1: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Display + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn core::error::Error + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn core::error::Error + '__lt, __Storage>,
+    > for crate::__private::Box<dyn core::error::Error + '__lt> {
+        fn from(
+            object: crate::DynObject<dyn core::error::Error + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Debug + '__lt>
+    for dyn core::error::Error + '__lt {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Display + '__lt>
+    for dyn core::error::Error + '__lt {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast1)()
+            }
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::error::Error + '__target>
+    for dyn core::error::Error + '__lt {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::error::Error + '__target>
+    for dyn core::error::Error + '__lt + Send {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::error::Error + '__target>
+    for dyn core::error::Error + '__lt + Send + Sync {}
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::error::Error
+    for crate::DynObject<dyn core::error::Error + '__lt, __Storage>
+    where
+        Self: core::fmt::Debug + core::fmt::Display,
+    {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                >(self.vtable().source)(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::error::Error
+    for crate::ThinDynObject<dyn core::error::Error + '__lt, __Storage>
+    where
+        Self: core::fmt::Debug + core::fmt::Display,
+    {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                >(self.vtable().source)(self.storage())
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        source: unsafe fn(),
+        __upcast0: unsafe fn(),
+        __upcast1: unsafe fn(),
+        __upcast2: unsafe fn(),
+        __upcast3: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "source",
+                "__upcast0",
+                "__upcast1",
+                "__upcast2",
+                "__upcast3",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.source,
+                &self.__upcast0,
+                &self.__upcast1,
+                &self.__upcast2,
+                &&self.__upcast3,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn core::error::Error + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: core::error::Error + '__lt + Send,
+    > crate::object::Vtable<__Dyn> for dyn core::error::Error + '__lt + Send {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    source: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &__Storage,
+                            ) -> Option<&(dyn core::error::Error + 'static)>,
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::source(__self.as_ref()),
+                        ))
+                    },
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Debug + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    __upcast1: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Display + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    __upcast2: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Debug + '__lt + Send as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Debug + '__lt + Send as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    __upcast3: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Display + '__lt + Send as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Display + '__lt + Send as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn core::error::Error + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn core::error::Error + '__lt + Send, __Storage>,
+    > for crate::__private::Box<dyn core::error::Error + '__lt + Send> {
+        fn from(
+            object: crate::DynObject<
+                dyn core::error::Error + '__lt + Send,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Debug + '__lt>
+    for dyn core::error::Error + '__lt + Send {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Display + '__lt>
+    for dyn core::error::Error + '__lt + Send {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast1)()
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Debug + '__lt + Send>
+    for dyn core::error::Error + '__lt + Send {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Debug + '__lt + Send as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Debug + '__lt + Send as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast2)()
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Display + '__lt + Send>
+    for dyn core::error::Error + '__lt + Send {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Display + '__lt + Send as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Display + '__lt + Send as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast3)()
+            }
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::error::Error + '__target + Send>
+    for dyn core::error::Error + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::error::Error
+    for crate::DynObject<dyn core::error::Error + '__lt + Send, __Storage>
+    where
+        Self: core::fmt::Debug + core::fmt::Display,
+    {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                >(self.vtable().source)(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::error::Error
+    for crate::ThinDynObject<dyn core::error::Error + '__lt + Send, __Storage>
+    where
+        Self: core::fmt::Debug + core::fmt::Display,
+    {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                >(self.vtable().source)(self.storage())
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        source: unsafe fn(),
+        __upcast0: unsafe fn(),
+        __upcast1: unsafe fn(),
+        __upcast2: unsafe fn(),
+        __upcast3: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "source",
+                "__upcast0",
+                "__upcast1",
+                "__upcast2",
+                "__upcast3",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.source,
+                &self.__upcast0,
+                &self.__upcast1,
+                &self.__upcast2,
+                &&self.__upcast3,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait
+    for dyn core::error::Error + '__lt + Send + Sync {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: core::error::Error + '__lt + Send + Sync,
+    > crate::object::Vtable<__Dyn> for dyn core::error::Error + '__lt + Send + Sync {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    source: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &__Storage,
+                            ) -> Option<&(dyn core::error::Error + 'static)>,
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::source(__self.as_ref()),
+                        ))
+                    },
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Debug + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    __upcast1: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Display + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    __upcast2: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Debug + '__lt + Send + Sync as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Debug + '__lt + Send + Sync as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    __upcast3: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn core::fmt::Display + '__lt + Send + Sync as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn core::fmt::Display + '__lt + Send + Sync as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn core::error::Error + '__lt + Send + Sync {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn core::error::Error + '__lt + Send + Sync, __Storage>,
+    > for crate::__private::Box<dyn core::error::Error + '__lt + Send + Sync> {
+        fn from(
+            object: crate::DynObject<
+                dyn core::error::Error + '__lt + Send + Sync,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Debug + '__lt>
+    for dyn core::error::Error + '__lt + Send + Sync {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Debug + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn core::fmt::Display + '__lt>
+    for dyn core::error::Error + '__lt + Send + Sync {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Display + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast1)()
+            }
+        }
+    }
+    unsafe impl<
+        '__lt,
+    > crate::object::Upcast<dyn core::fmt::Debug + '__lt + Send + Sync>
+    for dyn core::error::Error + '__lt + Send + Sync {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Debug + '__lt + Send + Sync as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Debug + '__lt + Send + Sync as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast2)()
+            }
+        }
+    }
+    unsafe impl<
+        '__lt,
+    > crate::object::Upcast<dyn core::fmt::Display + '__lt + Send + Sync>
+    for dyn core::error::Error + '__lt + Send + Sync {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn core::fmt::Display + '__lt + Send + Sync as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn core::fmt::Display + '__lt + Send + Sync as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast3)()
+            }
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn core::error::Error + '__target + Send + Sync>
+    for dyn core::error::Error + '__lt + Send + Sync {}
+    impl<'__lt, __Storage: crate::storage::SharedStorage> core::error::Error
+    for crate::DynObject<dyn core::error::Error + '__lt + Send + Sync, __Storage>
+    where
+        Self: core::fmt::Debug + core::fmt::Display,
+    {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                >(self.vtable().source)(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> core::error::Error
+    for crate::ThinDynObject<dyn core::error::Error + '__lt + Send + Sync, __Storage>
+    where
+        Self: core::fmt::Debug + core::fmt::Display,
+    {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> Option<&(dyn core::error::Error + 'static)>,
+                >(self.vtable().source)(self.storage())
+            }
+        }
+    }
+};
@@ -0,0 +1,20 @@
+#[dyn_utils::dyn_object(crate = crate, remote = core::fmt::Debug)]
+#[dyn_utils::dyn_object(crate = crate, remote = core::fmt::Debug, bounds = Send)]
+#[dyn_utils::dyn_object(crate = crate, remote = core::fmt::Debug, bounds = Send + Sync)]
+trait Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
+}
+
+#[dyn_utils::dyn_object(crate = crate, remote = core::fmt::Display)]
+#[dyn_utils::dyn_object(crate = crate, remote = core::fmt::Display, bounds = Send)]
+#[dyn_utils::dyn_object(crate = crate, remote = core::fmt::Display, bounds = Send + Sync)]
+trait Display {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
+}
+
+#[dyn_utils::dyn_object(crate = crate, remote = core::error::Error, supertraits = core::fmt::Debug + core::fmt::Display)]
+#[dyn_utils::dyn_object(crate = crate, remote = core::error::Error, bounds = Send, supertraits = core::fmt::Debug + core::fmt::Display)]
+#[dyn_utils::dyn_object(crate = crate, remote = core::error::Error, bounds = Send + Sync, supertraits = core::fmt::Debug + core::fmt::Display)]
+trait Error: core::fmt::Debug + core::fmt::Display {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)>;
+}
@@ -23,6 +23,11 @@ trait InvalidRemote {
     fn method(&self);
 }
 
+#[dyn_utils::dyn_trait(storage = dyn_utils::storage::Box)]
+trait TraitStorage {
+    fn method(&self) -> impl Iterator<Item = u8>;
+}
+
 #[dyn_utils::dyn_trait]
 trait AttributeOnNormalMethod {
     #[dyn_trait()]
@@ -65,6 +70,29 @@ trait UnknownAttribute2 {
     fn method(&self);
 }
 
+#[dyn_utils::dyn_trait]
+trait InlineWithoutType {
+    #[dyn_trait(maybe_inline)]
+    fn method(&self) -> impl Future<Output = ()>;
+}
+
+#[dyn_utils::dyn_trait]
+trait InlineAndSync {
+    #[dyn_trait(maybe_inline, maybe_sync)]
+    async fn method(&self);
+}
+
+trait InlinedOnImplTrait {
+    fn method(&self) -> impl Iterator<Item = ()>;
+}
+
+impl InlinedOnImplTrait for () {
+    #[dyn_utils::inlined]
+    fn method(&self) -> impl Iterator<Item = ()> {
+        core::iter::empty()
+    }
+}
+
 trait SyncOnSyncMethod {
     fn method(&self);
 }
@@ -97,6 +125,20 @@ impl ForCoverage for () {
     async fn future(&self) {}
 }
 
+#[dyn_utils::dyn_object(supertraits = Debug)]
+trait NotASupertrait: Clone {
+    fn method(&self);
+}
+
+#[dyn_utils::dyn_object]
+trait Mutable {
+    fn mutate(&mut self);
+}
+
+fn shared_mutable(mut object: dyn_utils::DynObject<dyn Mutable, dyn_utils::storage::Rc>) {
+    object.mutate();
+}
+
 trait Remote {
     fn method(&self);
 }
@@ -110,4 +152,45 @@ impl Remote for () {
     fn method(&self) {}
 }
 
+fn slot_outlived_by_buffer() {
+    let mut buffer = core::pin::pin!(dyn_utils::storage::SlotBuffer::<16>::new());
+    let local = 42;
+    let object = dyn_utils::DynObject::<dyn Fn() -> i32 + '_, dyn_utils::storage::Slot<16>>::new_in(
+        || local,
+        buffer.as_mut(),
+    );
+    core::mem::forget(object);
+}
+
+struct NotSendStorage(core::ptr::NonNull<()>);
+
+// SAFETY: never constructed
+unsafe impl dyn_utils::storage::Storage for NotSendStorage {
+    fn new<T>(_data: T) -> Self {
+        unimplemented!()
+    }
+    fn ptr(&self) -> core::ptr::NonNull<()> {
+        self.0
+    }
+    fn ptr_mut(&mut self) -> core::ptr::NonNull<()> {
+        self.0
+    }
+    unsafe fn drop_in_place(&mut self, _layout: core::alloc::Layout) {}
+}
+
+fn not_send_storage(object: dyn_utils::DynObject<dyn Fn() + Send, NotSendStorage>) {
+    fn assert_send<T: Send>(_: T) {}
+    assert_send(object);
+}
+
+#[dyn_utils::dyn_trait]
+trait UnsupportedPinnedImplTrait {
+    fn method(&self) -> core::pin::Pin<Box<impl Future<Output = ()>>>;
+}
+
+#[dyn_utils::dyn_trait]
+trait UnsupportedNestedImplTrait {
+    fn method(&self) -> std::rc::Rc<impl Iterator<Item = ()>>;
+}
+
 fn main() {}
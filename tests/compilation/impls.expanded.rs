@@ -1,20 +1,24 @@
 const _: () = {
+    #[repr(C)]
     pub struct __Vtable {
         __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
         __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
         poll: unsafe fn(),
     }
     #[automatically_derived]
     impl ::core::fmt::Debug for __Vtable {
         #[inline]
         fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-            ::core::fmt::Formatter::debug_struct_field3_finish(
+            ::core::fmt::Formatter::debug_struct_field4_finish(
                 f,
                 "__Vtable",
                 "__drop_in_place",
                 &self.__drop_in_place,
                 "__layout",
                 &self.__layout,
+                "__unsize",
+                &self.__unsize,
                 "poll",
                 &&self.poll,
             )
@@ -37,13 +41,19 @@ const _: () = {
         __TypeOutput,
         __Dyn: Future<Output = __TypeOutput> + '__lt,
     > crate::object::Vtable<__Dyn> for dyn Future<Output = __TypeOutput> + '__lt {
-        fn vtable<__Storage: crate::storage::Storage>() -> &'static Self::Vtable {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
             &const {
                 __Vtable {
                     __drop_in_place: <Self as crate::object::Vtable<
                         __Dyn,
                     >>::DROP_IN_PLACE_FN,
                     __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
                     #[allow(
                         clippy::missing_transmute_annotations,
                         clippy::useless_transmute
@@ -57,7 +67,13 @@ const _: () = {
                             unsafe fn(),
                         >(|__self, cx| ::core::mem::transmute(
                             __Dyn::poll(
-                                __self.as_pinned_mut(),
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
                                 ::core::mem::transmute(cx),
                             ),
                         ))
@@ -66,6 +82,47 @@ const _: () = {
             }
         }
     }
+    unsafe impl<'__lt, __TypeOutput> crate::object::DynUnsize
+    for dyn Future<Output = __TypeOutput> + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __TypeOutput,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt, __Storage>,
+    > for crate::__private::Box<dyn Future<Output = __TypeOutput> + '__lt> {
+        fn from(
+            object: crate::DynObject<
+                dyn Future<Output = __TypeOutput> + '__lt,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+        __TypeOutput,
+    > crate::object::Downgrade<dyn Future<Output = __TypeOutput> + '__target>
+    for dyn Future<Output = __TypeOutput> + '__lt {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+        __TypeOutput,
+    > crate::object::Downgrade<dyn Future<Output = __TypeOutput> + '__target>
+    for dyn Future<Output = __TypeOutput> + '__lt + Send {}
     impl<'__lt, __TypeOutput, __Storage: crate::storage::Storage> Future
     for crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt, __Storage> {
         type Output = __TypeOutput;
@@ -84,24 +141,46 @@ const _: () = {
             }
         }
     }
+    impl<'__lt, __TypeOutput, __Storage: crate::storage::ThinStorage> Future
+    for crate::ThinDynObject<dyn Future<Output = __TypeOutput> + '__lt, __Storage> {
+        type Output = __TypeOutput;
+        fn poll(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<Self::Output> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<Self::Output>,
+                >(self.vtable().poll)(self.storage_pinned_mut(), cx)
+            }
+        }
+    }
 };
 const _: () = {
+    #[repr(C)]
     pub struct __Vtable {
         __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
         __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
         poll: unsafe fn(),
     }
     #[automatically_derived]
     impl ::core::fmt::Debug for __Vtable {
         #[inline]
         fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-            ::core::fmt::Formatter::debug_struct_field3_finish(
+            ::core::fmt::Formatter::debug_struct_field4_finish(
                 f,
                 "__Vtable",
                 "__drop_in_place",
                 &self.__drop_in_place,
                 "__layout",
                 &self.__layout,
+                "__unsize",
+                &self.__unsize,
                 "poll",
                 &&self.poll,
             )
@@ -123,14 +202,21 @@ const _: () = {
         '__lt,
         __TypeOutput,
         __Dyn: Future<Output = __TypeOutput> + '__lt + Send,
-    > crate::object::Vtable<__Dyn> for dyn Future<Output = __TypeOutput> + '__lt + Send {
-        fn vtable<__Storage: crate::storage::Storage>() -> &'static Self::Vtable {
+    > crate::object::Vtable<__Dyn>
+    for dyn Future<Output = __TypeOutput> + '__lt + Send {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
             &const {
                 __Vtable {
                     __drop_in_place: <Self as crate::object::Vtable<
                         __Dyn,
                     >>::DROP_IN_PLACE_FN,
                     __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
                     #[allow(
                         clippy::missing_transmute_annotations,
                         clippy::useless_transmute
@@ -144,7 +230,13 @@ const _: () = {
                             unsafe fn(),
                         >(|__self, cx| ::core::mem::transmute(
                             __Dyn::poll(
-                                __self.as_pinned_mut(),
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
                                 ::core::mem::transmute(cx),
                             ),
                         ))
@@ -153,8 +245,67 @@ const _: () = {
             }
         }
     }
+    unsafe impl<'__lt, __TypeOutput> crate::object::DynUnsize
+    for dyn Future<Output = __TypeOutput> + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __TypeOutput,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt + Send, __Storage>,
+    > for crate::__private::Box<dyn Future<Output = __TypeOutput> + '__lt + Send> {
+        fn from(
+            object: crate::DynObject<
+                dyn Future<Output = __TypeOutput> + '__lt + Send,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+        __TypeOutput,
+    > crate::object::Downgrade<dyn Future<Output = __TypeOutput> + '__target + Send>
+    for dyn Future<Output = __TypeOutput> + '__lt + Send {}
     impl<'__lt, __TypeOutput, __Storage: crate::storage::Storage> Future
-    for crate::DynObject<dyn Future<Output = __TypeOutput> + '__lt + Send, __Storage> {
+    for crate::DynObject<
+        dyn Future<Output = __TypeOutput> + '__lt + Send,
+        __Storage,
+    > {
+        type Output = __TypeOutput;
+        fn poll(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<Self::Output> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<Self::Output>,
+                >(self.vtable().poll)(self.storage_pinned_mut(), cx)
+            }
+        }
+    }
+    impl<'__lt, __TypeOutput, __Storage: crate::storage::ThinStorage> Future
+    for crate::ThinDynObject<
+        dyn Future<Output = __TypeOutput> + '__lt + Send,
+        __Storage,
+    > {
         type Output = __TypeOutput;
         fn poll(
             self: core::pin::Pin<&mut Self>,
@@ -173,9 +324,11 @@ const _: () = {
     }
 };
 const _: () = {
+    #[repr(C)]
     pub struct __Vtable {
         __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
         __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
         next: unsafe fn(),
         size_hint: unsafe fn(),
         nth: unsafe fn(),
@@ -184,19 +337,27 @@ const _: () = {
     impl ::core::fmt::Debug for __Vtable {
         #[inline]
         fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-            ::core::fmt::Formatter::debug_struct_field5_finish(
-                f,
-                "__Vtable",
+            let names: &'static _ = &[
                 "__drop_in_place",
-                &self.__drop_in_place,
                 "__layout",
-                &self.__layout,
+                "__unsize",
                 "next",
-                &self.next,
                 "size_hint",
-                &self.size_hint,
                 "nth",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.next,
+                &self.size_hint,
                 &&self.nth,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
             )
         }
     }
@@ -217,13 +378,19 @@ const _: () = {
         __TypeItem,
         __Dyn: Iterator<Item = __TypeItem> + '__lt,
     > crate::object::Vtable<__Dyn> for dyn Iterator<Item = __TypeItem> + '__lt {
-        fn vtable<__Storage: crate::storage::Storage>() -> &'static Self::Vtable {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
             &const {
                 __Vtable {
                     __drop_in_place: <Self as crate::object::Vtable<
                         __Dyn,
                     >>::DROP_IN_PLACE_FN,
                     __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
                     #[allow(
                         clippy::missing_transmute_annotations,
                         clippy::useless_transmute
@@ -232,7 +399,9 @@ const _: () = {
                         ::core::mem::transmute::<
                             fn(&mut __Storage) -> Option<__Dyn::Item>,
                             unsafe fn(),
-                        >(|__self| ::core::mem::transmute(__Dyn::next(__self.as_mut())))
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::next(__self.ptr_mut().cast::<__Dyn>().as_mut()),
+                        ))
                     },
                     #[allow(
                         clippy::missing_transmute_annotations,
@@ -255,13 +424,57 @@ const _: () = {
                             fn(&mut __Storage, usize) -> Option<__Dyn::Item>,
                             unsafe fn(),
                         >(|__self, n| ::core::mem::transmute(
-                            __Dyn::nth(__self.as_mut(), ::core::mem::transmute(n)),
+                            __Dyn::nth(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(n),
+                            ),
                         ))
                     },
                 }
             }
         }
     }
+    unsafe impl<'__lt, __TypeItem> crate::object::DynUnsize
+    for dyn Iterator<Item = __TypeItem> + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __TypeItem,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn Iterator<Item = __TypeItem> + '__lt, __Storage>,
+    > for crate::__private::Box<dyn Iterator<Item = __TypeItem> + '__lt> {
+        fn from(
+            object: crate::DynObject<
+                dyn Iterator<Item = __TypeItem> + '__lt,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+        __TypeItem,
+    > crate::object::Downgrade<dyn Iterator<Item = __TypeItem> + '__target>
+    for dyn Iterator<Item = __TypeItem> + '__lt {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+        __TypeItem,
+    > crate::object::Downgrade<dyn Iterator<Item = __TypeItem> + '__target>
+    for dyn Iterator<Item = __TypeItem> + '__lt + Send {}
     impl<'__lt, __TypeItem, __Storage: crate::storage::Storage> Iterator
     for crate::DynObject<dyn Iterator<Item = __TypeItem> + '__lt, __Storage> {
         type Item = __TypeItem;
@@ -290,4 +503,239 @@ const _: () = {
             }
         }
     }
+    impl<'__lt, __TypeItem, __Storage: crate::storage::ThinStorage> Iterator
+    for crate::ThinDynObject<dyn Iterator<Item = __TypeItem> + '__lt, __Storage> {
+        type Item = __TypeItem;
+        fn next(&mut self) -> Option<Self::Item> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> Option<Self::Item>,
+                >(self.vtable().next)(self.storage_mut())
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> (usize, Option<usize>),
+                >(self.vtable().size_hint)(self.storage())
+            }
+        }
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, usize) -> Option<Self::Item>,
+                >(self.vtable().nth)(self.storage_mut(), n)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        next: unsafe fn(),
+        size_hint: unsafe fn(),
+        nth: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "next",
+                "size_hint",
+                "nth",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.next,
+                &self.size_hint,
+                &&self.nth,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt, __TypeItem> crate::object::DynTrait
+    for dyn Iterator<Item = __TypeItem> + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __TypeItem,
+        __Dyn: Iterator<Item = __TypeItem> + '__lt + Send,
+    > crate::object::Vtable<__Dyn>
+    for dyn Iterator<Item = __TypeItem> + '__lt + Send {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    next: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> Option<__Dyn::Item>,
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::next(__self.ptr_mut().cast::<__Dyn>().as_mut()),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    size_hint: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage) -> (usize, Option<usize>),
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::size_hint(__self.as_ref()),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    nth: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, usize) -> Option<__Dyn::Item>,
+                            unsafe fn(),
+                        >(|__self, n| ::core::mem::transmute(
+                            __Dyn::nth(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(n),
+                            ),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt, __TypeItem> crate::object::DynUnsize
+    for dyn Iterator<Item = __TypeItem> + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __TypeItem,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn Iterator<Item = __TypeItem> + '__lt + Send, __Storage>,
+    > for crate::__private::Box<dyn Iterator<Item = __TypeItem> + '__lt + Send> {
+        fn from(
+            object: crate::DynObject<
+                dyn Iterator<Item = __TypeItem> + '__lt + Send,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+        __TypeItem,
+    > crate::object::Downgrade<dyn Iterator<Item = __TypeItem> + '__target + Send>
+    for dyn Iterator<Item = __TypeItem> + '__lt + Send {}
+    impl<'__lt, __TypeItem, __Storage: crate::storage::Storage> Iterator
+    for crate::DynObject<dyn Iterator<Item = __TypeItem> + '__lt + Send, __Storage> {
+        type Item = __TypeItem;
+        fn next(&mut self) -> Option<Self::Item> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> Option<Self::Item>,
+                >(self.vtable().next)(self.storage_mut())
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> (usize, Option<usize>),
+                >(self.vtable().size_hint)(self.storage())
+            }
+        }
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, usize) -> Option<Self::Item>,
+                >(self.vtable().nth)(self.storage_mut(), n)
+            }
+        }
+    }
+    impl<'__lt, __TypeItem, __Storage: crate::storage::ThinStorage> Iterator
+    for crate::ThinDynObject<
+        dyn Iterator<Item = __TypeItem> + '__lt + Send,
+        __Storage,
+    > {
+        type Item = __TypeItem;
+        fn next(&mut self) -> Option<Self::Item> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> Option<Self::Item>,
+                >(self.vtable().next)(self.storage_mut())
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> (usize, Option<usize>),
+                >(self.vtable().size_hint)(self.storage())
+            }
+        }
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, usize) -> Option<Self::Item>,
+                >(self.vtable().nth)(self.storage_mut(), n)
+            }
+        }
+    }
 };
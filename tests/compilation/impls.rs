@@ -1,12 +1,4 @@
 #[dyn_utils::dyn_object(crate = crate, remote = Future)]
-trait Future {
-    type Output;
-    fn poll(
-        self: core::pin::Pin<&mut Self>,
-        cx: &mut core::task::Context<'_>,
-    ) -> core::task::Poll<Self::Output>;
-}
-
 #[dyn_utils::dyn_object(crate = crate, remote = Future, bounds = Send)]
 trait Future {
     type Output;
@@ -17,6 +9,7 @@ trait Future {
 }
 
 #[dyn_utils::dyn_object(crate = crate, remote = Iterator)]
+#[dyn_utils::dyn_object(crate = crate, remote = Iterator, bounds = Send)]
 trait Iterator {
     type Item;
     fn next(&mut self) -> Option<Self::Item>;
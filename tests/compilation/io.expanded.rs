@@ -0,0 +1,2047 @@
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        read: unsafe fn(),
+        read_vectored: unsafe fn(),
+        read_to_end: unsafe fn(),
+        read_to_string: unsafe fn(),
+        read_exact: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "read",
+                "read_vectored",
+                "read_to_end",
+                "read_to_string",
+                "read_exact",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.read,
+                &self.read_vectored,
+                &self.read_to_end,
+                &self.read_to_string,
+                &&self.read_exact,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::Read + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<'__lt, __Dyn: std::io::Read + '__lt> crate::object::Vtable<__Dyn>
+    for dyn std::io::Read + '__lt {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut [u8]) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::read(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                &mut [std::io::IoSliceMut<'_>],
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, bufs| ::core::mem::transmute(
+                            __Dyn::read_vectored(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(bufs),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read_to_end: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                &mut std::vec::Vec<u8>,
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::read_to_end(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read_to_string: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                &mut std::string::String,
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::read_to_string(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read_exact: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut [u8]) -> std::io::Result<()>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::read_exact(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn std::io::Read + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<crate::DynObject<dyn std::io::Read + '__lt, __Storage>>
+    for crate::__private::Box<dyn std::io::Read + '__lt> {
+        fn from(
+            object: crate::DynObject<dyn std::io::Read + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn std::io::Read + '__target>
+    for dyn std::io::Read + '__lt {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn std::io::Read + '__target>
+    for dyn std::io::Read + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::Read
+    for crate::DynObject<dyn std::io::Read + '__lt, __Storage> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<usize>,
+                >(self.vtable().read)(self.storage_mut(), buf)
+            }
+        }
+        fn read_vectored(
+            &mut self,
+            bufs: &mut [std::io::IoSliceMut<'_>],
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        &mut [std::io::IoSliceMut<'_>],
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn read_to_end(
+            &mut self,
+            buf: &mut std::vec::Vec<u8>,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                >(self.vtable().read_to_end)(self.storage_mut(), buf)
+            }
+        }
+        fn read_to_string(
+            &mut self,
+            buf: &mut std::string::String,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        &mut std::string::String,
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_to_string)(self.storage_mut(), buf)
+            }
+        }
+        fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<()>,
+                >(self.vtable().read_exact)(self.storage_mut(), buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::Read
+    for crate::ThinDynObject<dyn std::io::Read + '__lt, __Storage> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<usize>,
+                >(self.vtable().read)(self.storage_mut(), buf)
+            }
+        }
+        fn read_vectored(
+            &mut self,
+            bufs: &mut [std::io::IoSliceMut<'_>],
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        &mut [std::io::IoSliceMut<'_>],
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn read_to_end(
+            &mut self,
+            buf: &mut std::vec::Vec<u8>,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                >(self.vtable().read_to_end)(self.storage_mut(), buf)
+            }
+        }
+        fn read_to_string(
+            &mut self,
+            buf: &mut std::string::String,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        &mut std::string::String,
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_to_string)(self.storage_mut(), buf)
+            }
+        }
+        fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<()>,
+                >(self.vtable().read_exact)(self.storage_mut(), buf)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        read: unsafe fn(),
+        read_vectored: unsafe fn(),
+        read_to_end: unsafe fn(),
+        read_to_string: unsafe fn(),
+        read_exact: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "read",
+                "read_vectored",
+                "read_to_end",
+                "read_to_string",
+                "read_exact",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.read,
+                &self.read_vectored,
+                &self.read_to_end,
+                &self.read_to_string,
+                &&self.read_exact,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::Read + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: std::io::Read + '__lt + Send,
+    > crate::object::Vtable<__Dyn> for dyn std::io::Read + '__lt + Send {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut [u8]) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::read(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                &mut [std::io::IoSliceMut<'_>],
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, bufs| ::core::mem::transmute(
+                            __Dyn::read_vectored(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(bufs),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read_to_end: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                &mut std::vec::Vec<u8>,
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::read_to_end(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read_to_string: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                &mut std::string::String,
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::read_to_string(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read_exact: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &mut [u8]) -> std::io::Result<()>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::read_exact(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn std::io::Read + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn std::io::Read + '__lt + Send, __Storage>,
+    > for crate::__private::Box<dyn std::io::Read + '__lt + Send> {
+        fn from(
+            object: crate::DynObject<dyn std::io::Read + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn std::io::Read + '__target + Send>
+    for dyn std::io::Read + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::Read
+    for crate::DynObject<dyn std::io::Read + '__lt + Send, __Storage> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<usize>,
+                >(self.vtable().read)(self.storage_mut(), buf)
+            }
+        }
+        fn read_vectored(
+            &mut self,
+            bufs: &mut [std::io::IoSliceMut<'_>],
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        &mut [std::io::IoSliceMut<'_>],
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn read_to_end(
+            &mut self,
+            buf: &mut std::vec::Vec<u8>,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                >(self.vtable().read_to_end)(self.storage_mut(), buf)
+            }
+        }
+        fn read_to_string(
+            &mut self,
+            buf: &mut std::string::String,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        &mut std::string::String,
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_to_string)(self.storage_mut(), buf)
+            }
+        }
+        fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<()>,
+                >(self.vtable().read_exact)(self.storage_mut(), buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::Read
+    for crate::ThinDynObject<dyn std::io::Read + '__lt + Send, __Storage> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<usize>,
+                >(self.vtable().read)(self.storage_mut(), buf)
+            }
+        }
+        fn read_vectored(
+            &mut self,
+            bufs: &mut [std::io::IoSliceMut<'_>],
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        &mut [std::io::IoSliceMut<'_>],
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn read_to_end(
+            &mut self,
+            buf: &mut std::vec::Vec<u8>,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut std::vec::Vec<u8>) -> std::io::Result<usize>,
+                >(self.vtable().read_to_end)(self.storage_mut(), buf)
+            }
+        }
+        fn read_to_string(
+            &mut self,
+            buf: &mut std::string::String,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        &mut std::string::String,
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_to_string)(self.storage_mut(), buf)
+            }
+        }
+        fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &mut [u8]) -> std::io::Result<()>,
+                >(self.vtable().read_exact)(self.storage_mut(), buf)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        write: unsafe fn(),
+        write_vectored: unsafe fn(),
+        flush: unsafe fn(),
+        write_all: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "write",
+                "write_vectored",
+                "flush",
+                "write_all",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.write,
+                &self.write_vectored,
+                &self.flush,
+                &&self.write_all,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::Write + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<'__lt, __Dyn: std::io::Write + '__lt> crate::object::Vtable<__Dyn>
+    for dyn std::io::Write + '__lt {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    write: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &[u8]) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::write(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    write_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                &[std::io::IoSlice<'_>],
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, bufs| ::core::mem::transmute(
+                            __Dyn::write_vectored(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(bufs),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    flush: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> std::io::Result<()>,
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::flush(__self.ptr_mut().cast::<__Dyn>().as_mut()),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    write_all: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &[u8]) -> std::io::Result<()>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::write_all(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn std::io::Write + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<crate::DynObject<dyn std::io::Write + '__lt, __Storage>>
+    for crate::__private::Box<dyn std::io::Write + '__lt> {
+        fn from(
+            object: crate::DynObject<dyn std::io::Write + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn std::io::Write + '__target>
+    for dyn std::io::Write + '__lt {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn std::io::Write + '__target>
+    for dyn std::io::Write + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::Write
+    for crate::DynObject<dyn std::io::Write + '__lt, __Storage> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<usize>,
+                >(self.vtable().write)(self.storage_mut(), buf)
+            }
+        }
+        fn write_vectored(
+            &mut self,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[std::io::IoSlice<'_>]) -> std::io::Result<usize>,
+                >(self.vtable().write_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> std::io::Result<()>,
+                >(self.vtable().flush)(self.storage_mut())
+            }
+        }
+        fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<()>,
+                >(self.vtable().write_all)(self.storage_mut(), buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::Write
+    for crate::ThinDynObject<dyn std::io::Write + '__lt, __Storage> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<usize>,
+                >(self.vtable().write)(self.storage_mut(), buf)
+            }
+        }
+        fn write_vectored(
+            &mut self,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[std::io::IoSlice<'_>]) -> std::io::Result<usize>,
+                >(self.vtable().write_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> std::io::Result<()>,
+                >(self.vtable().flush)(self.storage_mut())
+            }
+        }
+        fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<()>,
+                >(self.vtable().write_all)(self.storage_mut(), buf)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        write: unsafe fn(),
+        write_vectored: unsafe fn(),
+        flush: unsafe fn(),
+        write_all: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "write",
+                "write_vectored",
+                "flush",
+                "write_all",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.write,
+                &self.write_vectored,
+                &self.flush,
+                &&self.write_all,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::Write + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: std::io::Write + '__lt + Send,
+    > crate::object::Vtable<__Dyn> for dyn std::io::Write + '__lt + Send {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    write: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &[u8]) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::write(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    write_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                &[std::io::IoSlice<'_>],
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, bufs| ::core::mem::transmute(
+                            __Dyn::write_vectored(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(bufs),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    flush: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> std::io::Result<()>,
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::flush(__self.ptr_mut().cast::<__Dyn>().as_mut()),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    write_all: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, &[u8]) -> std::io::Result<()>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::write_all(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn std::io::Write + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn std::io::Write + '__lt + Send, __Storage>,
+    > for crate::__private::Box<dyn std::io::Write + '__lt + Send> {
+        fn from(
+            object: crate::DynObject<dyn std::io::Write + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn std::io::Write + '__target + Send>
+    for dyn std::io::Write + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::Write
+    for crate::DynObject<dyn std::io::Write + '__lt + Send, __Storage> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<usize>,
+                >(self.vtable().write)(self.storage_mut(), buf)
+            }
+        }
+        fn write_vectored(
+            &mut self,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[std::io::IoSlice<'_>]) -> std::io::Result<usize>,
+                >(self.vtable().write_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> std::io::Result<()>,
+                >(self.vtable().flush)(self.storage_mut())
+            }
+        }
+        fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<()>,
+                >(self.vtable().write_all)(self.storage_mut(), buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::Write
+    for crate::ThinDynObject<dyn std::io::Write + '__lt + Send, __Storage> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<usize>,
+                >(self.vtable().write)(self.storage_mut(), buf)
+            }
+        }
+        fn write_vectored(
+            &mut self,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[std::io::IoSlice<'_>]) -> std::io::Result<usize>,
+                >(self.vtable().write_vectored)(self.storage_mut(), bufs)
+            }
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> std::io::Result<()>,
+                >(self.vtable().flush)(self.storage_mut())
+            }
+        }
+        fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, &[u8]) -> std::io::Result<()>,
+                >(self.vtable().write_all)(self.storage_mut(), buf)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fill_buf: unsafe fn(),
+        consume: unsafe fn(),
+        read_until: unsafe fn(),
+        read_line: unsafe fn(),
+        __upcast0: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "fill_buf",
+                "consume",
+                "read_until",
+                "read_line",
+                "__upcast0",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.fill_buf,
+                &self.consume,
+                &self.read_until,
+                &self.read_line,
+                &&self.__upcast0,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::BufRead + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<'__lt, __Dyn: std::io::BufRead + '__lt> crate::object::Vtable<__Dyn>
+    for dyn std::io::BufRead + '__lt {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    fill_buf: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> std::io::Result<&[u8]>,
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::fill_buf(__self.ptr_mut().cast::<__Dyn>().as_mut()),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    consume: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, usize),
+                            unsafe fn(),
+                        >(|__self, amt| ::core::mem::transmute(
+                            __Dyn::consume(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(amt),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read_until: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                u8,
+                                &mut std::vec::Vec<u8>,
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, byte, buf| ::core::mem::transmute(
+                            __Dyn::read_until(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(byte),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read_line: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                &mut std::string::String,
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::read_line(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn std::io::Read + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn std::io::Read + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn std::io::BufRead + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn std::io::BufRead + '__lt, __Storage>,
+    > for crate::__private::Box<dyn std::io::BufRead + '__lt> {
+        fn from(
+            object: crate::DynObject<dyn std::io::BufRead + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn std::io::Read + '__lt>
+    for dyn std::io::BufRead + '__lt {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn std::io::Read + '__lt as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn std::io::Read + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn std::io::BufRead + '__target>
+    for dyn std::io::BufRead + '__lt {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn std::io::BufRead + '__target>
+    for dyn std::io::BufRead + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::BufRead
+    for crate::DynObject<dyn std::io::BufRead + '__lt, __Storage>
+    where
+        Self: std::io::Read,
+    {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> std::io::Result<&[u8]>,
+                >(self.vtable().fill_buf)(self.storage_mut())
+            }
+        }
+        fn consume(&mut self, amt: usize) {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, usize),
+                >(self.vtable().consume)(self.storage_mut(), amt)
+            }
+        }
+        fn read_until(
+            &mut self,
+            byte: u8,
+            buf: &mut std::vec::Vec<u8>,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        u8,
+                        &mut std::vec::Vec<u8>,
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_until)(self.storage_mut(), byte, buf)
+            }
+        }
+        fn read_line(
+            &mut self,
+            buf: &mut std::string::String,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        &mut std::string::String,
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_line)(self.storage_mut(), buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::BufRead
+    for crate::ThinDynObject<dyn std::io::BufRead + '__lt, __Storage>
+    where
+        Self: std::io::Read,
+    {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> std::io::Result<&[u8]>,
+                >(self.vtable().fill_buf)(self.storage_mut())
+            }
+        }
+        fn consume(&mut self, amt: usize) {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, usize),
+                >(self.vtable().consume)(self.storage_mut(), amt)
+            }
+        }
+        fn read_until(
+            &mut self,
+            byte: u8,
+            buf: &mut std::vec::Vec<u8>,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        u8,
+                        &mut std::vec::Vec<u8>,
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_until)(self.storage_mut(), byte, buf)
+            }
+        }
+        fn read_line(
+            &mut self,
+            buf: &mut std::string::String,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        &mut std::string::String,
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_line)(self.storage_mut(), buf)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        fill_buf: unsafe fn(),
+        consume: unsafe fn(),
+        read_until: unsafe fn(),
+        read_line: unsafe fn(),
+        __upcast0: unsafe fn(),
+        __upcast1: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "fill_buf",
+                "consume",
+                "read_until",
+                "read_line",
+                "__upcast0",
+                "__upcast1",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.fill_buf,
+                &self.consume,
+                &self.read_until,
+                &self.read_line,
+                &self.__upcast0,
+                &&self.__upcast1,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::BufRead + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: std::io::BufRead + '__lt + Send,
+    > crate::object::Vtable<__Dyn> for dyn std::io::BufRead + '__lt + Send {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    fill_buf: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> std::io::Result<&[u8]>,
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::fill_buf(__self.ptr_mut().cast::<__Dyn>().as_mut()),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    consume: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage, usize),
+                            unsafe fn(),
+                        >(|__self, amt| ::core::mem::transmute(
+                            __Dyn::consume(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(amt),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read_until: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                u8,
+                                &mut std::vec::Vec<u8>,
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, byte, buf| ::core::mem::transmute(
+                            __Dyn::read_until(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(byte),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    read_line: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                &mut std::string::String,
+                            ) -> std::io::Result<usize>,
+                            unsafe fn(),
+                        >(|__self, buf| ::core::mem::transmute(
+                            __Dyn::read_line(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn std::io::Read + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn std::io::Read + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    __upcast1: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn std::io::Read + '__lt + Send as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn std::io::Read + '__lt + Send as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn std::io::BufRead + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn std::io::BufRead + '__lt + Send, __Storage>,
+    > for crate::__private::Box<dyn std::io::BufRead + '__lt + Send> {
+        fn from(
+            object: crate::DynObject<dyn std::io::BufRead + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn std::io::Read + '__lt>
+    for dyn std::io::BufRead + '__lt + Send {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn std::io::Read + '__lt as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn std::io::Read + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn std::io::Read + '__lt + Send>
+    for dyn std::io::BufRead + '__lt + Send {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn std::io::Read + '__lt + Send as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn std::io::Read + '__lt + Send as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast1)()
+            }
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn std::io::BufRead + '__target + Send>
+    for dyn std::io::BufRead + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::BufRead
+    for crate::DynObject<dyn std::io::BufRead + '__lt + Send, __Storage>
+    where
+        Self: std::io::Read,
+    {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> std::io::Result<&[u8]>,
+                >(self.vtable().fill_buf)(self.storage_mut())
+            }
+        }
+        fn consume(&mut self, amt: usize) {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, usize),
+                >(self.vtable().consume)(self.storage_mut(), amt)
+            }
+        }
+        fn read_until(
+            &mut self,
+            byte: u8,
+            buf: &mut std::vec::Vec<u8>,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        u8,
+                        &mut std::vec::Vec<u8>,
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_until)(self.storage_mut(), byte, buf)
+            }
+        }
+        fn read_line(
+            &mut self,
+            buf: &mut std::string::String,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        &mut std::string::String,
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_line)(self.storage_mut(), buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::BufRead
+    for crate::ThinDynObject<dyn std::io::BufRead + '__lt + Send, __Storage>
+    where
+        Self: std::io::Read,
+    {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> std::io::Result<&[u8]>,
+                >(self.vtable().fill_buf)(self.storage_mut())
+            }
+        }
+        fn consume(&mut self, amt: usize) {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, usize),
+                >(self.vtable().consume)(self.storage_mut(), amt)
+            }
+        }
+        fn read_until(
+            &mut self,
+            byte: u8,
+            buf: &mut std::vec::Vec<u8>,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        u8,
+                        &mut std::vec::Vec<u8>,
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_until)(self.storage_mut(), byte, buf)
+            }
+        }
+        fn read_line(
+            &mut self,
+            buf: &mut std::string::String,
+        ) -> std::io::Result<usize> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        &mut __Storage,
+                        &mut std::string::String,
+                    ) -> std::io::Result<usize>,
+                >(self.vtable().read_line)(self.storage_mut(), buf)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        seek: unsafe fn(),
+        stream_position: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::debug_struct_field5_finish(
+                f,
+                "__Vtable",
+                "__drop_in_place",
+                &self.__drop_in_place,
+                "__layout",
+                &self.__layout,
+                "__unsize",
+                &self.__unsize,
+                "seek",
+                &self.seek,
+                "stream_position",
+                &&self.stream_position,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::Seek + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<'__lt, __Dyn: std::io::Seek + '__lt> crate::object::Vtable<__Dyn>
+    for dyn std::io::Seek + '__lt {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    seek: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                std::io::SeekFrom,
+                            ) -> std::io::Result<u64>,
+                            unsafe fn(),
+                        >(|__self, pos| ::core::mem::transmute(
+                            __Dyn::seek(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(pos),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    stream_position: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> std::io::Result<u64>,
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::stream_position(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                            ),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize for dyn std::io::Seek + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<crate::DynObject<dyn std::io::Seek + '__lt, __Storage>>
+    for crate::__private::Box<dyn std::io::Seek + '__lt> {
+        fn from(
+            object: crate::DynObject<dyn std::io::Seek + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn std::io::Seek + '__target>
+    for dyn std::io::Seek + '__lt {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn std::io::Seek + '__target>
+    for dyn std::io::Seek + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::Seek
+    for crate::DynObject<dyn std::io::Seek + '__lt, __Storage> {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, std::io::SeekFrom) -> std::io::Result<u64>,
+                >(self.vtable().seek)(self.storage_mut(), pos)
+            }
+        }
+        fn stream_position(&mut self) -> std::io::Result<u64> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> std::io::Result<u64>,
+                >(self.vtable().stream_position)(self.storage_mut())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::Seek
+    for crate::ThinDynObject<dyn std::io::Seek + '__lt, __Storage> {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, std::io::SeekFrom) -> std::io::Result<u64>,
+                >(self.vtable().seek)(self.storage_mut(), pos)
+            }
+        }
+        fn stream_position(&mut self) -> std::io::Result<u64> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> std::io::Result<u64>,
+                >(self.vtable().stream_position)(self.storage_mut())
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        seek: unsafe fn(),
+        stream_position: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::debug_struct_field5_finish(
+                f,
+                "__Vtable",
+                "__drop_in_place",
+                &self.__drop_in_place,
+                "__layout",
+                &self.__layout,
+                "__unsize",
+                &self.__unsize,
+                "seek",
+                &self.seek,
+                "stream_position",
+                &&self.stream_position,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn std::io::Seek + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: std::io::Seek + '__lt + Send,
+    > crate::object::Vtable<__Dyn> for dyn std::io::Seek + '__lt + Send {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    seek: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                &mut __Storage,
+                                std::io::SeekFrom,
+                            ) -> std::io::Result<u64>,
+                            unsafe fn(),
+                        >(|__self, pos| ::core::mem::transmute(
+                            __Dyn::seek(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                                ::core::mem::transmute(pos),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    stream_position: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&mut __Storage) -> std::io::Result<u64>,
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::stream_position(
+                                __self.ptr_mut().cast::<__Dyn>().as_mut(),
+                            ),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn std::io::Seek + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn std::io::Seek + '__lt + Send, __Storage>,
+    > for crate::__private::Box<dyn std::io::Seek + '__lt + Send> {
+        fn from(
+            object: crate::DynObject<dyn std::io::Seek + '__lt + Send, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn std::io::Seek + '__target + Send>
+    for dyn std::io::Seek + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> std::io::Seek
+    for crate::DynObject<dyn std::io::Seek + '__lt + Send, __Storage> {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, std::io::SeekFrom) -> std::io::Result<u64>,
+                >(self.vtable().seek)(self.storage_mut(), pos)
+            }
+        }
+        fn stream_position(&mut self) -> std::io::Result<u64> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> std::io::Result<u64>,
+                >(self.vtable().stream_position)(self.storage_mut())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> std::io::Seek
+    for crate::ThinDynObject<dyn std::io::Seek + '__lt + Send, __Storage> {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage, std::io::SeekFrom) -> std::io::Result<u64>,
+                >(self.vtable().seek)(self.storage_mut(), pos)
+            }
+        }
+        fn stream_position(&mut self) -> std::io::Result<u64> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&mut __Storage) -> std::io::Result<u64>,
+                >(self.vtable().stream_position)(self.storage_mut())
+            }
+        }
+    }
+};
@@ -0,0 +1,34 @@
+#[dyn_utils::dyn_object(crate = crate, remote = std::io::Read)]
+#[dyn_utils::dyn_object(crate = crate, remote = std::io::Read, bounds = Send)]
+trait Read {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize>;
+    fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize>;
+    fn read_to_end(&mut self, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize>;
+    fn read_to_string(&mut self, buf: &mut std::string::String) -> std::io::Result<usize>;
+    fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()>;
+}
+
+#[dyn_utils::dyn_object(crate = crate, remote = std::io::Write)]
+#[dyn_utils::dyn_object(crate = crate, remote = std::io::Write, bounds = Send)]
+trait Write {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize>;
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize>;
+    fn flush(&mut self) -> std::io::Result<()>;
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()>;
+}
+
+#[dyn_utils::dyn_object(crate = crate, remote = std::io::BufRead, supertraits = std::io::Read)]
+#[dyn_utils::dyn_object(crate = crate, remote = std::io::BufRead, bounds = Send, supertraits = std::io::Read)]
+trait BufRead: std::io::Read {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]>;
+    fn consume(&mut self, amt: usize);
+    fn read_until(&mut self, byte: u8, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize>;
+    fn read_line(&mut self, buf: &mut std::string::String) -> std::io::Result<usize>;
+}
+
+#[dyn_utils::dyn_object(crate = crate, remote = std::io::Seek)]
+#[dyn_utils::dyn_object(crate = crate, remote = std::io::Seek, bounds = Send)]
+trait Seek {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64>;
+    fn stream_position(&mut self) -> std::io::Result<u64>;
+}
@@ -0,0 +1,10 @@
+#[dyn_utils::dyn_object(crate = crate, remote = futures_core::Stream)]
+#[dyn_utils::dyn_object(crate = crate, remote = futures_core::Stream, bounds = Send)]
+trait Stream {
+    type Item;
+    fn poll_next(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Option<Self::Item>>;
+    fn size_hint(&self) -> (usize, Option<usize>);
+}
@@ -0,0 +1,1530 @@
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_read: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::debug_struct_field4_finish(
+                f,
+                "__Vtable",
+                "__drop_in_place",
+                &self.__drop_in_place,
+                "__layout",
+                &self.__layout,
+                "__unsize",
+                &self.__unsize,
+                "poll_read",
+                &&self.poll_read,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn tokio::io::AsyncRead + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: tokio::io::AsyncRead + '__lt,
+    > crate::object::Vtable<__Dyn> for dyn tokio::io::AsyncRead + '__lt {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    poll_read: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &mut tokio::io::ReadBuf<'_>,
+                            ) -> core::task::Poll<std::io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx, buf| ::core::mem::transmute(
+                            __Dyn::poll_read(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn tokio::io::AsyncRead + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn tokio::io::AsyncRead + '__lt, __Storage>,
+    > for crate::__private::Box<dyn tokio::io::AsyncRead + '__lt> {
+        fn from(
+            object: crate::DynObject<dyn tokio::io::AsyncRead + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn tokio::io::AsyncRead + '__target>
+    for dyn tokio::io::AsyncRead + '__lt {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn tokio::io::AsyncRead + '__target>
+    for dyn tokio::io::AsyncRead + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> tokio::io::AsyncRead
+    for crate::DynObject<dyn tokio::io::AsyncRead + '__lt, __Storage> {
+        fn poll_read(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut tokio::io::ReadBuf<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_read)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> tokio::io::AsyncRead
+    for crate::ThinDynObject<dyn tokio::io::AsyncRead + '__lt, __Storage> {
+        fn poll_read(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut tokio::io::ReadBuf<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_read)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_read: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            ::core::fmt::Formatter::debug_struct_field4_finish(
+                f,
+                "__Vtable",
+                "__drop_in_place",
+                &self.__drop_in_place,
+                "__layout",
+                &self.__layout,
+                "__unsize",
+                &self.__unsize,
+                "poll_read",
+                &&self.poll_read,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn tokio::io::AsyncRead + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: tokio::io::AsyncRead + '__lt + Send,
+    > crate::object::Vtable<__Dyn> for dyn tokio::io::AsyncRead + '__lt + Send {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    poll_read: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &mut tokio::io::ReadBuf<'_>,
+                            ) -> core::task::Poll<std::io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx, buf| ::core::mem::transmute(
+                            __Dyn::poll_read(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn tokio::io::AsyncRead + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn tokio::io::AsyncRead + '__lt + Send, __Storage>,
+    > for crate::__private::Box<dyn tokio::io::AsyncRead + '__lt + Send> {
+        fn from(
+            object: crate::DynObject<
+                dyn tokio::io::AsyncRead + '__lt + Send,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn tokio::io::AsyncRead + '__target + Send>
+    for dyn tokio::io::AsyncRead + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> tokio::io::AsyncRead
+    for crate::DynObject<dyn tokio::io::AsyncRead + '__lt + Send, __Storage> {
+        fn poll_read(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut tokio::io::ReadBuf<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_read)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> tokio::io::AsyncRead
+    for crate::ThinDynObject<dyn tokio::io::AsyncRead + '__lt + Send, __Storage> {
+        fn poll_read(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &mut tokio::io::ReadBuf<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_read)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_write: unsafe fn(),
+        poll_flush: unsafe fn(),
+        poll_shutdown: unsafe fn(),
+        poll_write_vectored: unsafe fn(),
+        is_write_vectored: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "poll_write",
+                "poll_flush",
+                "poll_shutdown",
+                "poll_write_vectored",
+                "is_write_vectored",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.poll_write,
+                &self.poll_flush,
+                &self.poll_shutdown,
+                &self.poll_write_vectored,
+                &&self.is_write_vectored,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn tokio::io::AsyncWrite + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: tokio::io::AsyncWrite + '__lt,
+    > crate::object::Vtable<__Dyn> for dyn tokio::io::AsyncWrite + '__lt {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    poll_write: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &[u8],
+                            ) -> core::task::Poll<std::io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, buf| ::core::mem::transmute(
+                            __Dyn::poll_write(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    poll_flush: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            ) -> core::task::Poll<std::io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx| ::core::mem::transmute(
+                            __Dyn::poll_flush(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    poll_shutdown: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            ) -> core::task::Poll<std::io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx| ::core::mem::transmute(
+                            __Dyn::poll_shutdown(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    poll_write_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &[std::io::IoSlice<'_>],
+                            ) -> core::task::Poll<std::io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, bufs| ::core::mem::transmute(
+                            __Dyn::poll_write_vectored(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(bufs),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    is_write_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage) -> bool,
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::is_write_vectored(__self.as_ref()),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn tokio::io::AsyncWrite + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn tokio::io::AsyncWrite + '__lt, __Storage>,
+    > for crate::__private::Box<dyn tokio::io::AsyncWrite + '__lt> {
+        fn from(
+            object: crate::DynObject<dyn tokio::io::AsyncWrite + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn tokio::io::AsyncWrite + '__target>
+    for dyn tokio::io::AsyncWrite + '__lt {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn tokio::io::AsyncWrite + '__target>
+    for dyn tokio::io::AsyncWrite + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> tokio::io::AsyncWrite
+    for crate::DynObject<dyn tokio::io::AsyncWrite + '__lt, __Storage> {
+        fn poll_write(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &[u8],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[u8],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_flush)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_shutdown(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_shutdown)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_write_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[std::io::IoSlice<'_>],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write_vectored)(self.storage_pinned_mut(), cx, bufs)
+            }
+        }
+        fn is_write_vectored(&self) -> bool {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> bool,
+                >(self.vtable().is_write_vectored)(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> tokio::io::AsyncWrite
+    for crate::ThinDynObject<dyn tokio::io::AsyncWrite + '__lt, __Storage> {
+        fn poll_write(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &[u8],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[u8],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_flush)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_shutdown(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_shutdown)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_write_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[std::io::IoSlice<'_>],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write_vectored)(self.storage_pinned_mut(), cx, bufs)
+            }
+        }
+        fn is_write_vectored(&self) -> bool {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> bool,
+                >(self.vtable().is_write_vectored)(self.storage())
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_write: unsafe fn(),
+        poll_flush: unsafe fn(),
+        poll_shutdown: unsafe fn(),
+        poll_write_vectored: unsafe fn(),
+        is_write_vectored: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "poll_write",
+                "poll_flush",
+                "poll_shutdown",
+                "poll_write_vectored",
+                "is_write_vectored",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.poll_write,
+                &self.poll_flush,
+                &self.poll_shutdown,
+                &self.poll_write_vectored,
+                &&self.is_write_vectored,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait
+    for dyn tokio::io::AsyncWrite + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: tokio::io::AsyncWrite + '__lt + Send,
+    > crate::object::Vtable<__Dyn> for dyn tokio::io::AsyncWrite + '__lt + Send {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    poll_write: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &[u8],
+                            ) -> core::task::Poll<std::io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, buf| ::core::mem::transmute(
+                            __Dyn::poll_write(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(buf),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    poll_flush: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            ) -> core::task::Poll<std::io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx| ::core::mem::transmute(
+                            __Dyn::poll_flush(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    poll_shutdown: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            ) -> core::task::Poll<std::io::Result<()>>,
+                            unsafe fn(),
+                        >(|__self, cx| ::core::mem::transmute(
+                            __Dyn::poll_shutdown(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    poll_write_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(
+                                ::core::pin::Pin<&mut __Storage>,
+                                &mut core::task::Context<'_>,
+                                &[std::io::IoSlice<'_>],
+                            ) -> core::task::Poll<std::io::Result<usize>>,
+                            unsafe fn(),
+                        >(|__self, cx, bufs| ::core::mem::transmute(
+                            __Dyn::poll_write_vectored(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                                ::core::mem::transmute(bufs),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    is_write_vectored: unsafe {
+                        ::core::mem::transmute::<
+                            fn(&__Storage) -> bool,
+                            unsafe fn(),
+                        >(|__self| ::core::mem::transmute(
+                            __Dyn::is_write_vectored(__self.as_ref()),
+                        ))
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn tokio::io::AsyncWrite + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn tokio::io::AsyncWrite + '__lt + Send, __Storage>,
+    > for crate::__private::Box<dyn tokio::io::AsyncWrite + '__lt + Send> {
+        fn from(
+            object: crate::DynObject<
+                dyn tokio::io::AsyncWrite + '__lt + Send,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn tokio::io::AsyncWrite + '__target + Send>
+    for dyn tokio::io::AsyncWrite + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> tokio::io::AsyncWrite
+    for crate::DynObject<dyn tokio::io::AsyncWrite + '__lt + Send, __Storage> {
+        fn poll_write(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &[u8],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[u8],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_flush)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_shutdown(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_shutdown)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_write_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[std::io::IoSlice<'_>],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write_vectored)(self.storage_pinned_mut(), cx, bufs)
+            }
+        }
+        fn is_write_vectored(&self) -> bool {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> bool,
+                >(self.vtable().is_write_vectored)(self.storage())
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> tokio::io::AsyncWrite
+    for crate::ThinDynObject<dyn tokio::io::AsyncWrite + '__lt + Send, __Storage> {
+        fn poll_write(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            buf: &[u8],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[u8],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write)(self.storage_pinned_mut(), cx, buf)
+            }
+        }
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_flush)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_shutdown(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<()>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<()>>,
+                >(self.vtable().poll_shutdown)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn poll_write_vectored(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+            bufs: &[std::io::IoSlice<'_>],
+        ) -> core::task::Poll<std::io::Result<usize>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&mut __Storage>,
+                        &mut core::task::Context<'_>,
+                        &[std::io::IoSlice<'_>],
+                    ) -> core::task::Poll<std::io::Result<usize>>,
+                >(self.vtable().poll_write_vectored)(self.storage_pinned_mut(), cx, bufs)
+            }
+        }
+        fn is_write_vectored(&self) -> bool {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(&__Storage) -> bool,
+                >(self.vtable().is_write_vectored)(self.storage())
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_fill_buf: unsafe fn(),
+        consume: unsafe fn(),
+        __upcast0: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "poll_fill_buf",
+                "consume",
+                "__upcast0",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.poll_fill_buf,
+                &self.consume,
+                &&self.__upcast0,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait for dyn tokio::io::AsyncBufRead + '__lt {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: tokio::io::AsyncBufRead + '__lt,
+    > crate::object::Vtable<__Dyn> for dyn tokio::io::AsyncBufRead + '__lt {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    poll_fill_buf: unsafe {
+                        ::core::mem::transmute::<
+                            for<'a> fn(
+                                ::core::pin::Pin<&'a mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            ) -> core::task::Poll<std::io::Result<&'a [u8]>>,
+                            unsafe fn(),
+                        >(|__self, cx| ::core::mem::transmute(
+                            __Dyn::poll_fill_buf(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    consume: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::pin::Pin<&mut __Storage>, usize),
+                            unsafe fn(),
+                        >(|__self, amt| ::core::mem::transmute(
+                            __Dyn::consume(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(amt),
+                            ),
+                        ))
+                    },
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn tokio::io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn tokio::io::AsyncRead + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn tokio::io::AsyncBufRead + '__lt {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt, __Storage>,
+    > for crate::__private::Box<dyn tokio::io::AsyncBufRead + '__lt> {
+        fn from(
+            object: crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt, __Storage>,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn tokio::io::AsyncRead + '__lt>
+    for dyn tokio::io::AsyncBufRead + '__lt {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn tokio::io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn tokio::io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn tokio::io::AsyncBufRead + '__target>
+    for dyn tokio::io::AsyncBufRead + '__lt {}
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn tokio::io::AsyncBufRead + '__target>
+    for dyn tokio::io::AsyncBufRead + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> tokio::io::AsyncBufRead
+    for crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt, __Storage>
+    where
+        Self: tokio::io::AsyncRead,
+    {
+        fn poll_fill_buf<'a>(
+            self: core::pin::Pin<&'a mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<&'a [u8]>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&'a mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<&'a [u8]>>,
+                >(self.vtable().poll_fill_buf)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn consume(self: core::pin::Pin<&mut Self>, amt: usize) {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::pin::Pin<&mut __Storage>, usize),
+                >(self.vtable().consume)(self.storage_pinned_mut(), amt)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> tokio::io::AsyncBufRead
+    for crate::ThinDynObject<dyn tokio::io::AsyncBufRead + '__lt, __Storage>
+    where
+        Self: tokio::io::AsyncRead,
+    {
+        fn poll_fill_buf<'a>(
+            self: core::pin::Pin<&'a mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<&'a [u8]>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&'a mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<&'a [u8]>>,
+                >(self.vtable().poll_fill_buf)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn consume(self: core::pin::Pin<&mut Self>, amt: usize) {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::pin::Pin<&mut __Storage>, usize),
+                >(self.vtable().consume)(self.storage_pinned_mut(), amt)
+            }
+        }
+    }
+};
+const _: () = {
+    #[repr(C)]
+    pub struct __Vtable {
+        __drop_in_place: Option<unsafe fn(::core::ptr::NonNull<()>)>,
+        __layout: ::core::alloc::Layout,
+        __unsize: unsafe fn(),
+        poll_fill_buf: unsafe fn(),
+        consume: unsafe fn(),
+        __upcast0: unsafe fn(),
+        __upcast1: unsafe fn(),
+    }
+    #[automatically_derived]
+    impl ::core::fmt::Debug for __Vtable {
+        #[inline]
+        fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let names: &'static _ = &[
+                "__drop_in_place",
+                "__layout",
+                "__unsize",
+                "poll_fill_buf",
+                "consume",
+                "__upcast0",
+                "__upcast1",
+            ];
+            let values: &[&dyn ::core::fmt::Debug] = &[
+                &self.__drop_in_place,
+                &self.__layout,
+                &self.__unsize,
+                &self.poll_fill_buf,
+                &self.consume,
+                &self.__upcast0,
+                &&self.__upcast1,
+            ];
+            ::core::fmt::Formatter::debug_struct_fields_finish(
+                f,
+                "__Vtable",
+                names,
+                values,
+            )
+        }
+    }
+    impl<'__lt> crate::object::DynTrait
+    for dyn tokio::io::AsyncBufRead + '__lt + Send {
+        type Vtable = __Vtable;
+        fn drop_in_place_fn(
+            vtable: &Self::Vtable,
+        ) -> Option<unsafe fn(core::ptr::NonNull<()>)> {
+            vtable.__drop_in_place
+        }
+        fn layout(vtable: &Self::Vtable) -> core::alloc::Layout {
+            vtable.__layout
+        }
+    }
+    unsafe impl<
+        '__lt,
+        __Dyn: tokio::io::AsyncBufRead + '__lt + Send,
+    > crate::object::Vtable<__Dyn> for dyn tokio::io::AsyncBufRead + '__lt + Send {
+        fn vtable<__Storage: crate::storage::SharedStorage>() -> &'static Self::Vtable {
+            &const {
+                __Vtable {
+                    __drop_in_place: <Self as crate::object::Vtable<
+                        __Dyn,
+                    >>::DROP_IN_PLACE_FN,
+                    __layout: core::alloc::Layout::new::<__Dyn>(),
+                    __unsize: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                            unsafe fn(),
+                        >(|__ptr| __ptr.cast::<__Dyn>())
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    poll_fill_buf: unsafe {
+                        ::core::mem::transmute::<
+                            for<'a> fn(
+                                ::core::pin::Pin<&'a mut __Storage>,
+                                &mut core::task::Context<'_>,
+                            ) -> core::task::Poll<std::io::Result<&'a [u8]>>,
+                            unsafe fn(),
+                        >(|__self, cx| ::core::mem::transmute(
+                            __Dyn::poll_fill_buf(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(cx),
+                            ),
+                        ))
+                    },
+                    #[allow(
+                        clippy::missing_transmute_annotations,
+                        clippy::useless_transmute
+                    )]
+                    consume: unsafe {
+                        ::core::mem::transmute::<
+                            fn(::core::pin::Pin<&mut __Storage>, usize),
+                            unsafe fn(),
+                        >(|__self, amt| ::core::mem::transmute(
+                            __Dyn::consume(
+                                ::core::pin::Pin::new_unchecked(
+                                    __self
+                                        .get_unchecked_mut()
+                                        .ptr_mut()
+                                        .cast::<__Dyn>()
+                                        .as_mut(),
+                                ),
+                                ::core::mem::transmute(amt),
+                            ),
+                        ))
+                    },
+                    __upcast0: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn tokio::io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn tokio::io::AsyncRead + '__lt as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                    __upcast1: unsafe {
+                        ::core::mem::transmute::<
+                            fn() -> &'static <dyn tokio::io::AsyncRead + '__lt + Send as crate::object::DynTrait>::Vtable,
+                            unsafe fn(),
+                        >(
+                            <dyn tokio::io::AsyncRead + '__lt + Send as crate::object::Vtable<
+                                __Dyn,
+                            >>::vtable::<__Storage>,
+                        )
+                    },
+                }
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::DynUnsize
+    for dyn tokio::io::AsyncBufRead + '__lt + Send {
+        fn unsize_fn(
+            vtable: &Self::Vtable,
+        ) -> fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::ptr::NonNull<()>) -> ::core::ptr::NonNull<Self>,
+                >(vtable.__unsize)
+            }
+        }
+    }
+    impl<
+        '__lt,
+        __Storage: crate::storage::Storage,
+    > ::core::convert::From<
+        crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt + Send, __Storage>,
+    > for crate::__private::Box<dyn tokio::io::AsyncBufRead + '__lt + Send> {
+        fn from(
+            object: crate::DynObject<
+                dyn tokio::io::AsyncBufRead + '__lt + Send,
+                __Storage,
+            >,
+        ) -> Self {
+            object.into_box()
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn tokio::io::AsyncRead + '__lt>
+    for dyn tokio::io::AsyncBufRead + '__lt + Send {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn tokio::io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn tokio::io::AsyncRead + '__lt as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast0)()
+            }
+        }
+    }
+    unsafe impl<'__lt> crate::object::Upcast<dyn tokio::io::AsyncRead + '__lt + Send>
+    for dyn tokio::io::AsyncBufRead + '__lt + Send {
+        fn upcast_vtable(
+            vtable: &'static Self::Vtable,
+        ) -> &'static <dyn tokio::io::AsyncRead + '__lt + Send as crate::object::DynTrait>::Vtable {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn() -> &'static <dyn tokio::io::AsyncRead + '__lt + Send as crate::object::DynTrait>::Vtable,
+                >(vtable.__upcast1)()
+            }
+        }
+    }
+    unsafe impl<
+        '__target,
+        '__lt: '__target,
+    > crate::object::Downgrade<dyn tokio::io::AsyncBufRead + '__target + Send>
+    for dyn tokio::io::AsyncBufRead + '__lt + Send {}
+    impl<'__lt, __Storage: crate::storage::Storage> tokio::io::AsyncBufRead
+    for crate::DynObject<dyn tokio::io::AsyncBufRead + '__lt + Send, __Storage>
+    where
+        Self: tokio::io::AsyncRead,
+    {
+        fn poll_fill_buf<'a>(
+            self: core::pin::Pin<&'a mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<&'a [u8]>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&'a mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<&'a [u8]>>,
+                >(self.vtable().poll_fill_buf)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn consume(self: core::pin::Pin<&mut Self>, amt: usize) {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::pin::Pin<&mut __Storage>, usize),
+                >(self.vtable().consume)(self.storage_pinned_mut(), amt)
+            }
+        }
+    }
+    impl<'__lt, __Storage: crate::storage::ThinStorage> tokio::io::AsyncBufRead
+    for crate::ThinDynObject<dyn tokio::io::AsyncBufRead + '__lt + Send, __Storage>
+    where
+        Self: tokio::io::AsyncRead,
+    {
+        fn poll_fill_buf<'a>(
+            self: core::pin::Pin<&'a mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<std::io::Result<&'a [u8]>> {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(
+                        ::core::pin::Pin<&'a mut __Storage>,
+                        &mut core::task::Context<'_>,
+                    ) -> core::task::Poll<std::io::Result<&'a [u8]>>,
+                >(self.vtable().poll_fill_buf)(self.storage_pinned_mut(), cx)
+            }
+        }
+        fn consume(self: core::pin::Pin<&mut Self>, amt: usize) {
+            unsafe {
+                ::core::mem::transmute::<
+                    unsafe fn(),
+                    fn(::core::pin::Pin<&mut __Storage>, usize),
+                >(self.vtable().consume)(self.storage_pinned_mut(), amt)
+            }
+        }
+    }
+};
@@ -0,0 +1,43 @@
+#[dyn_utils::dyn_object(crate = crate, remote = tokio::io::AsyncRead)]
+#[dyn_utils::dyn_object(crate = crate, remote = tokio::io::AsyncRead, bounds = Send)]
+trait AsyncRead {
+    fn poll_read(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> core::task::Poll<std::io::Result<()>>;
+}
+
+#[dyn_utils::dyn_object(crate = crate, remote = tokio::io::AsyncWrite)]
+#[dyn_utils::dyn_object(crate = crate, remote = tokio::io::AsyncWrite, bounds = Send)]
+trait AsyncWrite {
+    fn poll_write(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        buf: &[u8],
+    ) -> core::task::Poll<std::io::Result<usize>>;
+    fn poll_flush(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<std::io::Result<()>>;
+    fn poll_shutdown(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<std::io::Result<()>>;
+    fn poll_write_vectored(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> core::task::Poll<std::io::Result<usize>>;
+    fn is_write_vectored(&self) -> bool;
+}
+
+#[dyn_utils::dyn_object(crate = crate, remote = tokio::io::AsyncBufRead, supertraits = tokio::io::AsyncRead)]
+#[dyn_utils::dyn_object(crate = crate, remote = tokio::io::AsyncBufRead, bounds = Send, supertraits = tokio::io::AsyncRead)]
+trait AsyncBufRead: tokio::io::AsyncRead {
+    fn poll_fill_buf<'a>(
+        self: core::pin::Pin<&'a mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<std::io::Result<&'a [u8]>>;
+    fn consume(self: core::pin::Pin<&mut Self>, amt: usize);
+}
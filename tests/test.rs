@@ -1,6 +1,7 @@
 #![cfg(feature = "macros")]
 use std::pin::Pin;
 
+use dyn_utils::DynObject;
 use futures::FutureExt;
 
 macro_rules! nothing {
@@ -63,6 +64,345 @@ trait Iterator {
     fn nth(&mut self, n: usize) -> Option<Self::Item>;
 }
 
+#[dyn_utils::dyn_object]
+trait Builder {
+    fn push(&mut self, s: &str);
+    fn finish(self) -> String;
+    fn finish_with(mut self, s: &str) -> String
+    where
+        Self: Sized,
+    {
+        self.push(s);
+        self.finish()
+    }
+}
+
+impl Builder for Vec<String> {
+    fn push(&mut self, s: &str) {
+        Vec::push(self, s.into());
+    }
+    fn finish(self) -> String {
+        self.concat()
+    }
+}
+
+#[dyn_utils::dyn_object(clone, bounds = Send)]
+#[dyn_utils::dyn_object(clone)]
+trait Counter {
+    fn incr(&mut self) -> usize;
+}
+
+impl Counter for usize {
+    fn incr(&mut self) -> usize {
+        *self += 1;
+        *self
+    }
+}
+
+#[dyn_utils::dyn_object]
+#[dyn_utils::dyn_object(bounds = Send)]
+#[dyn_utils::dyn_object(bounds = Send + Sync)]
+trait Named {
+    fn name(&self) -> String;
+}
+
+#[dyn_utils::dyn_object(supertraits = Named)]
+#[dyn_utils::dyn_object(bounds = Send, supertraits = Named)]
+trait Handler: Named + std::fmt::Debug {
+    fn handle(&mut self, arg: &str) -> String;
+}
+
+#[dyn_utils::dyn_object(downcast)]
+#[dyn_utils::dyn_object(downcast, bounds = Send)]
+trait Plugin {
+    fn id(&self) -> usize;
+}
+
+impl Plugin for usize {
+    fn id(&self) -> usize {
+        *self
+    }
+}
+
+impl Plugin for Greeter {
+    fn id(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[dyn_utils::dyn_object]
+trait Visitor {
+    fn visit(&mut self, n: usize);
+}
+
+impl Visitor for Vec<usize> {
+    fn visit(&mut self, n: usize) {
+        self.push(n);
+    }
+}
+
+#[dyn_utils::dyn_trait]
+trait Visitable {
+    fn accept<V: Visitor + ?Sized>(&self, visitor: &mut V);
+    fn accept_sized(&self, visitor: &mut impl Visitor);
+    fn accept_owned<V>(&self, visitor: V) -> usize
+    where
+        V: Visitor;
+    fn for_each(&self, f: impl Fn(usize));
+    async fn accept_async(&self, visitor: impl Visitor);
+    #[allow(dead_code)]
+    fn not_erasable(&self, v: impl Into<usize>);
+}
+
+impl Visitable for usize {
+    fn accept<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit(*self);
+    }
+    fn accept_sized(&self, visitor: &mut impl Visitor) {
+        visitor.visit(*self);
+    }
+    fn accept_owned<V: Visitor>(&self, mut visitor: V) -> usize {
+        visitor.visit(*self);
+        *self
+    }
+    fn for_each(&self, f: impl Fn(usize)) {
+        (0..*self).for_each(f);
+    }
+    async fn accept_async(&self, mut visitor: impl Visitor) {
+        visitor.visit(*self);
+    }
+    fn not_erasable(&self, _: impl Into<usize>) {}
+}
+
+#[dyn_utils::dyn_trait(bridge)]
+trait Callback {
+    type Output;
+    fn call(&self, arg: &str) -> impl Future<Output = Self::Output> + Send;
+    fn chars(&self) -> impl std::iter::Iterator<Item = char>;
+    fn visit(&self, visitor: impl Visitor);
+}
+
+#[dyn_utils::dyn_trait(bridge)]
+trait Accumulator {
+    #[dyn_trait(maybe_sync)]
+    async fn add(&mut self, n: usize) -> usize;
+    #[allow(dead_code)]
+    fn reset(self) -> Self
+    where
+        Self: Sized;
+}
+
+impl Accumulator for usize {
+    #[dyn_utils::sync]
+    async fn add(&mut self, n: usize) -> usize {
+        *self += n;
+        *self
+    }
+    fn reset(self) -> Self {
+        0
+    }
+}
+
+#[dyn_utils::dyn_trait(send, layouts)]
+trait Service: Sync {
+    #[dyn_trait(maybe_sync)]
+    async fn serve(&self, req: &str) -> String;
+    fn bytes(&self) -> impl std::iter::Iterator<Item = u8>;
+    async fn serve_twice(&self, req: &str) -> String {
+        self.serve(req).await + &self.serve(req).await
+    }
+}
+
+#[dyn_utils::dyn_trait(variants(send))]
+trait Job {
+    async fn run(&mut self) -> usize;
+}
+
+#[dyn_utils::dyn_trait(layouts)]
+trait Tokenizer {
+    fn split<'a>(&self, s: &'a str) -> (usize, impl std::iter::Iterator<Item = &'a str>);
+    fn parse(&self, s: &str) -> Result<impl Future<Output = usize> + Send, String>;
+    #[dyn_trait(storage = dyn_utils::storage::Raw<64>)]
+    fn split_first(&self, s: &str) -> Option<(u8, impl std::iter::Iterator<Item = u8>)>;
+    fn split_lines<'a>(&self, s: &'a str) -> Vec<impl std::iter::Iterator<Item = &'a str>>;
+    fn chars(&self, s: &str) -> Box<impl std::iter::Iterator<Item = char>>;
+}
+
+struct Whitespace;
+
+impl Tokenizer for Whitespace {
+    fn split<'a>(&self, s: &'a str) -> (usize, impl std::iter::Iterator<Item = &'a str>) {
+        (s.split_whitespace().count(), s.split_whitespace())
+    }
+    fn parse(&self, s: &str) -> Result<impl Future<Output = usize> + Send, String> {
+        let n = s.trim().parse::<usize>().map_err(|err| err.to_string())?;
+        Ok(async move { n })
+    }
+    fn split_first(&self, s: &str) -> Option<(u8, impl std::iter::Iterator<Item = u8>)> {
+        let (first, rest) = s.as_bytes().split_first()?;
+        Some((*first, rest.iter().copied()))
+    }
+    fn split_lines<'a>(&self, s: &'a str) -> Vec<impl std::iter::Iterator<Item = &'a str>> {
+        s.lines().map(str::split_whitespace).collect()
+    }
+    fn chars(&self, s: &str) -> Box<impl std::iter::Iterator<Item = char>> {
+        Box::new(s.chars().filter(|c| !c.is_whitespace()))
+    }
+}
+
+#[dyn_utils::dyn_trait(factory)]
+#[dyn_trait(dyn_utils::dyn_object)]
+trait Shape {
+    type Unit;
+    fn new(size: f64) -> Self
+    where
+        Self: Sized;
+    #[dyn_trait(storage = dyn_utils::storage::Box)]
+    fn parse(s: &str) -> Result<Self, String>
+    where
+        Self: Sized;
+    fn boxed(size: f64) -> Box<Self>
+    where
+        Self: Sized,
+    {
+        Box::new(Self::new(size))
+    }
+    // `Self` nested in an unsupported type, filtered from the factory
+    fn shared(size: f64) -> std::rc::Rc<Self>
+    where
+        Self: Sized,
+    {
+        std::rc::Rc::new(Self::new(size))
+    }
+    fn name() -> &'static str;
+    fn unit() -> Self::Unit;
+    fn area(&self) -> f64;
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    type Unit = char;
+    fn new(size: f64) -> Self {
+        Self(size)
+    }
+    fn parse(s: &str) -> Result<Self, String> {
+        let size = s.strip_suffix('m').ok_or("missing unit")?;
+        Ok(Self(size.parse().map_err(|_| "invalid size")?))
+    }
+    fn name() -> &'static str {
+        "square"
+    }
+    fn unit() -> Self::Unit {
+        'm'
+    }
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+#[dyn_utils::dyn_trait]
+trait Source {
+    #[dyn_trait(maybe_inline)]
+    fn values(&self) -> impl std::iter::Iterator<Item = u8>;
+    #[dyn_trait(maybe_inline = std::slice::Iter<'_, u8>)]
+    fn bytes(&self) -> impl std::iter::Iterator<Item = &u8>;
+    #[dyn_trait(maybe_inline = std::future::Ready<usize>)]
+    async fn len(&self) -> usize;
+}
+
+struct Single(u8);
+
+impl Source for Single {
+    #[dyn_utils::inlined]
+    fn values(&self) -> std::option::IntoIter<u8> {
+        Some(self.0).into_iter()
+    }
+    #[dyn_utils::inlined]
+    fn bytes(&self) -> std::slice::Iter<'_, u8> {
+        std::slice::from_ref(&self.0).iter()
+    }
+    async fn len(&self) -> usize {
+        1
+    }
+}
+
+struct Bytes(Vec<u8>);
+
+impl Source for Bytes {
+    fn values(&self) -> impl std::iter::Iterator<Item = u8> {
+        self.0.clone().into_iter()
+    }
+    fn bytes(&self) -> impl std::iter::Iterator<Item = &u8> {
+        self.0.iter()
+    }
+    #[dyn_utils::inlined]
+    fn len(&self) -> std::future::Ready<usize> {
+        std::future::ready(self.0.len())
+    }
+}
+
+#[cfg(feature = "futures-core")]
+#[dyn_utils::dyn_trait]
+trait Events {
+    #[dyn_trait(maybe_inline = futures::stream::Once<std::future::Ready<u8>>)]
+    fn events(&self) -> impl futures::Stream<Item = u8>;
+}
+
+#[cfg(feature = "futures-core")]
+impl Events for Single {
+    #[dyn_utils::inlined]
+    fn events(&self) -> futures::stream::Once<std::future::Ready<u8>> {
+        futures::stream::once(std::future::ready(self.0))
+    }
+}
+
+struct Echo;
+
+impl ServiceSend for Echo {
+    #[dyn_utils::sync]
+    async fn serve(&self, req: &str) -> String {
+        req.into()
+    }
+    fn bytes(&self) -> impl std::iter::Iterator<Item = u8> + Send {
+        0..3
+    }
+}
+
+impl JobSend for usize {
+    async fn run(&mut self) -> usize {
+        *self
+    }
+}
+
+#[derive(Debug)]
+struct Greeter(String);
+
+impl Callback for Greeter {
+    type Output = String;
+    async fn call(&self, arg: &str) -> Self::Output {
+        format!("{} greets {arg}", self.0)
+    }
+    fn chars(&self) -> impl std::iter::Iterator<Item = char> {
+        self.0.chars()
+    }
+    fn visit(&self, mut visitor: impl Visitor) {
+        visitor.visit(self.0.len());
+    }
+}
+
+impl Named for Greeter {
+    fn name(&self) -> String {
+        self.0.clone()
+    }
+}
+
+impl Handler for Greeter {
+    fn handle(&mut self, arg: &str) -> String {
+        format!("{} greets {arg}", self.0)
+    }
+}
+
 #[test]
 fn test() {
     let test = Box::new(()) as Box<dyn Test2<Result = usize>>;
@@ -70,5 +410,375 @@ fn test() {
     assert_eq!(test.method(), 42);
     assert_eq!(test.future("test").now_or_never(), Some("test"));
     assert_eq!(test.future2("test").now_or_never(), Some("test"));
+    assert!(test.future2("test").is_sync());
+    assert_eq!(test.future2("test").poll_once(), Ok("test"));
     assert_eq!(test.future_send("test").now_or_never(), Some(4));
 }
+
+#[test]
+fn consuming_method() {
+    fn check<S: dyn_utils::storage::Storage>() {
+        let mut builder = DynObject::<dyn Builder, S>::new(vec!["a".to_string()]);
+        builder.push("b");
+        assert_eq!(builder.finish_with("c"), "abc");
+    }
+    check::<dyn_utils::storage::Raw<{ size_of::<Vec<String>>() }>>();
+    check::<dyn_utils::storage::Box>();
+}
+
+#[test]
+fn clone() {
+    use dyn_utils::storage::{Fallback, Raw, Slot, SlotBuffer};
+
+    fn check<S: dyn_utils::storage::Storage>() {
+        let mut counter = DynObject::<dyn Counter + Send, S>::new(0usize);
+        assert_eq!(counter.incr(), 1);
+        let mut counter2 = counter.clone();
+        assert_eq!(counter2.incr(), 2);
+        assert_eq!(counter2.incr(), 3);
+        assert_eq!(counter.incr(), 2);
+    }
+    check::<dyn_utils::storage::Raw<8>>();
+    check::<dyn_utils::storage::Box>();
+    check::<dyn_utils::storage::RawOrBox<8>>();
+    check::<dyn_utils::storage::RawOrBox<0>>();
+    let counter = DynObject::<dyn Counter>::new(0usize);
+    drop(counter.clone());
+    let mut counter = DynObject::<dyn Counter, Fallback<Raw<8>, Slot<8>>>::new(0usize);
+    assert_eq!(counter.clone().incr(), 1);
+    assert_eq!(counter.incr(), 1);
+    // storages which cannot construct the object only fail when cloning
+    let mut buffer = std::pin::pin!(SlotBuffer::<8>::new());
+    let mut counter = DynObject::<dyn Counter, Slot<8>>::new_in(0usize, buffer.as_mut());
+    assert_eq!(counter.incr(), 1);
+    let clone = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| counter.clone()));
+    assert!(clone.is_err());
+    let mut vec = dyn_utils::DynVec::<dyn Counter>::new();
+    vec.push(0usize);
+    assert_eq!(vec.get_mut(0).unwrap().incr(), 1);
+}
+
+#[test]
+fn supertraits() {
+    fn check<S: dyn_utils::storage::Storage + std::fmt::Debug>() {
+        let mut handler = DynObject::<dyn Handler, S>::new(Greeter("Alice".into()));
+        assert_eq!(handler.handle("Bob"), "Alice greets Bob");
+        assert_eq!(handler.name(), "Alice");
+        assert!(format!("{handler:?}").starts_with("DynObject"));
+        let named = handler.upcast::<dyn Named>();
+        assert_eq!(named.name(), "Alice");
+        let handler = DynObject::<dyn Handler + Send, S>::new(Greeter("Alice".into()));
+        assert_eq!(handler.name(), "Alice");
+        let named = handler.upcast::<dyn Named + Send>();
+        assert_eq!(named.name(), "Alice");
+    }
+    check::<dyn_utils::storage::Raw<{ size_of::<Greeter>() }>>();
+    check::<dyn_utils::storage::Box>();
+}
+
+#[test]
+fn downgrade() {
+    let handler = DynObject::<dyn Handler + Send>::new(Greeter("Alice".into()));
+    let mut handler = handler.downgrade::<dyn Handler>();
+    assert_eq!(handler.handle("Bob"), "Alice greets Bob");
+    let named = DynObject::<dyn Named + Send>::new(Greeter("Alice".into()));
+    assert_eq!(named.downgrade::<dyn Named>().name(), "Alice");
+    let counter = DynObject::<dyn Counter + Send>::new(0usize);
+    let mut counter = counter.downgrade::<dyn Counter>().clone();
+    assert_eq!(counter.incr(), 1);
+    fn shorten<'a>(counter: DynObject<dyn Counter + Send>) -> DynObject<dyn Counter + 'a> {
+        counter.downgrade()
+    }
+    assert_eq!(shorten(DynObject::new(0usize)).incr(), 1);
+}
+
+#[test]
+fn shared_storage() {
+    let named = DynObject::<dyn Named, dyn_utils::storage::Rc>::new(Greeter("Alice".into()));
+    let named2 = named.clone();
+    drop(named);
+    assert_eq!(named2.name(), "Alice");
+    let handler = DynObject::<dyn Handler, dyn_utils::storage::Rc>::new(Greeter("Bob".into()));
+    assert_eq!(handler.clone().name(), "Bob");
+    let named =
+        DynObject::<dyn Named + Send + Sync, dyn_utils::storage::Arc>::new(Greeter("Alice".into()));
+    let named2 = named.clone();
+    let thread = std::thread::spawn(move || named2.name());
+    assert_eq!(thread.join().unwrap(), "Alice");
+    assert_eq!(named.name(), "Alice");
+}
+
+#[test]
+fn custom_storage() {
+    // storage implementing only the required methods, like before shared storages
+    struct Custom(Box<std::mem::MaybeUninit<[usize; 4]>>);
+    // SAFETY: `ptr`/`ptr_mut` return a pointer to the boxed data, which is not shared
+    unsafe impl dyn_utils::storage::Storage for Custom {
+        fn new<T>(data: T) -> Self {
+            assert!(size_of::<T>() <= 32 && align_of::<T>() <= align_of::<usize>());
+            let mut storage = Self(Box::new(std::mem::MaybeUninit::uninit()));
+            // SAFETY: the size and alignment of `T` are checked above
+            unsafe { storage.ptr_mut().cast::<T>().write(data) };
+            storage
+        }
+        fn ptr(&self) -> std::ptr::NonNull<()> {
+            std::ptr::NonNull::from(&*self.0).cast()
+        }
+        fn ptr_mut(&mut self) -> std::ptr::NonNull<()> {
+            std::ptr::NonNull::from(&mut *self.0).cast()
+        }
+        unsafe fn drop_in_place(&mut self, _layout: std::alloc::Layout) {}
+    }
+    fn assert_send<T: Send>(_: &T) {}
+    let mut iter = DynObject::<dyn core::iter::Iterator<Item = usize> + Send, Custom>::new(0..2);
+    assert_send(&iter);
+    assert_eq!(iter.next(), Some(0));
+    let future = DynObject::<dyn Future<Output = usize>, Custom>::new(async { 42 });
+    assert_eq!(future.now_or_never(), Some(42));
+}
+
+#[test]
+fn thin() {
+    use dyn_utils::{ThinDynObject, storage::ThinBox};
+
+    let mut handler = ThinDynObject::<dyn Handler, ThinBox>::new(Greeter("Alice".into()));
+    assert_eq!(size_of_val(&handler), size_of::<usize>());
+    assert_eq!(
+        size_of::<Option<ThinDynObject<dyn Handler, ThinBox>>>(),
+        size_of::<usize>()
+    );
+    assert_eq!(handler.handle("Bob"), "Alice greets Bob");
+    assert!(format!("{handler:?}").starts_with("ThinDynObject"));
+    let named = handler.upcast::<dyn Named>();
+    assert_eq!(named.name(), "Alice");
+    let ptr = named.into_raw();
+    // SAFETY: the pointer has been returned by `into_raw` with the same types
+    let named = unsafe { ThinDynObject::<dyn Named, ThinBox>::from_raw(ptr) };
+    assert_eq!(named.name(), "Alice");
+
+    let mut builder = ThinDynObject::<dyn Builder, ThinBox>::new(vec!["a".to_string()]);
+    builder.push("b");
+    assert_eq!(builder.finish_with("c"), "abc");
+
+    let counter = ThinDynObject::<dyn Counter + Send, ThinBox>::new(0usize);
+    let mut counter = counter.downgrade::<dyn Counter>();
+    assert_eq!(counter.incr(), 1);
+    let mut counter2 = counter.clone();
+    assert_eq!(counter2.incr(), 2);
+    assert_eq!(counter.incr(), 2);
+}
+
+#[test]
+fn dyn_vec() {
+    let mut handlers = dyn_utils::DynVec::<dyn Handler + Send>::new();
+    handlers.push(Greeter("Alice".into()));
+    handlers.push(Greeter("Bob".into()));
+    for mut handler in &mut handlers {
+        assert!(handler.handle("Carol").ends_with("greets Carol"));
+    }
+    let names = handlers.iter().map(|handler| handler.name());
+    assert_eq!(names.collect::<Vec<_>>(), ["Alice", "Bob"]);
+    let handlers = std::thread::spawn(move || handlers).join().unwrap();
+    assert!(format!("{:?}", handlers.get(1).unwrap()).starts_with("DynObject"));
+}
+
+#[test]
+fn into_box() {
+    let handler = DynObject::<dyn Handler + Send>::new(Greeter("Alice".into()));
+    let mut handler: Box<dyn Handler + Send> = handler.into();
+    assert_eq!(handler.handle("Bob"), "Alice greets Bob");
+    let named = DynObject::<dyn Named, dyn_utils::storage::Box>::new(Greeter("Alice".into()));
+    assert_eq!(named.into_box().name(), "Alice");
+    let builder = DynObject::<dyn Builder>::new(Vec::<String>::new());
+    Box::<dyn Builder>::from(builder).push("a");
+}
+
+#[test]
+fn downcast() {
+    use dyn_utils::{ThinDynObject, storage::ThinBox};
+
+    let plugin = DynObject::<dyn Plugin + Send>::new(42usize);
+    let mut plugin = plugin.downgrade::<dyn Plugin>();
+    assert!(plugin.is::<usize>());
+    assert_eq!(plugin.type_id(), std::any::TypeId::of::<usize>());
+    assert_eq!(plugin.downcast_ref::<Greeter>().map(|g| &g.0), None);
+    *plugin.downcast_mut::<usize>().unwrap() += 1;
+    assert_eq!(plugin.id(), 43);
+    let plugin = plugin.downcast::<Greeter>().unwrap_err();
+    assert_eq!(plugin.downcast::<usize>().unwrap(), 43);
+    let plugin = ThinDynObject::<dyn Plugin, ThinBox>::new(Greeter("Alice".into()));
+    assert_eq!(plugin.downcast_ref::<Greeter>().unwrap().0, "Alice");
+    assert_eq!(plugin.downcast::<Greeter>().unwrap().name(), "Alice");
+}
+
+#[test]
+fn generic_method() {
+    let visitable: &dyn DynVisitable = &42usize;
+    let mut visited = Vec::new();
+    visitable.accept(&mut visited);
+    assert_eq!(visited, [42]);
+    let mut visitor = DynObject::<dyn Visitor>::new(Vec::new());
+    visitable.accept_sized(&mut visitor);
+    assert_eq!(visitable.accept_owned(visitor), 42);
+    let sum = std::cell::Cell::new(0);
+    visitable.for_each(&|n| sum.set(sum.get() + n));
+    assert_eq!(sum.get(), (0..42).sum());
+    let visitor = DynObject::new(Vec::new());
+    visitable.accept_async(visitor).now_or_never().unwrap();
+}
+
+#[test]
+fn bridge() {
+    use std::sync::Arc;
+
+    fn check<C: Callback<Output = String> + ?Sized>(callback: &C) {
+        let res = callback.call("Bob").now_or_never().unwrap();
+        assert_eq!(res, "Alice greets Bob");
+        assert_eq!(callback.chars().count(), 5);
+        callback.visit(Vec::new());
+    }
+    let callback: Box<dyn DynCallback<Output = String> + Send + Sync> =
+        Box::new(Greeter("Alice".into()));
+    check(&callback);
+    check(&&*callback);
+    check(&*callback);
+    let callback: Arc<dyn DynCallback<Output = String> + Send + Sync> = Arc::from(callback);
+    check(&callback);
+
+    fn add<A: Accumulator + ?Sized>(acc: &mut A) -> usize {
+        acc.add(1).now_or_never().unwrap();
+        acc.add(2).now_or_never().unwrap()
+    }
+    let mut acc: Box<dyn DynAccumulator + Send> = Box::new(0usize);
+    assert_eq!(add(acc.as_mut()), 3);
+}
+
+#[test]
+fn send_variant() {
+    let service: Box<dyn DynServiceSend> = Box::new(Echo);
+    let future = service.serve_twice("a");
+    let res = std::thread::scope(|s| s.spawn(|| future.now_or_never().unwrap()).join());
+    assert_eq!(res.unwrap(), "aa");
+    let send_service: &dyn DynServiceSend = &Echo;
+    assert!(send_service.serve("a").is_sync());
+    let service: &dyn DynService = &Echo;
+    assert!(service.serve("a").is_sync());
+    assert_eq!(service.serve("a").now_or_never().unwrap(), "a");
+    assert_eq!(service.bytes().count(), 3);
+    assert_eq!(
+        Service::serve_twice(&Echo, "b").now_or_never().unwrap(),
+        "bb"
+    );
+    let mut job: Box<dyn DynJobSend> = Box::new(42usize);
+    let job = std::thread::spawn(move || job.run().now_or_never().unwrap());
+    assert_eq!(job.join().unwrap(), 42);
+    assert_eq!(Job::run(&mut 42usize).now_or_never().unwrap(), 42);
+}
+
+#[test]
+fn nested_rpit() {
+    let tokenizer: &dyn DynTokenizer = &Whitespace;
+    let (count, mut tokens) = tokenizer.split("a b");
+    assert_eq!(count, 2);
+    assert_eq!(tokens.next(), Some("a"));
+    assert_eq!(tokens.next(), Some("b"));
+    assert_eq!(tokenizer.parse(" 42 ").unwrap().now_or_never(), Some(42));
+    assert!(tokenizer.parse("a").is_err());
+    let (first, rest) = tokenizer.split_first("abc").unwrap();
+    assert_eq!((first, rest.collect::<Vec<_>>()), (b'a', b"bc".to_vec()));
+    assert!(tokenizer.split_first("").is_none());
+    let lines = tokenizer.split_lines("a b\nc");
+    let lines = lines.into_iter().map(|line| line.collect::<Vec<_>>());
+    assert_eq!(lines.collect::<Vec<_>>(), [vec!["a", "b"], vec!["c"]]);
+    assert_eq!(tokenizer.chars("a b").collect::<String>(), "ab");
+}
+
+#[test]
+fn storage_layouts() {
+    use std::alloc::Layout;
+
+    use dyn_utils::storage::Raw;
+
+    type SplitFirst = std::iter::Copied<std::slice::Iter<'static, u8>>;
+    assert_eq!(Whitespace::SPLIT_FIRST_0, Layout::new::<SplitFirst>());
+    let names = Whitespace::LAYOUTS.iter().map(|(name, _)| *name);
+    assert_eq!(
+        names.collect::<Vec<_>>(),
+        [
+            "split.0",
+            "parse.0",
+            "split_first.0",
+            "split_lines.0",
+            "chars.0"
+        ]
+    );
+    assert_eq!(
+        <Echo as DynServiceLayouts>::BYTES,
+        Layout::new::<std::ops::Range<u8>>()
+    );
+    let implementors = [
+        ("Echo", <Echo as DynServiceLayouts>::LAYOUTS),
+        ("Whitespace", Whitespace::LAYOUTS),
+    ];
+    Raw::<128>::assert_layouts(&implementors);
+    let report = Raw::<16, 8>::check_layouts(&implementors).unwrap_err();
+    let misfits = report
+        .misfits()
+        .map(|(implementor, method, _)| (implementor, method));
+    assert_eq!(
+        misfits.collect::<Vec<_>>(),
+        [
+            ("Echo", "serve"),
+            ("Echo", "serve_twice"),
+            ("Whitespace", "split.0"),
+            ("Whitespace", "split_lines.0")
+        ]
+    );
+    assert!(
+        report
+            .to_string()
+            .starts_with("objects don't fit into Raw<16, 8> storage:\n- Echo::serve (size=")
+    );
+}
+
+#[test]
+fn factory() {
+    let factory: Box<dyn DynShapeFactory<Unit = char> + Send + Sync> =
+        Box::new(dyn_utils::Factory::<Square>::new());
+    assert_eq!(factory.name(), "square");
+    assert_eq!(factory.new(2.0).area(), 4.0);
+    assert_eq!(factory.boxed(2.0).area(), 4.0);
+    assert_eq!(factory.unit(), 'm');
+    assert_eq!(factory.parse("3m").unwrap().area(), 9.0);
+    assert!(factory.parse("3").is_err());
+    assert_eq!(<Square as Shape>::name(), "square");
+    assert_eq!(Square::shared(2.0).0, 2.0);
+}
+
+#[test]
+fn maybe_inline() {
+    let single: &dyn DynSource = &Single(42);
+    assert!(matches!(single.values(), dyn_utils::MaybeInline::Inline(_)));
+    assert_eq!(single.values().collect::<Vec<_>>(), [42]);
+    assert_eq!(single.bytes().collect::<Vec<_>>(), [&42]);
+    assert!(matches!(single.len(), dyn_utils::MaybeInline::Dyn(_)));
+    assert_eq!(single.len().now_or_never(), Some(1));
+    let bytes: &dyn DynSource = &Bytes(vec![0, 1]);
+    assert!(matches!(bytes.values(), dyn_utils::MaybeInline::Dyn(_)));
+    assert_eq!(bytes.values().collect::<Vec<_>>(), [0, 1]);
+    assert_eq!(bytes.bytes().collect::<Vec<_>>(), [&0, &1]);
+    assert!(matches!(bytes.len(), dyn_utils::MaybeInline::Inline(_)));
+    assert_eq!(bytes.len().now_or_never(), Some(2));
+}
+
+#[cfg(feature = "futures-core")]
+#[test]
+fn maybe_inline_stream() {
+    use futures::StreamExt;
+
+    let single: &dyn DynEvents = &Single(42);
+    let events = single.events();
+    assert!(matches!(events, dyn_utils::MaybeInline::Inline(_)));
+    let mut events = std::pin::pin!(events);
+    assert_eq!(events.next().now_or_never(), Some(Some(42)));
+}